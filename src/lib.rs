@@ -28,7 +28,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! orbit_input_core = "0.1"
+//! orbit_input_core = "0.2"
 //! ```
 //!
 //! ---
@@ -56,11 +56,46 @@
 //! ### Traits de conversión
 //! - [`KeyExt<B, N>`]: Convierte entre teclas del backend nativo y teclas normalizadas
 //! - [`KeyStateExt<I, O>`]: Convierte entre estados del backend y estados normalizados
+//! - [`TryKeyExt<B, N>`]: Variante falible de `KeyExt` que reporta las teclas sin correspondencia
+//! - [`AllKeys<N>`]: Enumera las teclas normalizadas que un backend soporta
+//! - [`verify_roundtrip`]: Verifica en tests que `KeyExt` sea simétrico
 //!
 //! ### Traits de gestión de estado
 //! - [`InputStateExt<K, S>`]: Interfaz para consultar el estado actual del input (frame actual)
-//! - [`WithHistoryExt<K, S, T>`]: Extiende `InputStateExt` con sistema de historial temporal
+//! - [`WithHistoryExt<K, S, T>`]: Extiende `InputStateExt` con sistema de historial temporal. Es un alias de:
+//!   - [`HistoryAccessExt<K, S, T>`]: Acceso base al historial; el único que implementa el backend
+//!   - [`TemporalQueryExt<K, S, T>`]: Consultas temporales y filtrado de eventos
+//!   - [`ComboDetectionExt<K, S, T>`]: Combos y secuencias
+//!   - [`HistoryStatsExt<K, S, T>`]: Estadísticas de uso
 //! - [`InputEvent`]: Representa un evento individual en el historial
+//! - [`Clock`]: Fuente de tiempo de los eventos; [`StdClock`] usa `Instant` (feature `std`)
+//! - [`FrameTimeExt<K, S>`]: Tiempo actual inyectado por el game loop, sin reloj de pared
+//! - [`ChordEvent`] / [`ChordHistoryExt<K, S, T>`]: Acordes de teclas casi simultáneas en el historial
+//! - [`StateSemantics`]: Clasifica un estado `S` como tecla abajo o arriba
+//! - [`ObservableInputExt<K, S>`]: Callbacks invocados desde `set_key` en cada cambio de estado
+//! - [`StickyKeysExt<K, S>`]: Teclas persistentes (*sticky keys*) para accesibilidad
+//! - [`RecorderExt<K, S>`]: Grabación sin pérdidas de la entrada para reportes de bugs ([`Recording`])
+//!
+//! ### Traits de texto
+//! - [`TextInputExt<K, S>`]: Texto compuesto según distribución de teclado, modificadores e IME
+//! - [`LayoutExt<K>`]: Traduce entre posición física y tecla lógica según la distribución (QWERTY, AZERTY, Dvorak...)
+//!
+//! ### Traits de acciones
+//! - [`ActionMapExt<A, K>`]: Asocia teclas físicas a acciones lógicas del juego
+//! - [`ProfileSwitchExt<A, K>`]: Intercambia perfiles completos de bindings
+//! - [`CommandQueueExt<K, Cmd>`]: Produce objetos comando a partir de pulsaciones
+//! - [`ContextStackExt<C, K, S>`]: Pila de contextos (gameplay, menú, diálogo) que filtra el input
+//!
+//! ### Traits de dispositivos
+//! - [`AxisExt<A>`]: Ejes analógicos crudos y normalizados, base de los traits de mando
+//! - [`DeadzoneExt<A>`]: Zona muerta y curva de respuesta estándar por eje
+//! - [`GamepadExt<B, S, A>`]: Mando completo: botones, ejes y vibración
+//! - [`GamepadStateExt<A>`]: Estado analógico de un gamepad (sticks y gatillos)
+//! - [`GamepadHistoryExt<A, B, S, T>`]: Combos mixtos stick + botón sobre el historial
+//! - [`HapticExt`]: Vibración con efectos constantes, en rampa y periódicos
+//! - [`MotionExt`]: Giroscopio y acelerómetro para apuntado por movimiento
+//! - [`MouseStateExt<B, S>`]: Cursor, movimiento relativo, rueda y botones del ratón
+//! - [`TouchExt`]: Contactos multitáctiles con ids estables entre frames
 //!
 //! ---
 //!
@@ -188,7 +223,28 @@
 //!
 //! - 🔌 **Arquitectura plugin** — cualquier backend puede implementar los traits
 //! - 🎯 **Type-safe** — los tipos genéricos previenen errores en tiempo de compilación
-//! - 🔄 **Versionado semántico estricto** — cambios breaking solo en versiones mayores
+//! - 🔄 **Versionado semántico estricto** — cambios breaking solo en versiones
+//!   incompatibles según Cargo (mientras el crate esté en `0.x`, al subir la versión
+//!   menor)
+//!
+//! ## Política de evolución
+//!
+//! Cada trait separa un conjunto pequeño de **métodos requeridos** (las primitivas
+//! de almacenamiento, como `set_key`, `state_iter` o `history`) del resto de
+//! consultas, que tienen implementación por defecto construida sobre ellas; la
+//! documentación de cada trait explica por qué cada método requerido no puede tener
+//! un valor por defecto. En la familia de [`WithHistoryExt`] las consultas se
+//! implementan de forma genérica para todo [`HistoryAccessExt`]: su comportamiento
+//! es fijo y un backend no puede sobrescribirlas.
+//!
+//! La versión `0.2` **rompe** la compatibilidad con `0.1`: [`InputStateExt`],
+//! [`RuntimeExt`] y [`WithHistoryExt`] ganaron métodos y tipos asociados
+//! requeridos (por ejemplo `advance_frame`, `snapshot`/`restore`,
+//! `RuntimeExt::DeviceEvent`, `ready`, `pause`/`unpause` o `diagnostics`), y el
+//! historial se dividió en varios traits. A partir de `0.2`, los métodos que se
+//! añadan en una versión de parche llevan **siempre** implementación por defecto;
+//! un método requerido nuevo solo llega con la siguiente versión incompatible
+//! (`0.3`).
 //!
 //! ---
 //!
@@ -215,29 +271,51 @@
 //!
 //! ---
 //!
-//! ## Roadmap
-//!
-//! Futuras versiones del protocolo incluirán:
-//!
-//! - 🎮 Traits para otros dispositivos (mouse, gamepad, touch)
-//! - 📝 Trait para interpretación de texto y layouts de teclado
-//! - 🔊 Trait para feedback háptico
-//! - 🎯 Trait para gestión de contextos de input (menú, gameplay, diálogo)
-//!
-//! ---
-//!
 //! ## Módulos
 //!
 //! - [`traits`]: Todos los traits disponibles para implementación
+//! - `testing` *(feature `testing`)*: Implementaciones de referencia para tests, como `NullRuntime`, el estado `MockInputState` y el reloj determinista `MockClock`, y la macro `conformance_tests!` que genera los tests de conformidad de un backend
+//!
+//! ## Features
+//!
+//! - `std` *(por defecto)*: Habilita [`StdClock`] y las utilidades que requieren `std`. Sin él, el crate es `no_std` (requiere `alloc`)
+//! - `serde`: Deriva `Serialize`/`Deserialize` para los tipos concretos del crate ([`HistoryRecord`], [`KeyConversionError`], [`RuntimeSnapshot`], [`RuntimeDiagnostics`], [`BindingSet`], [`DeviceId`], [`Recording`]). Los eventos se serializan como [`SerializableEvent`] (ver [`InputEvent::to_serializable`]): `Instant` no es serializable, así que el tiempo se guarda como microsegundos relativos
+//! - `testing`: Habilita el módulo `testing` (implica `std`); `test-util` es un alias
+//! - `identity`: Implementación identidad de `KeyExt<T, T>` para backends que ya usan el tipo de tecla del motor (ver la documentación de `KeyExt` sobre coherencia)
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
 
 pub mod traits;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // Re-exports limpios
 pub use traits::*;
 
 
 
-pub use traits::keys::{KeyExt, KeyStateExt};
-pub use traits::runtime::{RuntimeExt};
-pub use traits::state::{InputEvent, InputStateExt, WithHistoryExt};
\ No newline at end of file
+pub use traits::axis::{AxisExt, DeadzoneExt, ResponseCurve};
+pub use traits::clock::{Clock, FrameTimeExt};
+#[cfg(feature = "std")]
+pub use traits::clock::StdClock;
+pub use traits::context::ContextStackExt;
+pub use traits::action::{ActionMap, ActionMapExt, BindingSet, CommandQueueExt, ProfileId, ProfileSwitchExt, RebindStatus};
+pub use traits::gamepad::{Axis, Direction8, GamepadExt, GamepadHistoryExt, GamepadStateExt, StickSample};
+pub use traits::haptic::{HapticEffect, HapticExt, Waveform};
+pub use traits::keys::{AllKeys, KeyConversionError, KeyExt, KeyStateExt, RoundtripFailure, TryKeyExt, UnmappedKeyExt, verify_roundtrip};
+pub use traits::layout::LayoutExt;
+pub use traits::motion::{MotionExt, Quaternion};
+pub use traits::mouse::MouseStateExt;
+pub use traits::observe::ObservableInputExt;
+pub use traits::record::{RecordedEvent, Recording, RecorderExt};
+pub use traits::runtime::{DeviceChange, DeviceId, DeviceInfo, DeviceKind, ErrorAction, InjectableRuntime, OverflowPolicy, PollRuntimeExt, RuntimeDiagnostics, RuntimeExt, RuntimeSnapshot};
+pub use traits::state::{ChordEvent, ChordHistoryExt, ComboDetectionExt, GapCounting, HistoryAccessExt, HistoryFootprint, HistoryRecord, HistoryStatsExt, InputEvent, InputStateExt, MappedState, PrioritizedCombo, ReconstructedState, SeqToken, SequenceMatcher, SerializableEvent, StateSemantics, TemporalQueryExt, WithHistoryExt};
+pub use traits::sticky::StickyKeysExt;
+pub use traits::text::{ImeEvent, KeyboardLayout, TextInputExt};
+pub use traits::touch::{TouchExt, TouchPhase, TouchPoint};
+
+#[cfg(feature = "testing")]
+pub use traits::state::CoherenceError;
\ No newline at end of file
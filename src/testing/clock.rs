@@ -0,0 +1,99 @@
+use core::time::Duration;
+
+use crate::Clock;
+
+/// # Reloj `MockClock`
+///
+/// [`Clock`] **determinista** para tests: el tiempo solo avanza cuando el test llama
+/// a [`advance`](Self::advance).
+///
+/// Las marcas de tiempo son un [`Duration`] desde el origen virtual del reloj (`0`
+/// al crearlo), así que los eventos se pueden construir en instantes exactos y las
+/// consultas temporales (`is_double_tap`, `match_sequence_in_time`,
+/// `average_press_interval`...) dan siempre el mismo resultado, sin `sleep` ni
+/// tolerancias.
+///
+/// ## Ejemplo
+/// [`MockInputState`](super::MockInputState) usa este reloj por defecto, y
+/// [`update_time`](crate::FrameTimeExt::update_time) lo avanza:
+///
+/// ```rust,ignore
+/// use orbit_input_core::testing::MockInputState;
+///
+/// let mut input: MockInputState<KeyCode, KeyState> = MockInputState::new();
+/// input.set_key(KeyCode::Space, KeyState::Pressed);
+/// input.set_key(KeyCode::Space, KeyState::Released);
+/// input.update_time(Duration::from_millis(120));
+/// input.set_key(KeyCode::Space, KeyState::Pressed);
+///
+/// assert!(input.is_double_tap(KeyCode::Space, Duration::from_millis(150)));
+/// assert_eq!(input.average_press_interval(KeyCode::Space), Some(Duration::from_millis(120)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MockClock {
+    now: Duration,
+}
+
+impl MockClock {
+    /// Crea un reloj en el origen virtual (`now() == Duration::ZERO`).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Avanza el reloj `by`. Satura en lugar de desbordar.
+    pub fn advance(&mut self, by: Duration) {
+        self.now = self.now.saturating_add(by);
+    }
+}
+
+impl Clock for MockClock {
+    type Timestamp = Duration;
+
+    fn now(&self) -> Duration {
+        self.now
+    }
+
+    fn duration_between(earlier: Duration, later: Duration) -> Duration {
+        later.saturating_sub(earlier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, Input, Key, ms};
+    use crate::{InputStateExt, TemporalQueryExt};
+
+    #[test]
+    fn advance_moves_now_and_saturates() {
+        let mut clock = MockClock::new();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        clock.advance(ms(120));
+        clock.advance(ms(5));
+        assert_eq!(clock.now(), ms(125));
+
+        clock.advance(Duration::MAX);
+        assert_eq!(clock.now(), Duration::MAX);
+    }
+
+    #[test]
+    fn duration_between_never_goes_negative() {
+        assert_eq!(MockClock::duration_between(ms(10), ms(35)), ms(25));
+        assert_eq!(MockClock::duration_between(ms(35), ms(10)), Duration::ZERO);
+    }
+
+    #[test]
+    fn temporal_queries_are_exact_under_the_mock_clock() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.release(Key::A);
+        input.clock_mut().advance(ms(120));
+        input.press(Key::A);
+
+        assert!(input.is_double_tap(Key::A, ms(150)));
+        assert!(!input.is_double_tap(Key::A, ms(119)));
+        assert_eq!(input.average_press_interval(Key::A), Some(ms(120)));
+        assert_eq!(input.state_of(Key::A), Some(Btn::Down));
+    }
+}
@@ -0,0 +1,436 @@
+//! # Comprobaciones de conformidad
+//!
+//! Cada función de este módulo ejercita una parte del **contrato** de
+//! [`InputStateExt`] o [`WithHistoryExt`] que la documentación de los traits describe
+//! en prosa, y entra en pánico con un mensaje descriptivo si la implementación no lo
+//! cumple.
+//!
+//! Normalmente no se llaman a mano: [`conformance_tests!`](crate::conformance_tests)
+//! genera un `#[test]` por cada una, aunque también sirven sueltas dentro de tests
+//! propios. Todas reciben el estado recién construido, dos
+//! teclas distintas `a` y `b`, y un estado `pressed` que represente una pulsación.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{InputEvent, InputStateExt, StateSemantics, WithHistoryExt};
+
+/// Genera la batería de tests de conformidad de [`conformance`](crate::testing::conformance)
+/// para un backend.
+///
+/// Crea un módulo `$name` con un `#[test]` por comprobación, cada uno sobre un estado
+/// nuevo obtenido llamando a `input` (una clausura o una ruta a función). Con
+/// `history: TipoDeEvento` se añaden además las comprobaciones de historial, que
+/// requieren [`WithHistoryExt`].
+///
+/// Requiere el feature `testing` (o `test-util`) en las `dev-dependencies`.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// orbit_input_core::conformance_tests! {
+///     mod keyboard_conformance;
+///     input: KeyboardState::default,
+///     keys: (KeyCode::KeyA, KeyCode::ShiftLeft),
+///     pressed: KeyState::Pressed,
+///     history: KeyboardEvent,
+/// }
+/// ```
+#[macro_export]
+macro_rules! conformance_tests {
+    (
+        mod $name:ident;
+        input: $input:expr,
+        keys: ($a:expr, $b:expr $(,)?),
+        pressed: $pressed:expr
+        $(, history: $event:ty)? $(,)?
+    ) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::conformance_tests!(@state ($input, $a, $b, $pressed)
+                just_press_lasts_one_frame,
+                just_released_lasts_one_frame,
+                press_and_release_in_one_frame,
+                keys_are_independent,
+                combos_require_every_key,
+                reset_clears_everything,
+                reset_key_clears_one_key,
+                snapshot_restores_edges,
+                release_all_emits_releases,
+            );
+
+            $(
+                $crate::conformance_tests!(@history ($input, $a, $b, $pressed, $event)
+                    history_is_chronological,
+                    set_keys_preserves_order,
+                    trim_history_keeps_latest,
+                    clear_history_keeps_state,
+                    undo_reverts_state,
+                    history_matches_state,
+                );
+            )?
+        }
+    };
+    (@state ($input:expr, $a:expr, $b:expr, $pressed:expr) $($check:ident,)*) => {
+        $(
+            #[test]
+            fn $check() {
+                $crate::testing::conformance::$check(($input)(), $a, $b, $pressed);
+            }
+        )*
+    };
+    (@history ($input:expr, $a:expr, $b:expr, $pressed:expr, $event:ty) $($check:ident,)*) => {
+        $(
+            #[test]
+            fn $check() {
+                $crate::testing::conformance::$check::<_, _, _, $event>(($input)(), $a, $b, $pressed);
+            }
+        )*
+    };
+}
+
+/// Comprueba que `pressed` es realmente una pulsación y que `a` y `b` son distintas.
+fn check_inputs<K: PartialEq + Debug, S: StateSemantics>(a: K, b: K, pressed: S) {
+    assert!(pressed.is_down() && pressed.is_press(), "`pressed` debe ser un estado de pulsación");
+    assert!(a != b, "las teclas `a` y `b` deben ser distintas, ambas son {a:?}");
+}
+
+/// [`is_just_press`](InputStateExt::is_just_press) se reporta solo en el frame de la
+/// pulsación; [`is_pressed`](InputStateExt::is_pressed) sigue mientras se mantiene.
+pub fn just_press_lasts_one_frame<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    assert!(input.is_pressed(a), "{a:?} debe estar presionada tras set_key");
+    assert!(input.is_just_press(a), "is_just_press({a:?}) debe ser true en el frame de la pulsación");
+
+    input.advance_frame();
+    assert!(input.is_pressed(a), "{a:?} debe seguir presionada tras advance_frame");
+    assert!(!input.is_just_press(a), "is_just_press({a:?}) debe consumirse con advance_frame");
+}
+
+/// [`is_just_released`](InputStateExt::is_just_released) se reporta solo en el
+/// frame de la liberación.
+pub fn just_released_lasts_one_frame<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.advance_frame();
+    input.set_key(a, S::released());
+    assert!(!input.is_pressed(a), "{a:?} no debe estar presionada tras soltarla");
+    assert!(input.is_just_released(a), "is_just_released({a:?}) debe ser true en el frame de la liberación");
+    assert_eq!(input.pressed_count(), 0, "una tecla recién soltada no cuenta en pressed_count");
+
+    input.advance_frame();
+    assert!(!input.is_just_released(a), "is_just_released({a:?}) debe consumirse con advance_frame");
+}
+
+/// Presionar y soltar dentro del mismo frame deja visibles **ambos** flancos hasta
+/// el siguiente [`advance_frame`](InputStateExt::advance_frame).
+pub fn press_and_release_in_one_frame<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(a, S::released());
+    assert!(!input.is_pressed(a), "{a:?} no debe estar presionada tras soltarla");
+    assert!(input.is_just_press(a), "el flanco de pulsación de {a:?} debe seguir visible en el mismo frame");
+    assert!(input.is_just_released(a), "el flanco de liberación de {a:?} debe ser visible");
+
+    input.advance_frame();
+    assert!(!input.is_just_press(a) && !input.is_just_released(a), "advance_frame debe consumir ambos flancos de {a:?}");
+}
+
+/// Cambiar una tecla no afecta a otra.
+pub fn keys_are_independent<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    assert!(!input.is_pressed(b), "presionar {a:?} no debe presionar {b:?}");
+    assert!(!input.is_just_press(b), "presionar {a:?} no debe generar flancos en {b:?}");
+    assert_eq!(input.keys_pressed(), [a], "solo {a:?} debe estar presionada");
+    assert_eq!(input.pressed_count(), 1, "pressed_count debe coincidir con keys_pressed");
+}
+
+/// Los combos se cumplen solo mientras todas sus teclas están presionadas.
+pub fn combos_require_every_key<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    assert!(!input.active_combo(&[a, b]), "el combo [{a:?}, {b:?}] no debe cumplirse con solo {a:?}");
+    assert!(input.is_any_pressed(&[a, b]), "is_any_pressed debe cumplirse con {a:?} presionada");
+
+    input.set_key(b, pressed);
+    assert!(input.active_combo(&[a, b]), "el combo [{a:?}, {b:?}] debe cumplirse con ambas presionadas");
+    assert!(input.is_all_pressed(&[a, b]), "is_all_pressed debe cumplirse con ambas presionadas");
+    assert!(input.combo_with_modifiers(&[a], b), "{a:?} + {b:?} debe cumplirse como combo con modificador");
+
+    input.set_key(b, S::released());
+    assert!(!input.active_combo(&[a, b]), "el combo [{a:?}, {b:?}] debe dejar de cumplirse al soltar {b:?}");
+}
+
+/// [`reset`](InputStateExt::reset) libera todo **sin** generar flancos.
+pub fn reset_clears_everything<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(b, pressed);
+    input.reset();
+    assert!(!input.any_pressed(), "ninguna tecla debe quedar presionada tras reset");
+    for key in [a, b] {
+        assert!(!input.is_just_press(key), "reset debe limpiar el flanco de pulsación de {key:?}");
+        assert!(!input.is_just_released(key), "reset no debe generar flancos de liberación en {key:?}");
+    }
+}
+
+/// [`reset_key`](InputStateExt::reset_key) libera una sola tecla sin flancos y sin
+/// tocar las demás.
+pub fn reset_key_clears_one_key<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(b, pressed);
+    input.reset_key(a);
+    assert!(!input.is_pressed(a), "{a:?} no debe estar presionada tras reset_key");
+    assert!(input.time_pressed(a).is_none(), "reset_key debe descartar el temporizador de {a:?}");
+    assert!(!input.is_just_press(a) && !input.is_just_released(a), "reset_key debe limpiar los flancos de {a:?}");
+    assert!(input.is_pressed(b), "reset_key({a:?}) no debe afectar a {b:?}");
+}
+
+/// [`restore`](InputStateExt::restore) recupera exactamente el estado de
+/// [`snapshot`](InputStateExt::snapshot), flancos incluidos.
+pub fn snapshot_restores_edges<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    let snapshot = input.snapshot();
+
+    input.set_key(a, S::released());
+    input.set_key(b, pressed);
+    input.advance_frame();
+
+    input.restore(&snapshot);
+    assert!(input.is_pressed(a), "{a:?} debe volver a estar presionada tras restore");
+    assert!(input.is_just_press(a), "restore debe recuperar el flanco de pulsación de {a:?}");
+    assert!(!input.is_pressed(b), "{b:?} se presionó después del snapshot y no debe estar presionada");
+}
+
+/// [`release_all`](InputStateExt::release_all) suelta todo **con** flancos de
+/// liberación.
+pub fn release_all_emits_releases<I, K, S>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(b, pressed);
+    input.advance_frame();
+    input.release_all();
+    assert!(!input.any_pressed(), "ninguna tecla debe quedar presionada tras release_all");
+    for key in [a, b] {
+        assert!(input.is_just_released(key), "release_all debe generar el flanco de liberación de {key:?}");
+    }
+}
+
+/// El historial registra los eventos en orden cronológico.
+pub fn history_is_chronological<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics + Debug,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(b, pressed);
+    input.set_key(a, S::released());
+
+    let recorded: Vec<(K, S)> = input.history().iter().map(|event| (event.key(), event.state())).collect();
+    assert_eq!(recorded, [(a, pressed), (b, pressed), (a, S::released())], "el historial debe seguir el orden de set_key");
+    assert!(
+        input.history().windows(2).all(|pair| pair[0].timestamp() <= pair[1].timestamp()),
+        "las marcas de tiempo del historial no deben retroceder"
+    );
+    assert!(
+        input.last_event().is_some_and(|event| event.key() == a && !event.state().is_down()),
+        "last_event debe ser la liberación de {a:?}"
+    );
+}
+
+/// [`set_keys`](InputStateExt::set_keys) equivale a llamar a `set_key` en el orden
+/// del slice, también en el historial.
+pub fn set_keys_preserves_order<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics + Debug,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    let updates = [(a, pressed), (b, pressed), (a, S::released())];
+    input.set_keys(&updates);
+
+    let recorded: Vec<(K, S)> = input.history().iter().map(|event| (event.key(), event.state())).collect();
+    assert_eq!(recorded, updates, "set_keys debe registrar los eventos en el orden del slice");
+    assert!(!input.is_pressed(a) && input.is_pressed(b), "set_keys debe dejar el estado del último par de cada tecla");
+    assert!(input.is_just_press(a) && input.is_just_released(a), "set_keys debe conservar ambos flancos de {a:?}");
+}
+
+/// [`trim_history`](crate::HistoryAccessExt::trim_history) descarta los eventos más
+/// antiguos y conserva los más recientes.
+pub fn trim_history_keeps_latest<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(b, pressed);
+    input.set_key(a, S::released());
+    input.trim_history(2);
+
+    let keys: Vec<K> = input.history().iter().map(|event| event.key()).collect();
+    assert_eq!(keys, [b, a], "trim_history(2) debe conservar los dos eventos más recientes");
+    assert!(input.is_pressed(b), "trim_history no debe modificar el estado vivo");
+}
+
+/// [`clear_history`](crate::HistoryAccessExt::clear_history) vacía el historial sin
+/// tocar el estado vivo.
+pub fn clear_history_keeps_state<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.clear_history();
+    assert!(input.history().is_empty(), "clear_history debe vaciar el historial");
+    assert!(input.is_pressed(a), "clear_history no debe modificar el estado vivo");
+}
+
+/// [`undo_last_and_revert`](crate::HistoryAccessExt::undo_last_and_revert) deja cada
+/// tecla en el estado de su último evento restante.
+pub fn undo_reverts_state<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    input.set_key(a, pressed);
+    input.set_key(a, S::released());
+
+    let undone = input.undo_last_and_revert();
+    assert!(undone.is_some_and(|event| event.key() == a), "undo_last_and_revert debe retornar la liberación de {a:?}");
+    assert!(input.is_pressed(a), "{a:?} debe volver al estado de su pulsación");
+
+    input.undo_last_and_revert();
+    assert!(!input.is_pressed(a), "sin eventos restantes, {a:?} debe quedar liberada");
+    assert!(input.history().is_empty(), "el historial debe quedar vacío");
+}
+
+/// Historial y estado vivo siguen coherentes (ver
+/// [`assert_coherent`](crate::HistoryAccessExt::assert_coherent)).
+pub fn history_matches_state<I, K, S, T>(mut input: I, a: K, b: K, pressed: S)
+where
+    I: WithHistoryExt<K, S, T>,
+    K: Copy + PartialEq + Hash + Debug,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    check_inputs(a, b, pressed);
+
+    for (key, state) in [(a, pressed), (b, pressed), (a, S::released()), (b, S::released()), (a, pressed)] {
+        input.set_key(key, state);
+        if let Err(error) = input.assert_coherent() {
+            panic!("historial y estado divergen: {error}");
+        }
+        input.advance_frame();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, DropsReleases, Event, Faulty, FreezesFrames, Healthy, Key};
+
+    // Un backend con solo los métodos requeridos también cumple el contrato.
+    crate::conformance_tests! {
+        mod defaults_conformance;
+        input: Faulty::<Healthy>::default,
+        keys: (Key::A, Key::B),
+        pressed: Btn::Down,
+        history: Event,
+    }
+
+    #[test]
+    #[should_panic(expected = "is_just_press(A) debe consumirse con advance_frame")]
+    fn frozen_frames_keep_the_press_edge() {
+        just_press_lasts_one_frame(Faulty::<FreezesFrames>::default(), Key::A, Key::B, Btn::Down);
+    }
+
+    #[test]
+    #[should_panic(expected = "is_just_released(A) debe consumirse con advance_frame")]
+    fn frozen_frames_keep_the_release_edge() {
+        just_released_lasts_one_frame(Faulty::<FreezesFrames>::default(), Key::A, Key::B, Btn::Down);
+    }
+
+    #[test]
+    #[should_panic(expected = "historial y estado divergen")]
+    fn dropped_releases_are_detected() {
+        history_matches_state::<_, _, _, Event>(Faulty::<DropsReleases>::default(), Key::A, Key::B, Btn::Down);
+    }
+}
@@ -0,0 +1,362 @@
+//! Tipos compartidos por los tests unitarios del crate.
+//!
+//! [`Input`] es el [`MockInputState`] sobre un teclado de juguete. [`Faulty`] lo
+//! envuelve para simular backends con errores concretos, que los tests usan para
+//! comprobar que las herramientas de diagnóstico los detectan.
+
+use core::marker::PhantomData;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use super::{MockClock, MockEvent, MockInputState, MockSnapshot, PressedState};
+use crate::{FrameTimeExt, HistoryAccessExt, HistoryRecord, InputStateExt, StateSemantics};
+
+/// Teclas del teclado de juguete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Key {
+    A,
+    B,
+    C,
+    D,
+}
+
+/// Estados de una tecla del teclado de juguete, con un estado "mantenida" que no
+/// es una pulsación nueva.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Btn {
+    Up,
+    Down,
+    Held,
+}
+
+impl StateSemantics for Btn {
+    fn is_down(&self) -> bool {
+        matches!(self, Btn::Down | Btn::Held)
+    }
+
+    fn is_press(&self) -> bool {
+        matches!(self, Btn::Down)
+    }
+
+    fn released() -> Self {
+        Btn::Up
+    }
+}
+
+impl PressedState for Btn {
+    fn pressed() -> Self {
+        Btn::Down
+    }
+}
+
+pub(crate) type Input = MockInputState<Key, Btn>;
+
+pub(crate) type Event = MockEvent<Key, Btn, Duration>;
+
+/// Milisegundos como [`Duration`].
+pub(crate) fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+/// Estado con los eventos `(milisegundo, tecla, estado)` aplicados en orden.
+///
+/// Los instantes deben ser crecientes: el reloj del mock no retrocede.
+pub(crate) fn play(events: &[(u64, Key, Btn)]) -> Input {
+    let mut input = Input::new();
+    for &(at, key, state) in events {
+        input.update_time(ms(at));
+        input.set_key(key, state);
+    }
+    input
+}
+
+/// Ejecuta un futuro que no espera a nada externo, como los de
+/// [`NullRuntime`](super::NullRuntime).
+///
+/// # Panics
+/// Si el futuro queda pendiente.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("el futuro quedó pendiente"),
+    }
+}
+
+/// Error de backend que simula un [`Faulty`].
+pub(crate) trait Fault {
+    /// Las liberaciones cambian el estado vivo pero no llegan al historial.
+    const DROPS_RELEASES: bool = false;
+
+    /// `advance_frame` no hace nada, así que los flancos nunca se consumen.
+    const FREEZES_FRAMES: bool = false;
+}
+
+/// Backend sin errores: delega todo en el mock salvo los métodos con
+/// implementación por defecto, que así se pueden probar.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Healthy;
+
+impl Fault for Healthy {}
+
+/// Backend que pierde las liberaciones del historial.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DropsReleases;
+
+impl Fault for DropsReleases {
+    const DROPS_RELEASES: bool = true;
+}
+
+/// Backend que olvida cerrar los frames.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FreezesFrames;
+
+impl Fault for FreezesFrames {
+    const FREEZES_FRAMES: bool = true;
+}
+
+/// [`Input`] con el error de backend `F`.
+#[derive(Debug, Clone)]
+pub(crate) struct Faulty<F> {
+    inner: Input,
+    fault: PhantomData<F>,
+}
+
+impl<F> Default for Faulty<F> {
+    fn default() -> Self {
+        Self { inner: Input::new(), fault: PhantomData }
+    }
+}
+
+impl<F: Fault> InputStateExt<Key, Btn> for Faulty<F> {
+    type Snapshot = MockSnapshot<Key, Btn, Duration>;
+
+    fn set_key(&mut self, key: Key, state: Btn) {
+        self.inner.set_key(key, state);
+        if F::DROPS_RELEASES && !state.is_down() {
+            self.inner.undo_last();
+        }
+    }
+
+    fn advance_frame(&mut self) {
+        if !F::FREEZES_FRAMES {
+            self.inner.advance_frame();
+        }
+    }
+
+    fn is_just_press(&self, key: Key) -> bool {
+        self.inner.is_just_press(key)
+    }
+
+    fn set_tick(&mut self, tick: u64) {
+        self.inner.set_tick(tick);
+    }
+
+    fn changed_since(&self, key: Key, tick: u64) -> bool {
+        self.inner.changed_since(key, tick)
+    }
+
+    fn suppress_repeat(&mut self, key: Key, window: Duration) {
+        self.inner.suppress_repeat(key, window);
+    }
+
+    fn is_just_released(&self, key: Key) -> bool {
+        self.inner.is_just_released(key)
+    }
+
+    fn time_pressed(&self, key: Key) -> Option<Duration> {
+        self.inner.time_pressed(key)
+    }
+
+    fn held_stable(&self, key: Key, frames: usize) -> bool {
+        self.inner.held_stable(key, frames)
+    }
+
+    fn state_iter(&self) -> impl Iterator<Item = (Key, Btn)> + '_ {
+        self.inner.state_iter()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn reset_key(&mut self, key: Key) {
+        self.inner.reset_key(key);
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.inner.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.inner.restore(snapshot);
+    }
+}
+
+impl<F: Fault> HistoryAccessExt<Key, Btn, Event> for Faulty<F> {
+    type Clock = MockClock;
+
+    fn history(&self) -> &[Event] {
+        self.inner.history()
+    }
+
+    fn clear_history(&mut self) {
+        self.inner.clear_history();
+    }
+
+    fn trim_history(&mut self, max: usize) {
+        self.inner.trim_history(max);
+    }
+
+    fn since_last_event(&self) -> Option<Duration> {
+        self.inner.since_last_event()
+    }
+
+    fn import_history(&mut self, records: &[HistoryRecord<Key, Btn>]) {
+        self.inner.import_history(records);
+    }
+
+    fn dedup_consecutive(&mut self) {
+        self.inner.dedup_consecutive();
+    }
+
+    fn undo_last(&mut self) -> Option<Event> {
+        self.inner.undo_last()
+    }
+
+    fn undo_last_and_revert(&mut self) -> Option<Event> {
+        self.inner.undo_last_and_revert()
+    }
+}
+
+/// Implementa [`InputStateExt<Key, Btn>`] para `$ty` delegando en su campo `$field`,
+/// de tipo [`Input`].
+///
+/// Lo usan los tests de traits que se montan sobre un estado de input y solo
+/// necesitan añadir su propia lógica encima. La segunda forma reemplaza `set_key` y
+/// `advance_frame` por las funciones dadas, para los traits que intervienen en ellos.
+macro_rules! delegate_input_state {
+    ($ty:ty, $field:ident) => {
+        $crate::testing::fixtures::delegate_input_state!(
+            $ty,
+            $field,
+            set_key: |this: &mut $ty, key, state| $crate::InputStateExt::set_key(&mut this.$field, key, state),
+            advance_frame: |this: &mut $ty| $crate::InputStateExt::advance_frame(&mut this.$field),
+        );
+    };
+    ($ty:ty, $field:ident, set_key: $set_key:expr, advance_frame: $advance_frame:expr $(,)?) => {
+        impl $crate::InputStateExt<Key, Btn> for $ty {
+            type Snapshot = $crate::testing::MockSnapshot<Key, Btn, ::std::time::Duration>;
+
+            fn set_key(&mut self, key: Key, state: Btn) {
+                let set_key: fn(&mut $ty, Key, Btn) = $set_key;
+                set_key(self, key, state);
+            }
+
+            fn advance_frame(&mut self) {
+                let advance_frame: fn(&mut $ty) = $advance_frame;
+                advance_frame(self);
+            }
+
+            fn is_just_press(&self, key: Key) -> bool {
+                self.$field.is_just_press(key)
+            }
+
+            fn set_tick(&mut self, tick: u64) {
+                self.$field.set_tick(tick);
+            }
+
+            fn changed_since(&self, key: Key, tick: u64) -> bool {
+                self.$field.changed_since(key, tick)
+            }
+
+            fn suppress_repeat(&mut self, key: Key, window: ::std::time::Duration) {
+                self.$field.suppress_repeat(key, window);
+            }
+
+            fn is_just_released(&self, key: Key) -> bool {
+                self.$field.is_just_released(key)
+            }
+
+            fn time_pressed(&self, key: Key) -> Option<::std::time::Duration> {
+                self.$field.time_pressed(key)
+            }
+
+            fn held_stable(&self, key: Key, frames: usize) -> bool {
+                self.$field.held_stable(key, frames)
+            }
+
+            fn state_iter(&self) -> impl Iterator<Item = (Key, Btn)> + '_ {
+                self.$field.state_iter()
+            }
+
+            fn reset(&mut self) {
+                self.$field.reset();
+            }
+
+            fn reset_key(&mut self, key: Key) {
+                self.$field.reset_key(key);
+            }
+
+            fn snapshot(&self) -> Self::Snapshot {
+                self.$field.snapshot()
+            }
+
+            fn restore(&mut self, snapshot: &Self::Snapshot) {
+                self.$field.restore(snapshot);
+            }
+        }
+    };
+}
+
+pub(crate) use delegate_input_state;
+
+/// Implementa [`HistoryAccessExt<Key, Btn, Event>`](HistoryAccessExt) para `$ty`
+/// delegando en su campo `$field`, de tipo [`Input`].
+///
+/// Complementa a [`delegate_input_state!`] en los tests de traits que necesitan el
+/// historial del mock.
+macro_rules! delegate_history_access {
+    ($ty:ty, $field:ident) => {
+        impl $crate::HistoryAccessExt<Key, Btn, $crate::testing::fixtures::Event> for $ty {
+            type Clock = $crate::testing::MockClock;
+
+            fn history(&self) -> &[$crate::testing::fixtures::Event] {
+                self.$field.history()
+            }
+
+            fn clear_history(&mut self) {
+                self.$field.clear_history();
+            }
+
+            fn trim_history(&mut self, max: usize) {
+                self.$field.trim_history(max);
+            }
+
+            fn since_last_event(&self) -> Option<::std::time::Duration> {
+                self.$field.since_last_event()
+            }
+
+            fn import_history(&mut self, records: &[$crate::HistoryRecord<Key, Btn>]) {
+                self.$field.import_history(records);
+            }
+
+            fn dedup_consecutive(&mut self) {
+                self.$field.dedup_consecutive();
+            }
+
+            fn undo_last(&mut self) -> Option<$crate::testing::fixtures::Event> {
+                self.$field.undo_last()
+            }
+
+            fn undo_last_and_revert(&mut self) -> Option<$crate::testing::fixtures::Event> {
+                self.$field.undo_last_and_revert()
+            }
+        }
+    };
+}
+
+pub(crate) use delegate_history_access;
@@ -0,0 +1,250 @@
+use std::hash::Hash;
+
+use crate::{CoherenceError, InputEvent, InputStateExt, StateSemantics, WithHistoryExt};
+
+/// Invariante violado detectado por [`fuzz_backend`] o [`fuzz_history_backend`].
+///
+/// `step` es el índice del evento de la secuencia tras el cual se detectó el fallo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation<K> {
+    /// `is_pressed` e `is_released` coinciden para la tecla.
+    PressedAndReleased {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+        /// Tecla con ambas consultas iguales.
+        key: K,
+    },
+    /// `is_pressed` no refleja el último estado aplicado a la tecla.
+    StateMismatch {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+        /// Tecla cuyo estado no coincide.
+        key: K,
+        /// Valor de `is_pressed` que correspondía al último estado aplicado.
+        expected_pressed: bool,
+    },
+    /// Hay un flanco (`is_just_press` / `is_just_released`) incompatible con el estado actual.
+    InconsistentEdge {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+        /// Tecla con el flanco incompatible.
+        key: K,
+    },
+    /// Un flanco sigue visible después de `advance_frame`.
+    EdgeSurvivedFrame {
+        /// Índice del evento cuyo `advance_frame` no consumió el flanco.
+        step: usize,
+        /// Tecla que conserva el flanco.
+        key: K,
+    },
+    /// `keys_pressed` no coincide con `is_pressed` para la tecla.
+    PressedListMismatch {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+        /// Tecla que sobra o falta en `keys_pressed`.
+        key: K,
+    },
+    /// `any_pressed` no coincide con `keys_pressed`.
+    AnyPressedMismatch {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+    },
+    /// Tras `reset` la tecla sigue presionada.
+    PressedAfterReset {
+        /// Primera tecla que sigue presionada.
+        key: K,
+    },
+    /// El historial y el estado vivo divergen (ver [`HistoryAccessExt::assert_coherent`](crate::HistoryAccessExt::assert_coherent)).
+    Incoherent {
+        /// Índice del evento tras el que se detectó.
+        step: usize,
+        /// Divergencia reportada por `assert_coherent`.
+        error: CoherenceError<K>,
+    },
+}
+
+/// Aplica `events` sobre un estado nuevo (`I::default()`) y verifica los
+/// invariantes del contrato de [`InputStateExt`] después de **cada** evento.
+///
+/// Pensado para conectarse a `proptest` o `quickcheck` con secuencias aleatorias.
+///
+/// ## Invariantes verificados
+/// Para cada tecla vista hasta el momento:
+/// 1. `is_pressed` y `is_released` son mutuamente excluyentes.
+/// 2. `is_pressed` coincide con [`StateSemantics::is_down`] del último estado aplicado.
+/// 3. `is_just_press` implica `is_pressed`, e `is_just_released` implica `is_released`.
+/// 4. La tecla está en `keys_pressed` si y solo si `is_pressed`.
+/// 5. Tras [`advance_frame`](InputStateExt::advance_frame) no queda ningún flanco y
+///    `is_pressed` no cambia.
+///
+/// Cada evento se aplica en su propio frame: los invariantes 1–4 se verifican tras
+/// `set_key` y el 5 tras el `advance_frame` que sigue.
+///
+/// Además, `any_pressed` equivale a `!keys_pressed().is_empty()`, y tras el último
+/// evento un `reset` deja todas las teclas liberadas.
+///
+/// # Errores
+/// Retorna el primer invariante violado.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// proptest! {
+///     #[test]
+///     fn my_backend_upholds_contract(events in vec((any_key(), any_state()), 0..64)) {
+///         prop_assert_eq!(fuzz_backend::<MyInput, _, _>(&events), Ok(()));
+///     }
+/// }
+/// ```
+pub fn fuzz_backend<I, K, S>(events: &[(K, S)]) -> Result<(), InvariantViolation<K>>
+where
+    I: InputStateExt<K, S> + Default,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    let mut input = I::default();
+    run_events(&mut input, events, |_, _| Ok(()))
+}
+
+/// Igual que [`fuzz_backend`], pero además verifica tras cada evento la coherencia
+/// entre historial y estado con [`HistoryAccessExt::assert_coherent`](crate::HistoryAccessExt::assert_coherent).
+///
+/// # Errores
+/// Retorna el primer invariante violado.
+pub fn fuzz_history_backend<I, K, S, T>(events: &[(K, S)]) -> Result<(), InvariantViolation<K>>
+where
+    I: WithHistoryExt<K, S, T> + Default,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    let mut input = I::default();
+    run_events(&mut input, events, |input, step| {
+        input
+            .assert_coherent()
+            .map_err(|error| InvariantViolation::Incoherent { step, error })
+    })
+}
+
+fn run_events<I, K, S>(
+    input: &mut I,
+    events: &[(K, S)],
+    extra: impl Fn(&I, usize) -> Result<(), InvariantViolation<K>>,
+) -> Result<(), InvariantViolation<K>>
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    let mut last_states: Vec<(K, S)> = Vec::new();
+
+    for (step, &(key, state)) in events.iter().enumerate() {
+        input.set_key(key, state);
+        match last_states.iter_mut().find(|(tracked, _)| *tracked == key) {
+            Some(entry) => entry.1 = state,
+            None => last_states.push((key, state)),
+        }
+
+        check_invariants(input, &last_states, step)?;
+        extra(input, step)?;
+
+        input.advance_frame();
+        check_frame_advanced(input, &last_states, step)?;
+    }
+
+    input.reset();
+    match last_states.iter().find(|(key, _)| input.is_pressed(*key)) {
+        Some(&(key, _)) => Err(InvariantViolation::PressedAfterReset { key }),
+        None => Ok(()),
+    }
+}
+
+fn check_invariants<I, K, S>(input: &I, last_states: &[(K, S)], step: usize) -> Result<(), InvariantViolation<K>>
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    let pressed = input.keys_pressed();
+
+    for &(key, state) in last_states {
+        let is_pressed = input.is_pressed(key);
+
+        if is_pressed == input.is_released(key) {
+            return Err(InvariantViolation::PressedAndReleased { step, key });
+        }
+        if is_pressed != state.is_down() {
+            return Err(InvariantViolation::StateMismatch { step, key, expected_pressed: state.is_down() });
+        }
+        if (input.is_just_press(key) && !is_pressed) || (input.is_just_released(key) && is_pressed) {
+            return Err(InvariantViolation::InconsistentEdge { step, key });
+        }
+        if pressed.contains(&key) != is_pressed {
+            return Err(InvariantViolation::PressedListMismatch { step, key });
+        }
+    }
+
+    if input.any_pressed() == pressed.is_empty() {
+        return Err(InvariantViolation::AnyPressedMismatch { step });
+    }
+
+    Ok(())
+}
+
+fn check_frame_advanced<I, K, S>(input: &I, last_states: &[(K, S)], step: usize) -> Result<(), InvariantViolation<K>>
+where
+    I: InputStateExt<K, S>,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    for &(key, state) in last_states {
+        if input.is_just_press(key) || input.is_just_released(key) {
+            return Err(InvariantViolation::EdgeSurvivedFrame { step, key });
+        }
+        if input.is_pressed(key) != state.is_down() {
+            return Err(InvariantViolation::StateMismatch { step, key, expected_pressed: state.is_down() });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, DropsReleases, Event, Faulty, FreezesFrames, Input, Key};
+
+    const EVENTS: [(Key, Btn); 7] = [
+        (Key::A, Btn::Down),
+        (Key::B, Btn::Held),
+        (Key::A, Btn::Held),
+        (Key::A, Btn::Up),
+        (Key::C, Btn::Up),
+        (Key::B, Btn::Up),
+        (Key::B, Btn::Down),
+    ];
+
+    #[test]
+    fn reference_backend_upholds_the_invariants() {
+        assert_eq!(fuzz_backend::<Input, _, _>(&EVENTS), Ok(()));
+        assert_eq!(fuzz_history_backend::<Input, _, _, Event>(&EVENTS), Ok(()));
+    }
+
+    #[test]
+    fn edges_that_survive_the_frame_are_reported() {
+        assert_eq!(
+            fuzz_backend::<Faulty<FreezesFrames>, _, _>(&EVENTS),
+            Err(InvariantViolation::EdgeSurvivedFrame { step: 0, key: Key::A })
+        );
+    }
+
+    #[test]
+    fn lost_history_events_are_reported() {
+        assert_eq!(fuzz_backend::<Faulty<DropsReleases>, _, _>(&EVENTS), Ok(()));
+        assert_eq!(
+            fuzz_history_backend::<Faulty<DropsReleases>, _, _, Event>(&EVENTS),
+            Err(InvariantViolation::Incoherent {
+                step: 3,
+                error: CoherenceError { key: Key::A, live_pressed: false, history_pressed: true },
+            })
+        );
+    }
+}
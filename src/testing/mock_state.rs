@@ -0,0 +1,870 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+use std::time::Duration;
+
+use super::MockClock;
+use crate::{Clock, FrameTimeExt, HistoryAccessExt, HistoryRecord, InputEvent, InputStateExt, StateSemantics};
+
+/// Estado con un valor canónico de **tecla presionada**, usado por
+/// [`MockInputState::press`].
+///
+/// [`StateSemantics`] solo sabe construir el estado liberado; los helpers del mock
+/// necesitan también el contrario.
+pub trait PressedState: StateSemantics {
+    /// Retorna el estado canónico de tecla recién presionada.
+    fn pressed() -> Self;
+}
+
+/// Evento del historial de [`MockInputState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MockEvent<K, S, Tm> {
+    /// Tecla del evento.
+    pub key: K,
+    /// Estado de la tecla en el evento.
+    pub state: S,
+    /// Instante del evento según el reloj del mock.
+    pub at: Tm,
+}
+
+impl<K, S, Tm> InputEvent for MockEvent<K, S, Tm>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics + Hash,
+    Tm: Copy + Ord + Hash,
+{
+    type Key = K;
+    type State = S;
+    type Time = Tm;
+
+    fn key(&self) -> K {
+        self.key
+    }
+
+    fn state(&self) -> S {
+        self.state
+    }
+
+    fn timestamp(&self) -> Tm {
+        self.at
+    }
+}
+
+/// Estado interno de una tecla rastreada.
+#[derive(Debug, Clone)]
+struct KeySlot<S, Tm> {
+    state: S,
+    pressed_at: Option<Tm>,
+    last_accepted_press: Option<Tm>,
+    /// Tick del último cambio entre presionada y liberada; `None` si nunca cambió.
+    changed_tick: Option<u64>,
+    press_tick: Option<u64>,
+    release_tick: Option<u64>,
+    stable_frames: usize,
+}
+
+impl<S: StateSemantics, Tm> KeySlot<S, Tm> {
+    /// Tecla recién rastreada: liberada y sin cambios ni flancos.
+    fn released() -> Self {
+        Self {
+            state: S::released(),
+            pressed_at: None,
+            last_accepted_press: None,
+            changed_tick: None,
+            press_tick: None,
+            release_tick: None,
+            stable_frames: 0,
+        }
+    }
+}
+
+/// Lo que un evento del historial cambió en el estado vivo, para que
+/// [`undo_last_and_revert`](HistoryAccessExt::undo_last_and_revert) pueda deshacerlo.
+#[derive(Debug, Clone)]
+struct Revert<K, S, Tm> {
+    /// Slot de la tecla antes del evento; `None` si no estaba rastreada.
+    slot: Option<KeySlot<S, Tm>>,
+    last_pressed: Option<K>,
+    /// Frames cerrados antes del evento.
+    frames: u64,
+}
+
+/// Copia del estado vivo de un [`MockInputState`], producida por
+/// [`snapshot`](InputStateExt::snapshot).
+#[derive(Debug, Clone)]
+pub struct MockSnapshot<K, S, Tm> {
+    keys: HashMap<K, KeySlot<S, Tm>>,
+    tick: u64,
+    last_pressed: Option<K>,
+}
+
+/// # Estado `MockInputState`
+///
+/// Implementación de **referencia** de [`InputStateExt`] y [`HistoryAccessExt`] (y
+/// por tanto de [`WithHistoryExt`](crate::WithHistoryExt)) para tests. No es un
+/// backend de producción: prioriza seguir al pie de la letra el contrato de los
+/// traits sobre la eficiencia.
+///
+/// Guarda las teclas en un `HashMap` y el historial en un `Vec`, sin límite de
+/// capacidad. El reloj es intercambiable: por defecto [`MockClock`], para que los
+/// tests controlen el tiempo con [`tick`](Self::tick) o, como lo haría un game
+/// loop, con [`FrameTimeExt::update_time`] (el tiempo parte de cero); con
+/// [`StdClock`](crate::StdClock) mide tiempo real.
+///
+/// ## Modelo de frames
+/// - [`advance_frame`](InputStateExt::advance_frame) avanza el tick interno en uno,
+///   y [`set_tick`](InputStateExt::set_tick) lo fija (solo hacia delante).
+/// - Cada `set_key` se registra en el historial, cambie o no el estado.
+/// - [`changed_since`](InputStateExt::changed_since) solo ve los cambios entre
+///   presionada y liberada: liberar una tecla que nunca se presionó no es un cambio.
+/// - [`remap`](InputStateExt::remap) está soportado, con remapeos que no se
+///   encadenan.
+/// - [`last_pressed`](InputStateExt::last_pressed) recuerda la última tecla
+///   presionada aunque ya se haya soltado.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::testing::MockInputState;
+///
+/// let mut input: MockInputState<KeyCode, KeyState> = MockInputState::default();
+/// input.press(KeyCode::Space);
+/// input.tick(Duration::from_millis(16));
+/// input.release(KeyCode::Space);
+/// input.tick(Duration::from_millis(100));
+/// input.press(KeyCode::Space);
+///
+/// assert!(input.is_double_tap(KeyCode::Space, Duration::from_millis(150)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockInputState<K, S, C: Clock = MockClock> {
+    clock: C,
+    keys: HashMap<K, KeySlot<S, C::Timestamp>>,
+    history: Vec<MockEvent<K, S, C::Timestamp>>,
+    /// Una entrada por evento de `history`; `None` para los importados, que no
+    /// pasaron por el estado vivo.
+    reverts: Vec<Option<Revert<K, S, C::Timestamp>>>,
+    tick: u64,
+    /// Frames cerrados con `advance_frame`; a diferencia de `tick`, `set_tick` no lo
+    /// cambia.
+    frames: u64,
+    suppress: HashMap<K, Duration>,
+    remaps: HashMap<K, K>,
+    last_pressed: Option<K>,
+}
+
+impl<K, S, C: Clock + Default> Default for MockInputState<K, S, C> {
+    fn default() -> Self {
+        Self::with_clock(C::default())
+    }
+}
+
+impl<K, S> MockInputState<K, S> {
+    /// Crea un estado vacío con un [`MockClock`] en el origen.
+    pub fn new() -> Self {
+        Self::with_clock(MockClock::new())
+    }
+}
+
+impl<K, S, C: Clock> MockInputState<K, S, C> {
+    /// Crea un estado vacío que mide el tiempo con `clock`.
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            keys: HashMap::new(),
+            history: Vec::new(),
+            reverts: Vec::new(),
+            tick: 0,
+            frames: 0,
+            suppress: HashMap::new(),
+            remaps: HashMap::new(),
+            last_pressed: None,
+        }
+    }
+
+    /// Reloj del estado.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Reloj del estado, para avanzarlo o ajustarlo desde el test.
+    pub fn clock_mut(&mut self) -> &mut C {
+        &mut self.clock
+    }
+}
+
+impl<K, S, C> MockInputState<K, S, C>
+where
+    K: Copy + Eq + Hash,
+    S: PressedState,
+    C: Clock,
+{
+    /// Presiona `key` con [`PressedState::pressed`].
+    pub fn press(&mut self, key: K) {
+        self.set_key(key, S::pressed());
+    }
+
+    /// Suelta `key` con [`StateSemantics::released`].
+    pub fn release(&mut self, key: K) {
+        self.set_key(key, S::released());
+    }
+}
+
+impl<K, S> MockInputState<K, S, MockClock>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics,
+{
+    /// Cierra el frame actual con [`advance_frame`](InputStateExt::advance_frame) y
+    /// avanza el reloj `duration`.
+    pub fn tick(&mut self, duration: Duration) {
+        self.advance_frame();
+        self.clock.advance(duration);
+    }
+}
+
+impl<K, S> FrameTimeExt<K, S> for MockInputState<K, S, MockClock>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics,
+{
+    type Time = Duration;
+
+    fn update_time(&mut self, now: Duration) {
+        let current = self.clock.now();
+        self.clock.advance(now.saturating_sub(current));
+    }
+
+    fn current_time(&self) -> Option<Duration> {
+        Some(self.clock.now())
+    }
+}
+
+impl<K, S, C> MockInputState<K, S, C>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics,
+    C: Clock,
+{
+    /// Aplica `state` a `key` (ya remapeada) sin registrarlo en el historial.
+    fn store(&mut self, key: K, state: S) {
+        let now = self.clock.now();
+        let tick = self.tick;
+        let window = self.suppress.get(&key).copied().unwrap_or_default();
+
+        let slot = self.keys.entry(key).or_insert_with(KeySlot::released);
+
+        let was_down = slot.state.is_down();
+        if was_down != state.is_down() {
+            slot.changed_tick = Some(tick);
+        }
+        slot.state = state;
+
+        match (was_down, state.is_down()) {
+            (false, true) => {
+                slot.pressed_at = Some(now);
+                let suppressed = !window.is_zero()
+                    && slot
+                        .last_accepted_press
+                        .is_some_and(|accepted| C::duration_between(accepted, now) < window);
+                if !suppressed {
+                    slot.press_tick = Some(tick);
+                    slot.last_accepted_press = Some(now);
+                }
+                self.last_pressed = Some(key);
+            }
+            (true, false) => {
+                slot.pressed_at = None;
+                slot.release_tick = Some(tick);
+                slot.stable_frames = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Aplica `state` a `key` (ya remapeada) y lo registra en el historial.
+    fn record(&mut self, key: K, state: S) {
+        let revert = Revert { slot: self.keys.get(&key).cloned(), last_pressed: self.last_pressed, frames: self.frames };
+        self.store(key, state);
+        self.history.push(MockEvent { key, state, at: self.clock.now() });
+        self.reverts.push(Some(revert));
+    }
+}
+
+impl<K, S, C> InputStateExt<K, S> for MockInputState<K, S, C>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics,
+    C: Clock,
+{
+    type Snapshot = MockSnapshot<K, S, C::Timestamp>;
+
+    fn set_key(&mut self, key: K, state: S) {
+        let key = self.remaps.get(&key).copied().unwrap_or(key);
+        self.record(key, state);
+    }
+
+    fn advance_frame(&mut self) {
+        for slot in self.keys.values_mut() {
+            if slot.state.is_down() {
+                slot.stable_frames = slot.stable_frames.saturating_add(1);
+            }
+        }
+        self.tick += 1;
+        self.frames += 1;
+    }
+
+    fn is_just_press(&self, key: K) -> bool {
+        self.keys
+            .get(&key)
+            .is_some_and(|slot| slot.press_tick.is_some_and(|tick| tick >= self.tick))
+    }
+
+    fn set_tick(&mut self, tick: u64) {
+        self.tick = self.tick.max(tick);
+    }
+
+    fn changed_since(&self, key: K, tick: u64) -> bool {
+        self.keys
+            .get(&key)
+            .is_some_and(|slot| slot.changed_tick.is_some_and(|changed| changed >= tick))
+    }
+
+    fn suppress_repeat(&mut self, key: K, window: Duration) {
+        self.suppress.insert(key, window);
+    }
+
+    fn state_of(&self, key: K) -> Option<S> {
+        self.keys.get(&key).map(|slot| slot.state)
+    }
+
+    fn is_just_released(&self, key: K) -> bool {
+        self.keys
+            .get(&key)
+            .is_some_and(|slot| slot.release_tick.is_some_and(|tick| tick >= self.tick))
+    }
+
+    fn time_pressed(&self, key: K) -> Option<Duration> {
+        let pressed_at = self.keys.get(&key)?.pressed_at?;
+        Some(C::duration_between(pressed_at, self.clock.now()))
+    }
+
+    fn held_stable(&self, key: K, frames: usize) -> bool {
+        self.is_pressed(key) && self.keys.get(&key).is_some_and(|slot| slot.stable_frames >= frames)
+    }
+
+    fn last_pressed(&self) -> Option<K> {
+        self.last_pressed
+    }
+
+    fn state_iter(&self) -> impl Iterator<Item = (K, S)> + '_ {
+        self.keys.iter().map(|(key, slot)| (*key, slot.state))
+    }
+
+    fn reset(&mut self) {
+        self.keys.clear();
+        self.last_pressed = None;
+    }
+
+    fn reset_key(&mut self, key: K) {
+        self.keys.remove(&key);
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        MockSnapshot { keys: self.keys.clone(), tick: self.tick, last_pressed: self.last_pressed }
+    }
+
+    fn restore(&mut self, snapshot: &Self::Snapshot) {
+        self.keys = snapshot.keys.clone();
+        self.tick = snapshot.tick;
+        self.last_pressed = snapshot.last_pressed;
+    }
+
+    fn release_all(&mut self) {
+        for key in self.keys_pressed() {
+            self.record(key, S::released());
+        }
+    }
+
+    fn remap(&mut self, from: K, to: K) -> bool {
+        if from == to {
+            self.remaps.remove(&from);
+        } else {
+            self.remaps.insert(from, to);
+        }
+        true
+    }
+
+    fn clear_remaps(&mut self) -> bool {
+        self.remaps.clear();
+        true
+    }
+}
+
+impl<K, S, C> HistoryAccessExt<K, S, MockEvent<K, S, C::Timestamp>> for MockInputState<K, S, C>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics + Hash,
+    C: Clock,
+    C::Timestamp: Hash + Add<Duration, Output = C::Timestamp>,
+{
+    type Clock = C;
+
+    fn history(&self) -> &[MockEvent<K, S, C::Timestamp>] {
+        &self.history
+    }
+
+    fn clear_history(&mut self) {
+        self.history.clear();
+        self.reverts.clear();
+    }
+
+    fn trim_history(&mut self, max: usize) {
+        let excess = self.history.len().saturating_sub(max);
+        self.history.drain(..excess);
+        self.reverts.drain(..excess);
+    }
+
+    fn since_last_event(&self) -> Option<Duration> {
+        let last = self.history.last()?;
+        Some(C::duration_between(last.at, self.clock.now()))
+    }
+
+    fn import_history(&mut self, records: &[HistoryRecord<K, S>]) {
+        let base = self.clock.now();
+        self.history = records
+            .iter()
+            .map(|record| MockEvent {
+                key: record.key,
+                state: record.state,
+                at: base + Duration::from_micros(record.offset_micros),
+            })
+            .collect();
+        self.reverts = records.iter().map(|_| None).collect();
+    }
+
+    fn dedup_consecutive(&mut self) {
+        let mut entries: Vec<_> = self.history.drain(..).zip(self.reverts.drain(..)).collect();
+        entries.dedup_by(|(later, _), (earlier, _)| later.key == earlier.key && later.state == earlier.state);
+        (self.history, self.reverts) = entries.into_iter().unzip();
+    }
+
+    fn undo_last(&mut self) -> Option<MockEvent<K, S, C::Timestamp>> {
+        self.reverts.pop();
+        self.history.pop()
+    }
+
+    fn undo_last_and_revert(&mut self) -> Option<MockEvent<K, S, C::Timestamp>> {
+        let undone = self.history.pop()?;
+        let Some(revert) = self.reverts.pop().flatten() else {
+            self.revert_from_history(&undone);
+            return Some(undone);
+        };
+
+        self.last_pressed = revert.last_pressed;
+        match revert.slot {
+            // Los frames cerrados desde el evento cuentan como mantenidos si la tecla
+            // ya estaba abajo: sin el evento, nunca se habría soltado.
+            Some(mut slot) => {
+                if slot.state.is_down() {
+                    let elapsed = usize::try_from(self.frames - revert.frames).unwrap_or(usize::MAX);
+                    slot.stable_frames = slot.stable_frames.saturating_add(elapsed);
+                }
+                self.keys.insert(undone.key, slot);
+            }
+            None => {
+                self.keys.remove(&undone.key);
+            }
+        }
+        Some(undone)
+    }
+}
+
+impl<K, S, C> MockInputState<K, S, C>
+where
+    K: Copy + Eq + Hash,
+    S: StateSemantics,
+    C: Clock,
+{
+    /// Deja la tecla de `undone` en el estado de su último evento restante en el
+    /// historial. Es la reversión de los eventos importados, que no guardan qué
+    /// cambiaron en el estado vivo.
+    fn revert_from_history(&mut self, undone: &MockEvent<K, S, C::Timestamp>) {
+        let mut earlier = self.history.iter().rev().filter(|event| event.key == undone.key);
+        let Some(previous) = earlier.next().copied() else {
+            self.keys.remove(&undone.key);
+            return;
+        };
+
+        // La pulsación vigente empezó en el primer evento de la racha presionada que
+        // termina en `previous`.
+        let pressed_at = previous.state.is_down().then(|| {
+            earlier
+                .take_while(|event| event.state.is_down())
+                .last()
+                .map_or(previous.at, |event| event.at)
+        });
+
+        // Una reset_key posterior pudo haber descartado el slot: se reconstruye.
+        let slot = self.keys.entry(undone.key).or_insert_with(KeySlot::released);
+        slot.state = previous.state;
+        slot.pressed_at = pressed_at;
+        if !previous.state.is_down() {
+            slot.stable_frames = 0;
+        }
+
+        // El flanco que produjo el evento deshecho desaparece con él.
+        if previous.state.is_down() != undone.state.is_down() {
+            if undone.state.is_down() {
+                slot.press_tick = None;
+            } else {
+                slot.release_tick = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockClock, MockEvent};
+    use crate::{HistoryAccessExt, InputStateExt};
+    use crate::testing::fixtures::{Btn, Input, Key, ms};
+
+    #[test]
+    fn advance_frame_consumes_the_edges() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.press(Key::B);
+        input.release(Key::B);
+
+        assert!(input.is_just_press(Key::A));
+        assert!(input.is_just_press(Key::B));
+        assert!(input.is_just_released(Key::B));
+
+        input.advance_frame();
+
+        assert!(input.is_pressed(Key::A));
+        assert!(!input.is_just_press(Key::A));
+        assert!(!input.is_just_press(Key::B));
+        assert!(!input.is_just_released(Key::B));
+    }
+
+    #[test]
+    fn one_frame_blip_is_not_stable() {
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Down);
+        input.advance_frame();
+
+        assert!(input.held_stable(Key::A, 1));
+        assert!(!input.held_stable(Key::A, 3));
+
+        input.set_key(Key::A, Btn::Up);
+        input.set_key(Key::A, Btn::Down);
+        input.advance_frame();
+
+        assert!(input.is_pressed(Key::A));
+        assert!(!input.held_stable(Key::A, 2));
+    }
+
+    #[test]
+    fn three_frame_hold_is_stable() {
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Down);
+        for _ in 0..3 {
+            input.advance_frame();
+        }
+
+        assert!(input.held_stable(Key::A, 3));
+        assert!(!input.held_stable(Key::A, 4));
+    }
+
+    #[test]
+    fn release_restarts_the_frame_count() {
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Down);
+        input.advance_frame();
+        input.advance_frame();
+        input.set_key(Key::A, Btn::Up);
+        input.set_key(Key::A, Btn::Down);
+        input.advance_frame();
+
+        assert!(!input.held_stable(Key::A, 2));
+        assert!(input.held_stable(Key::A, 0));
+        assert!(!input.held_stable(Key::B, 0));
+    }
+
+    #[test]
+    fn second_press_within_the_window_is_suppressed() {
+        let mut input = Input::new();
+        input.suppress_repeat(Key::A, ms(100));
+
+        input.press(Key::A);
+        assert!(input.is_just_press(Key::A));
+        input.tick(ms(10));
+        input.release(Key::A);
+        input.tick(ms(40));
+
+        input.press(Key::A);
+        assert!(input.is_pressed(Key::A));
+        assert!(!input.is_just_press(Key::A));
+        input.tick(ms(10));
+        input.release(Key::A);
+        input.tick(ms(100));
+
+        input.press(Key::A);
+        assert!(input.is_just_press(Key::A));
+    }
+
+    #[test]
+    fn suppression_is_per_key() {
+        let mut input = Input::new();
+        input.suppress_repeat(Key::A, ms(100));
+
+        input.press(Key::B);
+        input.tick(ms(10));
+        input.release(Key::B);
+        input.tick(ms(10));
+        input.press(Key::B);
+
+        assert!(input.is_just_press(Key::B));
+    }
+
+    #[test]
+    fn observers_at_different_ticks_see_the_press_once() {
+        let mut input = Input::new();
+        // (cada cuántos ticks se ejecuta, primer tick no procesado, pulsaciones vistas)
+        let mut observers = [(1, 0, 0), (3, 0, 0)];
+
+        for tick in 1..=6 {
+            input.set_tick(tick);
+            if tick == 2 {
+                input.press(Key::A);
+                assert!(input.is_just_press(Key::A));
+            }
+
+            for (every, next_unseen, seen) in &mut observers {
+                if tick % *every == 0 {
+                    if input.changed_since(Key::A, *next_unseen) && input.is_pressed(Key::A) {
+                        *seen += 1;
+                    }
+                    *next_unseen = tick + 1;
+                }
+            }
+        }
+
+        assert_eq!(observers.map(|(_, _, seen)| seen), [1, 1]);
+        assert!(!input.is_just_press(Key::A));
+    }
+
+    #[test]
+    fn ticks_never_go_backwards() {
+        let mut input = Input::new();
+        input.set_tick(5);
+        input.set_tick(2);
+        input.press(Key::A);
+
+        assert!(input.changed_since(Key::A, 5));
+        assert!(!input.changed_since(Key::A, 6));
+    }
+
+    #[test]
+    fn releasing_an_untracked_key_is_not_a_change() {
+        let mut input = Input::new();
+        input.release(Key::A);
+
+        assert!(!input.changed_since(Key::A, 0));
+    }
+
+    #[test]
+    fn reset_key_acts_as_a_synthetic_release_of_one_key() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.press(Key::B);
+        input.tick(ms(100));
+        input.reset_key(Key::A);
+
+        assert!(input.is_released(Key::A));
+        assert!(!input.is_just_press(Key::A));
+        assert!(!input.is_just_released(Key::A));
+        assert_eq!(input.time_pressed(Key::A), None);
+        assert_eq!(input.time_pressed(Key::B), Some(ms(100)));
+
+        input.press(Key::A);
+        assert!(input.is_just_press(Key::A));
+    }
+
+    #[test]
+    fn restore_rewinds_keys_edges_and_timers() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        let frame = input.snapshot();
+
+        input.tick(ms(16));
+        input.release(Key::A);
+        input.press(Key::B);
+        input.restore(&frame);
+
+        assert!(input.is_just_press(Key::A));
+        assert!(input.is_released(Key::B));
+        assert_eq!(input.last_pressed(), Some(Key::A));
+        assert_eq!(input.time_pressed(Key::A), Some(ms(16)));
+    }
+
+    #[test]
+    fn resimulating_from_a_snapshot_is_deterministic() {
+        fn resimulate(input: &mut Input) {
+            input.advance_frame();
+            input.press(Key::B);
+        }
+
+        fn observe(input: &Input) -> (Vec<Key>, Vec<Key>, Option<Key>) {
+            let mut pressed = input.keys_pressed();
+            pressed.sort();
+            (pressed, input.just_pressed_keys(), input.last_pressed())
+        }
+
+        let mut input = Input::new();
+        input.press(Key::A);
+        let frame = input.snapshot();
+
+        resimulate(&mut input);
+        let first = observe(&input);
+        input.restore(&frame);
+        resimulate(&mut input);
+
+        assert_eq!(observe(&input), first);
+    }
+
+    #[test]
+    fn undo_and_revert_restores_the_previous_state() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.tick(ms(10));
+        input.release(Key::A);
+        input.advance_frame();
+
+        let undone = input.undo_last_and_revert().unwrap();
+        assert_eq!((undone.key, undone.state), (Key::A, Btn::Up));
+        assert!(input.is_pressed(Key::A));
+        assert_eq!(input.time_pressed(Key::A), Some(ms(10)));
+        assert!(!input.is_just_press(Key::A));
+        assert!(input.held_stable(Key::A, 2));
+        assert_eq!(input.history().len(), 1);
+    }
+
+    #[test]
+    fn undo_and_revert_restores_frame_counters_and_last_pressed() {
+        let mut input = Input::new();
+        input.press(Key::B);
+        input.set_tick(1);
+        input.press(Key::A);
+        input.set_tick(2);
+
+        input.undo_last_and_revert();
+        assert_eq!(input.last_pressed(), Some(Key::B));
+        assert!(!input.changed_since(Key::A, 0));
+
+        input.press(Key::A);
+        input.advance_frame();
+        input.advance_frame();
+        input.release(Key::A);
+        input.advance_frame();
+
+        input.undo_last_and_revert();
+        assert!(input.held_stable(Key::A, 3));
+        assert!(!input.changed_since(Key::A, 3));
+        assert!(input.changed_since(Key::A, 2));
+        assert_eq!(input.last_pressed(), Some(Key::A));
+    }
+
+    #[test]
+    fn undoing_the_only_event_releases_the_key() {
+        let mut input = Input::new();
+        input.press(Key::B);
+        input.press(Key::A);
+
+        input.undo_last_and_revert();
+        assert!(input.is_released(Key::A));
+        assert!(!input.is_just_press(Key::A));
+        assert!(!input.is_just_released(Key::A));
+        assert!(input.is_just_press(Key::B));
+
+        input.undo_last_and_revert();
+        assert!(input.keys_pressed().is_empty());
+        assert!(input.undo_last_and_revert().is_none());
+    }
+
+    #[test]
+    fn remapped_keys_reach_state_and_history_as_the_target() {
+        let mut input = Input::new();
+        assert!(input.remap(Key::A, Key::B));
+
+        input.set_key(Key::A, Btn::Down);
+
+        assert!(input.is_pressed(Key::B) && input.is_just_press(Key::B));
+        assert!(!input.is_pressed(Key::A));
+        assert_eq!(input.history().iter().map(|event| event.key).collect::<Vec<_>>(), [Key::B]);
+    }
+
+    #[test]
+    fn remaps_do_not_chain_so_two_of_them_swap_keys() {
+        let mut input = Input::new();
+        input.remap(Key::A, Key::B);
+        input.remap(Key::B, Key::A);
+        input.remap(Key::C, Key::D);
+
+        input.set_key(Key::A, Btn::Down);
+        assert_eq!(input.keys_pressed(), [Key::B]);
+
+        input.release_all();
+        input.set_key(Key::B, Btn::Down);
+        assert_eq!(input.keys_pressed(), [Key::A]);
+    }
+
+    #[test]
+    fn self_remap_and_clear_remaps_restore_the_original_keys() {
+        let mut input = Input::new();
+        input.remap(Key::A, Key::B);
+        input.remap(Key::C, Key::D);
+
+        input.remap(Key::A, Key::A);
+        input.set_key(Key::A, Btn::Down);
+        assert!(input.is_pressed(Key::A));
+
+        assert!(input.clear_remaps());
+        input.set_key(Key::C, Btn::Down);
+        assert!(input.is_pressed(Key::C) && !input.is_pressed(Key::D));
+    }
+
+    #[test]
+    fn events_are_stamped_with_the_plugged_clock() {
+        let mut clock = MockClock::new();
+        clock.advance(ms(500));
+        let mut input = Input::with_clock(clock);
+
+        input.press(Key::A);
+        input.tick(ms(16));
+        input.release(Key::A);
+
+        assert!(input.is_just_released(Key::A));
+        assert_eq!(
+            input.history(),
+            [
+                MockEvent { key: Key::A, state: Btn::Down, at: ms(500) },
+                MockEvent { key: Key::A, state: Btn::Up, at: ms(516) },
+            ]
+        );
+    }
+
+    #[test]
+    fn last_pressed_survives_the_release() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.press(Key::B);
+        input.release(Key::B);
+
+        assert_eq!(input.last_pressed(), Some(Key::B));
+
+        input.reset();
+        assert_eq!(input.last_pressed(), None);
+    }
+}
@@ -0,0 +1,61 @@
+//! # Utilidades de testing
+//!
+//! Implementaciones de referencia y herramientas para **probar** runtimes y estados
+//! de input sin dispositivos reales. Disponible solo con el feature `testing` (o su
+//! alias `test-util`):
+//!
+//! ```toml
+//! [dev-dependencies]
+//! orbit_input_core = { version = "0.2", features = ["testing"] }
+//! ```
+//!
+//! Para verificar que un backend cumple el contrato de los traits, la macro
+//! [`conformance_tests!`](crate::conformance_tests) genera una batería de tests a
+//! partir de un constructor del estado (ver [`conformance`]).
+//!
+//! Nada de este módulo está pensado para producción.
+
+mod clock;
+pub mod conformance;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod fuzz;
+mod mock_state;
+mod null_runtime;
+
+pub use clock::MockClock;
+pub use fuzz::{fuzz_backend, fuzz_history_backend, InvariantViolation};
+pub use mock_state::{MockEvent, MockInputState, MockSnapshot, PressedState};
+pub use null_runtime::NullRuntime;
+
+use std::ops::DerefMut;
+
+/// Mutex usado por las implementaciones de referencia para el estado compartido.
+///
+/// Es `parking_lot::Mutex` con el feature `parking_lot` y `std::sync::Mutex` en
+/// caso contrario.
+#[cfg(feature = "parking_lot")]
+pub type Lock<T> = parking_lot::Mutex<T>;
+
+/// Mutex usado por las implementaciones de referencia para el estado compartido.
+///
+/// Es `parking_lot::Mutex` con el feature `parking_lot` y `std::sync::Mutex` en
+/// caso contrario.
+#[cfg(not(feature = "parking_lot"))]
+pub type Lock<T> = std::sync::Mutex<T>;
+
+/// Adquiere un [`Lock`] independientemente del backend de sincronización.
+///
+/// Con `std::sync::Mutex` un lock envenenado se recupera igualmente: en un entorno
+/// de tests el estado sigue siendo útil para diagnosticar el pánico original.
+pub fn lock<T>(lock: &Lock<T>) -> impl DerefMut<Target = T> + '_ {
+    #[cfg(feature = "parking_lot")]
+    {
+        lock.lock()
+    }
+
+    #[cfg(not(feature = "parking_lot"))]
+    {
+        lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,287 @@
+use std::convert::Infallible;
+use std::future::poll_fn;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::Instant;
+
+use super::{lock, Lock};
+use crate::{DeviceChange, InjectableRuntime, InputStateExt, PollRuntimeExt, RuntimeDiagnostics, RuntimeExt, StateSemantics};
+
+/// # Runtime `NullRuntime`
+///
+/// Implementación de [`RuntimeExt`] **sin dispositivos**, pensada para CI, servidores
+/// y tests headless.
+///
+/// No captura nada del sistema operativo: el estado compartido solo cambia mediante
+/// [`inject`](InjectableRuntime::inject), que aplica el evento directamente y cuenta
+/// como evento procesado. Todas las operaciones de ciclo de vida tienen éxito siempre
+/// (`Error = Infallible`).
+///
+/// Su único dispositivo es el **canal de inyección**: la entrada por defecto de
+/// [`list_devices`](RuntimeExt::list_devices), que se abre con
+/// [`initialize`](RuntimeExt::initialize) y se cierra con [`stop`](RuntimeExt::stop).
+///
+/// - [`run`](RuntimeExt::run) retorna inmediatamente: no hay dispositivos que escuchar.
+/// - [`ready`](RuntimeExt::ready) se resuelve cuando el runtime está inicializado y en
+///   marcha. Sin `initialize` queda pendiente: nada más puede abrir el canal.
+/// - [`backend_name`](RuntimeExt::backend_name) retorna `"Null"`.
+/// - [`diagnostics`](RuntimeExt::diagnostics) reporta un dispositivo en marcha y
+///   ninguno detenido, y cero vueltas de loop; `last_event` se mide desde `new()`.
+/// - [`reset_state`](RuntimeExt::reset_state) reemplaza el estado por `S::default()`.
+/// - [`pause`](RuntimeExt::pause) hace que `inject` **descarte** los eventos (se
+///   cuentan en `events_dropped`) hasta [`unpause`](RuntimeExt::unpause).
+/// - [`on_device_change`](RuntimeExt::on_device_change) descarta el manejador: no hay
+///   dispositivos que conectar ni desconectar.
+/// - [`frame_tick`](RuntimeExt::frame_tick) avanza el frame del estado, incluidos
+///   los cambios hechos directamente sobre el estado compartido.
+/// - [`set_overflow_policy`](RuntimeExt::set_overflow_policy) retorna `false`: no
+///   hay cola de eventos que pueda llenarse.
+/// - [`poll`](PollRuntimeExt::poll) retorna `Ok(0)`: los eventos inyectados ya se
+///   aplicaron al llamar a `inject`.
+///
+/// ## Parámetros genéricos
+/// - `S`: Estado compartido, que implementa [`InputStateExt<K, St>`](InputStateExt).
+/// - `K`: Tipo de tecla que acepta [`inject`](InjectableRuntime::inject).
+/// - `St`: Tipo de estado de tecla que acepta `inject`.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::testing::{lock, NullRuntime};
+///
+/// let (mut runtime, shared) = NullRuntime::<MyInputState, KeyCode, KeyState>::new()?;
+/// runtime.initialize().await?;
+///
+/// runtime.inject(KeyCode::Space, KeyState::Pressed)?;
+/// assert!(lock(&shared).is_pressed(KeyCode::Space));
+/// assert_eq!(runtime.events_processed(), 1);
+/// ```
+pub struct NullRuntime<S, K, St> {
+    state: Arc<Lock<S>>,
+    running: bool,
+    paused: bool,
+    started: Instant,
+    diagnostics: RuntimeDiagnostics,
+    _keys: PhantomData<fn(K, St)>,
+}
+
+impl<S, K, St> RuntimeExt for NullRuntime<S, K, St>
+where
+    S: InputStateExt<K, St> + Default + Send + Sync + 'static,
+    K: Copy + PartialEq + Hash,
+    St: StateSemantics,
+{
+    type Error = Infallible;
+    type State = S;
+    type SharedState = Arc<Lock<S>>;
+    type DeviceEvent = DeviceChange;
+
+    fn new() -> Result<(Self, Self::SharedState), Self::Error> {
+        let state = Arc::new(Lock::new(S::default()));
+        let runtime = Self {
+            state: state.clone(),
+            running: false,
+            paused: false,
+            started: Instant::now(),
+            diagnostics: RuntimeDiagnostics::new("Null"),
+            _keys: PhantomData,
+        };
+        Ok((runtime, state))
+    }
+
+    async fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.running = true;
+        Ok(())
+    }
+
+    async fn ready(&self) {
+        poll_fn(|_| if self.running { Poll::Ready(()) } else { Poll::Pending }).await;
+    }
+
+    async fn run(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), Self::Error> {
+        self.running = false;
+        Ok(())
+    }
+
+    fn restart(&mut self) -> Result<(), Self::Error> {
+        self.running = true;
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<(), Self::Error> {
+        self.paused = true;
+        Ok(())
+    }
+
+    fn unpause(&mut self) -> Result<(), Self::Error> {
+        self.paused = false;
+        Ok(())
+    }
+
+    fn on_device_change(&mut self, handler: impl FnMut(Self::DeviceEvent) + Send + 'static) {
+        let _ = handler;
+    }
+
+    fn is_running(&self) -> bool {
+        self.running
+    }
+
+    fn diagnostics(&self) -> RuntimeDiagnostics {
+        RuntimeDiagnostics { device_count: usize::from(self.running), ..self.diagnostics.clone() }
+    }
+
+    fn reset_state(&mut self) {
+        *lock(&self.state) = S::default();
+    }
+
+    fn update(&mut self) {}
+
+    fn frame_tick(&self) {
+        lock(&self.state).advance_frame();
+    }
+}
+
+impl<S, K, St> PollRuntimeExt for NullRuntime<S, K, St> {
+    type Error = Infallible;
+
+    fn poll(&mut self) -> Result<usize, Self::Error> {
+        Ok(0)
+    }
+}
+
+impl<S, K, St> InjectableRuntime<K, St> for NullRuntime<S, K, St>
+where
+    S: InputStateExt<K, St> + Default + Send + Sync + 'static,
+    K: Copy + PartialEq + Hash,
+    St: StateSemantics,
+{
+    /// Llama a [`set_key`](InputStateExt::set_key) bajo el lock e incrementa
+    /// [`events_processed`](RuntimeExt::events_processed). Si el runtime está
+    /// pausado, el evento se descarta y se cuenta en
+    /// [`RuntimeDiagnostics::events_dropped`].
+    fn inject(&mut self, key: K, state: St) -> Result<(), Self::Error> {
+        if self.paused {
+            self.diagnostics.events_dropped += 1;
+            return Ok(());
+        }
+        lock(&self.state).set_key(key, state);
+        self.diagnostics.events_processed += 1;
+        self.diagnostics.last_event = Some(self.started.elapsed());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HistoryAccessExt;
+    use crate::testing::fixtures::{block_on, play, Btn, Input, Key};
+
+    #[test]
+    fn injection_drives_the_shared_state_across_frames() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        block_on(runtime.initialize()).unwrap();
+        block_on(runtime.ready());
+        assert!(runtime.is_running());
+
+        runtime.inject(Key::A, Btn::Down).unwrap();
+        assert!(lock(&shared).is_just_press(Key::A));
+
+        runtime.frame_tick();
+        assert!(lock(&shared).is_pressed(Key::A));
+        assert!(!lock(&shared).is_just_press(Key::A));
+
+        runtime.inject(Key::A, Btn::Up).unwrap();
+        runtime.inject(Key::B, Btn::Down).unwrap();
+        assert!(lock(&shared).is_just_released(Key::A));
+        assert_eq!(lock(&shared).keys_pressed(), [Key::B]);
+
+        runtime.frame_tick();
+        assert!(!lock(&shared).is_just_released(Key::A));
+        assert_eq!(runtime.events_processed(), 3);
+        assert_eq!(runtime.diagnostics().backend, "Null");
+    }
+
+    #[test]
+    fn run_returns_without_devices() {
+        let (mut runtime, _) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        assert_eq!(block_on(runtime.run()), Ok(()));
+        assert_eq!(runtime.backend_name(), "Null");
+        assert_eq!(runtime.diagnostics().device_count, 0);
+    }
+
+    #[test]
+    fn frame_tick_consumes_edges_set_on_the_shared_state() {
+        let (runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        lock(&shared).set_key(Key::A, Btn::Down);
+
+        runtime.frame_tick();
+
+        assert!(lock(&shared).is_pressed(Key::A));
+        assert!(!lock(&shared).is_just_press(Key::A));
+    }
+
+    #[test]
+    fn reset_state_restores_the_default() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        runtime.inject(Key::A, Btn::Down).unwrap();
+
+        runtime.reset_state();
+
+        assert!(!lock(&shared).is_pressed(Key::A));
+    }
+
+    #[test]
+    fn paused_runtime_drops_events_but_keeps_running() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        block_on(runtime.initialize()).unwrap();
+        runtime.inject(Key::A, Btn::Down).unwrap();
+
+        runtime.pause().unwrap();
+        runtime.inject(Key::B, Btn::Down).unwrap();
+        assert!(runtime.is_running());
+        assert!(!lock(&shared).is_pressed(Key::B));
+        assert!(lock(&shared).is_pressed(Key::A));
+
+        runtime.unpause().unwrap();
+        runtime.inject(Key::C, Btn::Down).unwrap();
+        assert!(lock(&shared).is_pressed(Key::C));
+        assert_eq!(runtime.events_processed(), 2);
+        assert_eq!(runtime.diagnostics().events_dropped, 1);
+    }
+
+    #[test]
+    fn injected_events_reach_the_history() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        assert_eq!(runtime.diagnostics().last_event, None);
+
+        runtime.inject(Key::A, Btn::Down).unwrap();
+        runtime.inject(Key::B, Btn::Down).unwrap();
+
+        let state = lock(&shared);
+        let keys: Vec<Key> = state.history().iter().map(|event| event.key).collect();
+        assert_eq!(keys, [Key::A, Key::B]);
+        assert_eq!(state.last_pressed(), Some(Key::B));
+        assert!(runtime.diagnostics().last_event.is_some());
+    }
+
+    #[test]
+    fn exported_replay_can_be_injected() {
+        let recorded = play(&[(0, Key::A, Btn::Down), (16, Key::B, Btn::Down), (32, Key::A, Btn::Up)]);
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        for record in &recorded.export_history() {
+            runtime.inject(record.key, record.state).unwrap();
+        }
+
+        assert_eq!(lock(&shared).keys_pressed(), recorded.keys_pressed());
+        assert_eq!(lock(&shared).history().len(), 3);
+        assert_eq!(runtime.events_processed(), 3);
+    }
+}
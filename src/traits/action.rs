@@ -0,0 +1,794 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use crate::{InputStateExt, StateSemantics};
+
+/// # Tabla `ActionMap`
+///
+/// Tabla de asociaciones **acción → tecla** usada por [`ActionMapExt`] y
+/// [`ProfileSwitchExt`].
+///
+/// Es un contenedor simple (sin lógica de estado) que los runtimes pueden usar
+/// como almacenamiento de sus bindings, o intercambiar entre perfiles.
+/// Una misma acción puede tener varias teclas asociadas.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::ActionMap;
+///
+/// let mut on_foot = ActionMap::new();
+/// on_foot.bind(Action::Jump, KeyCode::Space);
+/// on_foot.bind(Action::Fire, KeyCode::MouseLeft);
+///
+/// if on_foot.is_action_pressed(&input, Action::Jump) {
+///     player.jump();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionMap<A, K> {
+    bindings: Vec<(A, K)>,
+}
+
+impl<A, K> ActionMap<A, K>
+where
+    A: Copy + PartialEq,
+    K: Copy + PartialEq + Hash,
+{
+    /// Crea una tabla vacía.
+    pub fn new() -> Self {
+        Self { bindings: Vec::new() }
+    }
+
+    /// Asocia `key` a `action`. Asociar dos veces el mismo par no lo duplica.
+    pub fn bind(&mut self, action: A, key: K) {
+        if !self.bindings.contains(&(action, key)) {
+            self.bindings.push((action, key));
+        }
+    }
+
+    /// Elimina todas las teclas asociadas a `action`.
+    pub fn unbind(&mut self, action: A) {
+        self.bindings.retain(|(bound, _)| *bound != action);
+    }
+
+    /// Itera las teclas asociadas a `action`, en orden de registro.
+    pub fn keys_for(&self, action: A) -> impl Iterator<Item = K> + '_ {
+        self.bindings
+            .iter()
+            .filter(move |(bound, _)| *bound == action)
+            .map(|(_, key)| *key)
+    }
+
+    /// Agrupa los bindings por acción: cada acción con sus teclas, en orden de
+    /// registro. Es el formato de [`ActionMapExt::export_bindings`].
+    pub fn to_bindings(&self) -> Vec<(A, Vec<K>)> {
+        let mut grouped: Vec<(A, Vec<K>)> = Vec::new();
+        for &(action, key) in &self.bindings {
+            match grouped.iter_mut().find(|(bound, _)| *bound == action) {
+                Some((_, keys)) => keys.push(key),
+                None => grouped.push((action, alloc::vec![key])),
+            }
+        }
+        grouped
+    }
+
+    /// Retorna `true` si **alguna** tecla asociada a `action` está presionada en `input`.
+    pub fn is_action_pressed<S, I>(&self, input: &I, action: A) -> bool
+    where
+        S: StateSemantics,
+        I: InputStateExt<K, S>,
+    {
+        self.keys_for(action).any(|key| input.is_pressed(key))
+    }
+
+    /// Retorna `true` si **alguna** tecla asociada a `action` fue presionada en este frame.
+    pub fn is_action_just_pressed<S, I>(&self, input: &I, action: A) -> bool
+    where
+        S: StateSemantics,
+        I: InputStateExt<K, S>,
+    {
+        self.keys_for(action).any(|key| input.is_just_press(key))
+    }
+}
+
+impl<A, K> Default for ActionMap<A, K>
+where
+    A: Copy + PartialEq,
+    K: Copy + PartialEq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Conjunto de bindings **portable**, producido por
+/// [`ActionMapExt::export_bindings`] y consumido por
+/// [`ActionMapExt::import_bindings`].
+///
+/// Con la feature `serde` implementa `Serialize`/`Deserialize` (si `A` y `K` lo
+/// implementan), para guardar los controles en el archivo de configuración del
+/// juego independientemente del backend.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let saved = BindingSet::from(input.export_bindings());
+/// std::fs::write("controls.json", serde_json::to_string(&saved)?)?;
+///
+/// let loaded: BindingSet<Action, KeyCode> = serde_json::from_str(&text)?;
+/// input.import_bindings(&loaded.bindings);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindingSet<A, K> {
+    /// Cada acción con sus teclas, en orden de registro.
+    pub bindings: Vec<(A, Vec<K>)>,
+}
+
+impl<A, K> From<Vec<(A, Vec<K>)>> for BindingSet<A, K> {
+    fn from(bindings: Vec<(A, Vec<K>)>) -> Self {
+        Self { bindings }
+    }
+}
+
+/// Estado del modo de captura de [`ActionMapExt::begin_rebind`], retornado por
+/// [`ActionMapExt::poll_rebind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RebindStatus<K> {
+    /// No hay ninguna captura en curso.
+    Idle,
+    /// Esperando la siguiente tecla.
+    Listening,
+    /// Se capturó esta tecla y ya quedó asociada a la acción. La captura terminó.
+    Captured(K),
+    /// Se presionó la tecla de cancelación; los bindings no cambiaron. La captura
+    /// terminó.
+    Cancelled,
+}
+
+/// # Trait `ActionMapExt`
+///
+/// Desacopla las **teclas físicas** de las **acciones lógicas** del juego
+/// ("saltar", "disparar").
+///
+/// El motor consulta intenciones (`A`) en lugar de teclas (`K`); la
+/// implementación resuelve cada consulta contra su [`InputStateExt`] subyacente.
+///
+/// ## Varias teclas por acción
+/// Una acción puede tener cualquier número de teclas, con semántica **any-of**: la
+/// acción está activa si **alguna** de sus teclas lo está. Así una UI de rebinding
+/// puede ofrecer una tecla primaria y una secundaria sin lógica adicional. Las
+/// implementaciones pueden usar [`ActionMap`] como almacenamiento, que ya resuelve
+/// las consultas de esta forma.
+///
+/// ## Parámetros genéricos
+/// - `A`: Tipo de acción lógica (normalmente un enum del juego).
+/// - `K`: Tipo de tecla del estado subyacente.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// input.bind(Action::Jump, KeyCode::Space);
+/// input.bind(Action::Jump, KeyCode::ArrowUp); // segunda tecla, no reemplaza a Space
+///
+/// if input.is_action_just_pressed(Action::Jump) {
+///     player.jump();
+/// }
+/// ```
+pub trait ActionMapExt<A, K>
+where
+    A: Copy + PartialEq,
+    K: Copy + PartialEq + Hash,
+{
+    /// Asocia `key` a `action`, **además** de las teclas que ya tuviera. Asociar dos
+    /// veces el mismo par no lo duplica.
+    fn bind(&mut self, action: A, key: K);
+
+    /// Elimina todas las teclas asociadas a `action`.
+    fn unbind(&mut self, action: A);
+
+    /// Retorna `true` si la acción está activa: **alguna** de sus teclas está
+    /// presionada según [`InputStateExt::is_pressed`]. Una acción sin teclas nunca
+    /// está activa.
+    fn is_action_pressed(&self, action: A) -> bool;
+
+    /// Retorna `true` si **alguna** de las teclas de la acción fue presionada en este
+    /// frame, según [`InputStateExt::is_just_press`].
+    fn is_action_just_pressed(&self, action: A) -> bool;
+
+    // === PERSISTENCIA ===
+
+    /// Retorna todos los bindings: cada acción con sus teclas, en orden de registro.
+    /// Las acciones sin teclas no aparecen.
+    ///
+    /// La implementación por defecto no conoce el almacenamiento y retorna una lista
+    /// vacía, así que las implementaciones que quieran persistir sus controles deben
+    /// sobrescribirla. Con [`ActionMap`] basta con [`ActionMap::to_bindings`].
+    fn export_bindings(&self) -> Vec<(A, Vec<K>)> {
+        Vec::new()
+    }
+
+    /// Aplica `bindings`: cada acción listada **reemplaza** sus teclas por las dadas;
+    /// las acciones que no aparecen conservan las suyas.
+    ///
+    /// Una tecla que no existe en el dispositivo actual (por ejemplo, un botón de
+    /// mando en un backend de teclado) se importa igualmente y simplemente nunca se
+    /// activa, de modo que vuelve a funcionar al cambiar de backend.
+    ///
+    /// La implementación por defecto usa [`unbind`](Self::unbind) y
+    /// [`bind`](Self::bind).
+    fn import_bindings(&mut self, bindings: &[(A, Vec<K>)]) {
+        for (action, keys) in bindings {
+            self.unbind(*action);
+            for key in keys {
+                self.bind(*action, *key);
+            }
+        }
+    }
+
+    // === REBINDING ===
+
+    /// Entra en modo **captura**: la siguiente tecla presionada se asociará a
+    /// `action`, reemplazando sus teclas actuales.
+    ///
+    /// Es el flujo estándar de una pantalla de controles ("pulsa una tecla para
+    /// Saltar..."). Llamarlo durante otra captura la reinicia con la nueva acción.
+    ///
+    /// La implementación por defecto no soporta la captura y no hace nada: con ella
+    /// [`poll_rebind`](Self::poll_rebind) retorna siempre [`RebindStatus::Idle`], y
+    /// la pantalla de controles puede ofrecer otra forma de editar los bindings.
+    ///
+    /// ## Relación con los contextos
+    /// Mientras la captura está en curso (y durante el frame en que termina),
+    /// [`is_action_pressed`](Self::is_action_pressed) e
+    /// [`is_action_just_pressed`](Self::is_action_just_pressed) retornan `false` para
+    /// todas las acciones: la tecla capturada no dispara ninguna acción de gameplay.
+    /// Si el juego usa [`ContextStackExt`](crate::ContextStackExt), conviene además
+    /// apilar un contexto propio para la pantalla de controles mientras dure.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// input.begin_rebind(Action::Jump);
+    ///
+    /// // Cada frame, en la pantalla de controles:
+    /// match input.poll_rebind() {
+    ///     RebindStatus::Listening => ui.show("Pulsa una tecla..."),
+    ///     RebindStatus::Captured(key) => ui.show_binding(Action::Jump, key),
+    ///     RebindStatus::Cancelled | RebindStatus::Idle => ui.close_prompt(),
+    /// }
+    /// ```
+    fn begin_rebind(&mut self, action: A) {
+        let _ = action;
+    }
+
+    /// Avanza la captura iniciada con [`begin_rebind`](Self::begin_rebind); se llama
+    /// una vez por frame.
+    ///
+    /// Toma la primera tecla de
+    /// [`just_pressed_keys`](InputStateExt::just_pressed_keys) del estado subyacente:
+    /// - Si es la tecla de cancelación (ver
+    ///   [`set_rebind_cancel_key`](Self::set_rebind_cancel_key)), retorna
+    ///   [`RebindStatus::Cancelled`] sin tocar los bindings.
+    /// - Si es cualquier otra, la asocia a la acción y retorna
+    ///   [`RebindStatus::Captured`].
+    /// - Si no hubo pulsaciones, retorna [`RebindStatus::Listening`].
+    ///
+    /// Fuera de una captura retorna [`RebindStatus::Idle`], que es lo único que
+    /// retorna la implementación por defecto.
+    fn poll_rebind(&mut self) -> RebindStatus<K> {
+        RebindStatus::Idle
+    }
+
+    /// Define la tecla que cancela una captura en curso (normalmente `Escape`). Esta
+    /// tecla no puede asociarse a ninguna acción mediante captura.
+    ///
+    /// Por defecto no hace nada, como [`begin_rebind`](Self::begin_rebind).
+    fn set_rebind_cancel_key(&mut self, key: K) {
+        let _ = key;
+    }
+}
+
+/// # Trait `CommandQueueExt`
+///
+/// Convierte pulsaciones en **objetos comando** (patrón *command*), separando la
+/// captura de input de la ejecución.
+///
+/// Pensado para juegos de estrategia y editores, donde las acciones se encolan,
+/// se validan o se deshacen: el input solo produce comandos `Cmd` y el sistema de
+/// comandos decide qué hacer con ellos.
+///
+/// ## Orden de los comandos
+/// [`drain_commands`](Self::drain_commands) entrega los comandos en **orden de
+/// pulsación**: si `Q` se presionó antes que `W` en el mismo frame, el comando de `Q`
+/// va primero. Una tecla con varias fábricas asociadas produce sus comandos en orden
+/// de registro.
+///
+/// ## Parámetros genéricos
+/// - `K`: Tipo de tecla.
+/// - `Cmd`: Tipo de comando producido (normalmente un enum o `Box<dyn Command>`).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// input.bind_command(KeyCode::B, || EditorCmd::PlaceBlock);
+/// input.bind_command(KeyCode::Delete, || EditorCmd::DeleteSelection);
+///
+/// for cmd in input.drain_commands() {
+///     undo_stack.execute(cmd);
+/// }
+/// ```
+pub trait CommandQueueExt<K, Cmd>
+where
+    K: Copy + PartialEq + Hash,
+{
+    /// Asocia a `key` una fábrica de comandos.
+    ///
+    /// Cada vez que `key` tenga un flanco de pulsación
+    /// ([`is_just_press`](InputStateExt::is_just_press)), se encola `make()`.
+    fn bind_command(&mut self, key: K, make: impl Fn() -> Cmd + Send + 'static);
+
+    /// Retorna y vacía los comandos producidos por las teclas recién presionadas,
+    /// en orden de pulsación.
+    ///
+    /// Llamar dos veces en el mismo frame retorna una lista vacía la segunda vez.
+    fn drain_commands(&mut self) -> Vec<Cmd>;
+}
+
+/// Identificador de un perfil de controles registrado en [`ProfileSwitchExt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProfileId(pub u32);
+
+/// # Trait `ProfileSwitchExt`
+///
+/// Permite **reemplazar** la tabla de bindings activa según el contexto del juego
+/// (a pie, en vehículo, con un arma concreta...).
+///
+/// A diferencia de los contextos de input, que se **apilan**, un perfil
+/// **sustituye** por completo la tabla activa: tras
+/// [`activate_profile`](Self::activate_profile) todas las consultas de
+/// [`ActionMapExt`] se resuelven con la nueva tabla, de forma atómica.
+///
+/// ## Teclas mantenidas durante el cambio
+/// Las consultas se evalúan siempre contra el estado físico actual, por lo que las
+/// pulsaciones en curso **se conservan**: si una tecla mantenida está asociada a una
+/// acción en el nuevo perfil, esa acción se reporta como presionada de inmediato,
+/// pero **no** genera un flanco de [`is_action_just_pressed`](ActionMapExt::is_action_just_pressed)
+/// (el flanco pertenece a la tecla, que ya estaba abajo).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// input.add_profile(ON_FOOT, on_foot_map);
+/// input.add_profile(IN_VEHICLE, vehicle_map);
+///
+/// // Al subir al vehículo:
+/// input.activate_profile(IN_VEHICLE);
+/// if input.is_action_pressed(Action::Accelerate) { /* ... */ }
+/// ```
+pub trait ProfileSwitchExt<A, K>: ActionMapExt<A, K>
+where
+    A: Copy + PartialEq,
+    K: Copy + PartialEq + Hash,
+{
+    /// Registra (o reemplaza) el perfil `id` con la tabla `map`.
+    ///
+    /// Registrar un perfil no lo activa.
+    fn add_profile(&mut self, id: ProfileId, map: ActionMap<A, K>);
+
+    /// Activa el perfil `id`, reemplazando la tabla de bindings activa.
+    ///
+    /// Retorna `false` (y deja el perfil activo sin cambios) si `id` no fue registrado.
+    fn activate_profile(&mut self, id: ProfileId) -> bool;
+
+    /// Retorna el perfil activo, si hay alguno.
+    fn active_profile(&self) -> Option<ProfileId>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, Input, Key};
+    use crate::{HistoryAccessExt, InputEvent};
+    use alloc::boxed::Box;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum Action {
+        Jump,
+        Fire,
+    }
+
+    const ON_FOOT: ProfileId = ProfileId(0);
+    const IN_VEHICLE: ProfileId = ProfileId(1);
+
+    /// Implementación mínima de los perfiles sobre [`ActionMap`].
+    #[derive(Default)]
+    struct Profiles {
+        input: Input,
+        bindings: ActionMap<Action, Key>,
+        profiles: Vec<(ProfileId, ActionMap<Action, Key>)>,
+        active: Option<ProfileId>,
+    }
+
+    impl ActionMapExt<Action, Key> for Profiles {
+        fn bind(&mut self, action: Action, key: Key) {
+            self.bindings.bind(action, key);
+        }
+
+        fn unbind(&mut self, action: Action) {
+            self.bindings.unbind(action);
+        }
+
+        fn is_action_pressed(&self, action: Action) -> bool {
+            self.bindings.is_action_pressed(&self.input, action)
+        }
+
+        fn is_action_just_pressed(&self, action: Action) -> bool {
+            self.bindings.is_action_just_pressed(&self.input, action)
+        }
+
+        fn export_bindings(&self) -> Vec<(Action, Vec<Key>)> {
+            self.bindings.to_bindings()
+        }
+    }
+
+    impl ProfileSwitchExt<Action, Key> for Profiles {
+        fn add_profile(&mut self, id: ProfileId, map: ActionMap<Action, Key>) {
+            self.profiles.retain(|(registered, _)| *registered != id);
+            self.profiles.push((id, map));
+        }
+
+        fn activate_profile(&mut self, id: ProfileId) -> bool {
+            let Some((_, map)) = self.profiles.iter().find(|(registered, _)| *registered == id) else {
+                return false;
+            };
+            self.bindings = map.clone();
+            self.active = Some(id);
+            true
+        }
+
+        fn active_profile(&self) -> Option<ProfileId> {
+            self.active
+        }
+    }
+
+    fn profiles() -> Profiles {
+        let mut on_foot = ActionMap::new();
+        on_foot.bind(Action::Jump, Key::A);
+        on_foot.bind(Action::Fire, Key::B);
+
+        let mut in_vehicle = ActionMap::new();
+        in_vehicle.bind(Action::Fire, Key::A);
+
+        let mut profiles = Profiles::default();
+        profiles.add_profile(ON_FOOT, on_foot);
+        profiles.add_profile(IN_VEHICLE, in_vehicle);
+        profiles
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum EditorCmd {
+        Place,
+        Delete,
+    }
+
+    type Factory = Box<dyn Fn() -> EditorCmd + Send>;
+
+    /// Cola de comandos que recorre las pulsaciones del historial en orden.
+    #[derive(Default)]
+    struct Commands {
+        input: Input,
+        factories: Vec<(Key, Factory)>,
+        drained: usize,
+    }
+
+    impl CommandQueueExt<Key, EditorCmd> for Commands {
+        fn bind_command(&mut self, key: Key, make: impl Fn() -> EditorCmd + Send + 'static) {
+            self.factories.push((key, Box::new(make)));
+        }
+
+        fn drain_commands(&mut self) -> Vec<EditorCmd> {
+            let history = self.input.history();
+            let commands = history[self.drained..]
+                .iter()
+                .filter(|event| event.is_press())
+                .flat_map(|event| self.factories.iter().filter(move |(key, _)| *key == event.key))
+                .map(|(_, make)| make())
+                .collect();
+            self.drained = history.len();
+            commands
+        }
+    }
+
+    #[test]
+    fn commands_drain_in_press_order() {
+        let mut commands = Commands::default();
+        commands.bind_command(Key::A, || EditorCmd::Place);
+        commands.bind_command(Key::B, || EditorCmd::Delete);
+
+        commands.input.set_key(Key::B, Btn::Down);
+        commands.input.set_key(Key::C, Btn::Down);
+        commands.input.set_key(Key::A, Btn::Down);
+
+        assert_eq!(commands.drain_commands(), [EditorCmd::Delete, EditorCmd::Place]);
+        assert_eq!(commands.drain_commands(), []);
+    }
+
+    #[test]
+    fn action_map_binds_each_pair_once() {
+        let mut map = ActionMap::new();
+        map.bind(Action::Jump, Key::A);
+        map.bind(Action::Jump, Key::B);
+        map.bind(Action::Jump, Key::A);
+
+        assert_eq!(map.keys_for(Action::Jump).collect::<Vec<_>>(), [Key::A, Key::B]);
+
+        map.unbind(Action::Jump);
+        assert_eq!(map.keys_for(Action::Jump).count(), 0);
+    }
+
+    #[test]
+    fn action_is_pressed_through_any_of_its_keys() {
+        let mut map = ActionMap::new();
+        map.bind(Action::Jump, Key::A);
+        map.bind(Action::Jump, Key::B);
+
+        let mut input = Input::new();
+        input.set_key(Key::B, Btn::Down);
+
+        assert!(map.is_action_pressed(&input, Action::Jump));
+        assert!(!map.is_action_pressed(&input, Action::Fire));
+    }
+
+    #[test]
+    fn activating_a_profile_replaces_the_bindings() {
+        let mut profiles = profiles();
+        assert!(profiles.activate_profile(ON_FOOT));
+        profiles.input.set_key(Key::A, Btn::Down);
+
+        assert!(profiles.is_action_pressed(Action::Jump));
+        assert!(!profiles.is_action_pressed(Action::Fire));
+
+        assert!(profiles.activate_profile(IN_VEHICLE));
+        assert_eq!(profiles.active_profile(), Some(IN_VEHICLE));
+        assert!(!profiles.is_action_pressed(Action::Jump));
+        assert!(profiles.is_action_pressed(Action::Fire));
+    }
+
+    #[test]
+    fn unknown_profile_keeps_the_active_one() {
+        let mut profiles = profiles();
+        profiles.activate_profile(ON_FOOT);
+
+        assert!(!profiles.activate_profile(ProfileId(7)));
+        assert_eq!(profiles.active_profile(), Some(ON_FOOT));
+    }
+
+    #[test]
+    fn held_key_carries_over_without_a_new_edge() {
+        let mut profiles = profiles();
+        profiles.activate_profile(ON_FOOT);
+        profiles.input.set_key(Key::A, Btn::Down);
+        profiles.input.advance_frame();
+
+        profiles.activate_profile(IN_VEHICLE);
+
+        assert!(profiles.is_action_pressed(Action::Fire));
+        assert!(!profiles.is_action_just_pressed(Action::Fire));
+    }
+
+    #[test]
+    fn export_follows_the_active_profile() {
+        let mut profiles = profiles();
+        assert_eq!(profiles.export_bindings(), []);
+
+        profiles.activate_profile(ON_FOOT);
+        assert_eq!(profiles.export_bindings(), [(Action::Jump, vec![Key::A]), (Action::Fire, vec![Key::B])]);
+
+        profiles.activate_profile(IN_VEHICLE);
+        assert_eq!(profiles.export_bindings(), [(Action::Fire, vec![Key::A])]);
+    }
+
+    #[test]
+    fn default_rebind_is_unsupported() {
+        let mut profiles = profiles();
+        profiles.activate_profile(ON_FOOT);
+        profiles.set_rebind_cancel_key(Key::D);
+
+        profiles.begin_rebind(Action::Jump);
+        profiles.input.set_key(Key::C, Btn::Down);
+
+        assert_eq!(profiles.poll_rebind(), RebindStatus::Idle);
+        assert_eq!(profiles.bindings.keys_for(Action::Jump).collect::<Vec<_>>(), [Key::A]);
+    }
+
+    /// Implementación de referencia completa de [`ActionMapExt`], con captura.
+    #[derive(Default)]
+    struct Controls {
+        input: Input,
+        bindings: ActionMap<Action, Key>,
+        capturing: Option<Action>,
+        cancel_key: Option<Key>,
+        /// Tecla que terminó la última captura: silencia las acciones mientras dure su flanco.
+        ended_with: Option<Key>,
+    }
+
+    impl Controls {
+        fn muted(&self) -> bool {
+            self.capturing.is_some() || self.ended_with.is_some_and(|key| self.input.is_just_press(key))
+        }
+    }
+
+    impl ActionMapExt<Action, Key> for Controls {
+        fn bind(&mut self, action: Action, key: Key) {
+            self.bindings.bind(action, key);
+        }
+
+        fn unbind(&mut self, action: Action) {
+            self.bindings.unbind(action);
+        }
+
+        fn is_action_pressed(&self, action: Action) -> bool {
+            !self.muted() && self.bindings.is_action_pressed(&self.input, action)
+        }
+
+        fn is_action_just_pressed(&self, action: Action) -> bool {
+            !self.muted() && self.bindings.is_action_just_pressed(&self.input, action)
+        }
+
+        fn export_bindings(&self) -> Vec<(Action, Vec<Key>)> {
+            self.bindings.to_bindings()
+        }
+
+        fn begin_rebind(&mut self, action: Action) {
+            self.capturing = Some(action);
+        }
+
+        fn poll_rebind(&mut self) -> RebindStatus<Key> {
+            let Some(action) = self.capturing else {
+                return RebindStatus::Idle;
+            };
+            let Some(&key) = self.input.just_pressed_keys().first() else {
+                return RebindStatus::Listening;
+            };
+            self.capturing = None;
+            self.ended_with = Some(key);
+            if self.cancel_key == Some(key) {
+                return RebindStatus::Cancelled;
+            }
+            self.unbind(action);
+            self.bind(action, key);
+            RebindStatus::Captured(key)
+        }
+
+        fn set_rebind_cancel_key(&mut self, key: Key) {
+            self.cancel_key = Some(key);
+        }
+    }
+
+    #[test]
+    fn bind_adds_keys_and_unbind_clears_them() {
+        let mut controls = Controls::default();
+        controls.bind(Action::Jump, Key::A);
+        controls.bind(Action::Jump, Key::B);
+        controls.bind(Action::Jump, Key::A);
+        controls.input.set_key(Key::B, Btn::Down);
+
+        // La segunda tecla no reemplaza a la primera: cualquiera activa la acción.
+        assert!(controls.is_action_pressed(Action::Jump));
+        assert!(controls.is_action_just_pressed(Action::Jump));
+        assert_eq!(controls.export_bindings(), [(Action::Jump, vec![Key::A, Key::B])]);
+
+        controls.unbind(Action::Jump);
+        assert!(!controls.is_action_pressed(Action::Jump));
+    }
+
+    #[test]
+    fn actions_without_keys_are_never_active() {
+        let mut controls = Controls::default();
+        controls.bind(Action::Jump, Key::A);
+        controls.input.set_key(Key::A, Btn::Down);
+        controls.input.advance_frame();
+
+        assert!(!controls.is_action_pressed(Action::Fire));
+        assert!(controls.is_action_pressed(Action::Jump));
+        assert!(!controls.is_action_just_pressed(Action::Jump));
+    }
+
+    #[test]
+    fn rebind_captures_the_next_pressed_key() {
+        let mut controls = Controls::default();
+        controls.bind(Action::Jump, Key::A);
+        assert_eq!(controls.poll_rebind(), RebindStatus::Idle);
+
+        controls.begin_rebind(Action::Jump);
+        assert_eq!(controls.poll_rebind(), RebindStatus::Listening);
+
+        controls.input.set_key(Key::C, Btn::Down);
+        // Mientras dura la captura ninguna acción se dispara.
+        assert!(!controls.is_action_pressed(Action::Jump));
+        assert_eq!(controls.poll_rebind(), RebindStatus::Captured(Key::C));
+        assert_eq!(controls.export_bindings(), [(Action::Jump, vec![Key::C])]);
+
+        // Ni siquiera en el frame en que termina la captura.
+        assert!(!controls.is_action_just_pressed(Action::Jump));
+        controls.input.advance_frame();
+        assert!(controls.is_action_pressed(Action::Jump));
+        assert_eq!(controls.poll_rebind(), RebindStatus::Idle);
+    }
+
+    #[test]
+    fn cancel_key_ends_the_capture_without_rebinding() {
+        let mut controls = Controls::default();
+        controls.bind(Action::Fire, Key::B);
+        controls.set_rebind_cancel_key(Key::D);
+
+        controls.begin_rebind(Action::Fire);
+        controls.input.set_key(Key::D, Btn::Down);
+
+        assert_eq!(controls.poll_rebind(), RebindStatus::Cancelled);
+        assert_eq!(controls.export_bindings(), [(Action::Fire, vec![Key::B])]);
+        assert_eq!(controls.poll_rebind(), RebindStatus::Idle);
+    }
+
+    #[test]
+    fn begin_rebind_during_a_capture_switches_the_action() {
+        let mut controls = Controls::default();
+        controls.begin_rebind(Action::Jump);
+        controls.begin_rebind(Action::Fire);
+        controls.input.set_key(Key::A, Btn::Down);
+
+        assert_eq!(controls.poll_rebind(), RebindStatus::Captured(Key::A));
+        assert_eq!(controls.export_bindings(), [(Action::Fire, vec![Key::A])]);
+    }
+
+    #[test]
+    fn import_replaces_only_the_listed_actions() {
+        let mut controls = Controls::default();
+        controls.bind(Action::Jump, Key::A);
+        controls.bind(Action::Fire, Key::B);
+
+        controls.import_bindings(&[(Action::Jump, vec![Key::C, Key::D])]);
+
+        assert_eq!(
+            controls.export_bindings(),
+            [(Action::Fire, vec![Key::B]), (Action::Jump, vec![Key::C, Key::D])]
+        );
+    }
+
+    #[test]
+    fn imported_key_missing_from_the_device_is_kept_but_never_fires() {
+        // Dispositivo que solo tiene las teclas A, B y C; D es de otro backend.
+        let mut controls = Controls::default();
+        controls.import_bindings(&[(Action::Jump, vec![Key::D])]);
+
+        for key in [Key::A, Key::B, Key::C] {
+            controls.input.set_key(key, Btn::Down);
+        }
+
+        assert!(!controls.is_action_pressed(Action::Jump));
+        assert!(!controls.is_action_just_pressed(Action::Jump));
+        assert_eq!(controls.export_bindings(), [(Action::Jump, vec![Key::D])]);
+    }
+
+    #[test]
+    fn exported_bindings_restore_another_state() {
+        let mut saved = Controls::default();
+        saved.bind(Action::Jump, Key::A);
+        saved.bind(Action::Fire, Key::B);
+        saved.bind(Action::Fire, Key::C);
+        let set = BindingSet::from(saved.export_bindings());
+
+        let mut restored = Controls::default();
+        restored.bind(Action::Fire, Key::D);
+        restored.import_bindings(&set.bindings);
+
+        assert_eq!(restored.export_bindings(), saved.export_bindings());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn binding_set_round_trips_through_json() {
+        let set = BindingSet::from(vec![(Action::Jump, vec![Key::A]), (Action::Fire, vec![Key::B, Key::C])]);
+
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"{"bindings":[["Jump",["A"]],["Fire",["B","C"]]]}"#);
+        assert_eq!(serde_json::from_str::<BindingSet<Action, Key>>(&json).unwrap(), set);
+    }
+}
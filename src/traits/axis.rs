@@ -0,0 +1,349 @@
+use alloc::vec::Vec;
+
+/// # Trait `AxisExt`
+///
+/// Contrato **mínimo** para entradas analógicas: cualquier dispositivo que reporte
+/// ejes con un valor entero crudo (sticks, gatillos, pedales, volantes, arcade
+/// sticks analógicos).
+///
+/// Es la capa cruda sobre la que se construyen traits de más alto nivel como
+/// [`GamepadStateExt`](crate::GamepadStateExt), que añade compensación de drift y
+/// ejes estándar ([`Axis`](crate::Axis)). Un backend de mando puede implementar
+/// ambos sobre el mismo tipo.
+///
+/// ## Valores
+/// - Crudo ([`raw_axis`](Self::raw_axis)): el entero que reporta el dispositivo,
+///   dentro de [`axis_range`](Self::axis_range).
+/// - Normalizado ([`normalized_axis`](Self::normalized_axis)): `-1.0..=1.0` con
+///   `0.0` en el centro del rango, o `0.0..=1.0` con `0.0` en reposo para los ejes
+///   de un solo sentido como los gatillos.
+///
+/// ## Parámetros genéricos
+/// - `A`: Identificador de eje del backend (por ejemplo, `evdev::AbsoluteAxisType`).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// // En el runtime, al leer un evento ABS_X:
+/// state.set_axis(StickAxis::X, event.value());
+///
+/// // En el juego:
+/// let steer = wheel.normalized_axis(WheelAxis::Steering);
+/// car.steer(steer);
+/// ```
+pub trait AxisExt<A>
+where
+    A: Copy + PartialEq,
+{
+    /// Retorna el último valor crudo reportado para `axis`.
+    fn raw_axis(&self, axis: A) -> i32;
+
+    /// Retorna el rango crudo `(min, max)` del eje, tal como lo declara el
+    /// dispositivo.
+    fn axis_range(&self, axis: A) -> (i32, i32);
+
+    /// Retorna el valor de `axis` normalizado.
+    ///
+    /// Por defecto mapea linealmente [`axis_range`](Self::axis_range) y recorta los
+    /// valores fuera de rango:
+    /// - Un rango con `min < 0` (sticks, volantes) va a `-1.0..=1.0`.
+    /// - Un rango con `min >= 0` (gatillos, pedales) va a `0.0..=1.0`, así que el
+    ///   eje en reposo lee `0.0`.
+    ///
+    /// Un rango degenerado (`min >= max`) retorna `0.0`.
+    fn normalized_axis(&self, axis: A) -> f32 {
+        let (min, max) = self.axis_range(axis);
+        if min >= max {
+            return 0.0;
+        }
+
+        let span = f64::from(max) - f64::from(min);
+        let unit = ((f64::from(self.raw_axis(axis)) - f64::from(min)) / span).clamp(0.0, 1.0);
+        let value = if min >= 0 { unit } else { unit * 2.0 - 1.0 };
+        value as f32
+    }
+
+    /// Retorna los ejes que **no** están centrados en este momento.
+    ///
+    /// Qué cuenta como centrado (zona muerta incluida) lo decide cada
+    /// implementación.
+    fn axes_pressed(&self) -> Vec<A>;
+
+    /// Registra un nuevo valor crudo para `axis`. Lo llama el runtime al leer un
+    /// evento analógico del backend.
+    fn set_axis(&mut self, axis: A, raw: i32);
+}
+
+/// Curva de respuesta aplicada por [`DeadzoneExt::shape`] a la magnitud de un eje,
+/// ya sin zona muerta (`0.0..=1.0`).
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ResponseCurve {
+    /// Sin cambios: `x`.
+    #[default]
+    Linear,
+    /// `x²`: más precisión cerca del centro.
+    Quadratic,
+    /// `x³`: aún más precisión cerca del centro.
+    Cubic,
+    /// Curva propia. Recibe y debe retornar valores en `0.0..=1.0`.
+    Custom(fn(f32) -> f32),
+}
+
+impl ResponseCurve {
+    /// Aplica la curva a una magnitud en `0.0..=1.0`.
+    pub fn apply(self, magnitude: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => magnitude,
+            ResponseCurve::Quadratic => magnitude * magnitude,
+            ResponseCurve::Cubic => magnitude * magnitude * magnitude,
+            ResponseCurve::Custom(curve) => curve(magnitude),
+        }
+    }
+}
+
+/// # Trait `DeadzoneExt`
+///
+/// Configuración **estándar** de zona muerta y curva de respuesta por eje, sobre
+/// [`AxisExt`].
+///
+/// Con este trait, [`normalized_axis`](AxisExt::normalized_axis) retorna el valor
+/// ya **moldeado** (zona muerta y curva aplicadas), mientras que
+/// [`raw_axis`](AxisExt::raw_axis) sigue siendo el valor crudo. Las
+/// implementaciones sobrescriben `normalized_axis` para pasar el valor normalizado
+/// por [`shape`](Self::shape).
+///
+/// ## Zona muerta
+/// Se aplica sobre la **magnitud** del valor normalizado, conservando el signo:
+/// - `|v| <= inner` → `0.0` (ruido del stick en reposo).
+/// - `|v| >= outer` → `1.0` (los sticks gastados que no llegan al tope).
+/// - Entre ambos, se reescala linealmente a `0.0..=1.0` y se aplica la
+///   [`ResponseCurve`].
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// pad.set_deadzone(StickAxis::LeftX, 0.1, 0.95);
+/// pad.set_curve(StickAxis::LeftX, ResponseCurve::Quadratic);
+///
+/// // Valor moldeado, listo para mover la cámara
+/// let x = pad.normalized_axis(StickAxis::LeftX);
+/// ```
+pub trait DeadzoneExt<A>: AxisExt<A>
+where
+    A: Copy + PartialEq,
+{
+    /// Define la zona muerta interior y exterior de `axis`, como magnitudes en
+    /// `0.0..=1.0`. `inner == 0.0` y `outer == 1.0` la desactivan.
+    fn set_deadzone(&mut self, axis: A, inner: f32, outer: f32);
+
+    /// Retorna la zona muerta `(inner, outer)` configurada para `axis`.
+    fn deadzone(&self, axis: A) -> (f32, f32);
+
+    /// Define la curva de respuesta de `axis`.
+    fn set_curve(&mut self, axis: A, curve: ResponseCurve);
+
+    /// Retorna la curva de respuesta configurada para `axis`.
+    fn curve(&self, axis: A) -> ResponseCurve;
+
+    /// Aplica la zona muerta y la curva de `axis` a un valor normalizado en
+    /// `-1.0..=1.0`.
+    ///
+    /// Si `outer <= inner`, cualquier valor fuera de la zona interior cuenta como
+    /// tope.
+    fn shape(&self, axis: A, value: f32) -> f32 {
+        let (inner, outer) = self.deadzone(axis);
+        let magnitude = value.abs();
+        if magnitude <= inner {
+            return 0.0;
+        }
+
+        let scaled = if magnitude >= outer {
+            1.0
+        } else {
+            (magnitude - inner) / (outer - inner)
+        };
+        let shaped = self.curve(axis).apply(scaled).clamp(0.0, 1.0);
+        shaped.copysign(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum StickAxis {
+        X,
+        Trigger,
+        Broken,
+    }
+
+    /// Stick con rango simétrico en `X`, gatillo `0..=255` y un eje sin rango.
+    #[derive(Default)]
+    struct RawStick {
+        raw: HashMap<StickAxis, i32>,
+    }
+
+    impl AxisExt<StickAxis> for RawStick {
+        fn raw_axis(&self, axis: StickAxis) -> i32 {
+            self.raw.get(&axis).copied().unwrap_or_default()
+        }
+
+        fn axis_range(&self, axis: StickAxis) -> (i32, i32) {
+            match axis {
+                StickAxis::X => (-32768, 32767),
+                StickAxis::Trigger => (0, 255),
+                StickAxis::Broken => (0, 0),
+            }
+        }
+
+        fn axes_pressed(&self) -> Vec<StickAxis> {
+            self.raw.keys().copied().filter(|axis| self.normalized_axis(*axis).abs() > 0.05).collect()
+        }
+
+        fn set_axis(&mut self, axis: StickAxis, raw: i32) {
+            self.raw.insert(axis, raw);
+        }
+    }
+
+    #[test]
+    fn range_maps_linearly_to_unit_interval() {
+        let mut stick = RawStick::default();
+        stick.set_axis(StickAxis::X, 32767);
+        stick.set_axis(StickAxis::Trigger, 0);
+        assert_eq!(stick.normalized_axis(StickAxis::X), 1.0);
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 0.0);
+
+        stick.set_axis(StickAxis::X, -32768);
+        stick.set_axis(StickAxis::Trigger, 255);
+        assert_eq!(stick.normalized_axis(StickAxis::X), -1.0);
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 1.0);
+        assert_eq!(stick.raw_axis(StickAxis::Trigger), 255);
+    }
+
+    #[test]
+    fn triggers_use_the_unit_range() {
+        let mut stick = RawStick::default();
+        stick.set_axis(StickAxis::Trigger, 51);
+        assert!((stick.normalized_axis(StickAxis::Trigger) - 0.2).abs() < 1e-6);
+
+        stick.set_axis(StickAxis::Trigger, -10);
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 0.0);
+        assert!(stick.axes_pressed().is_empty());
+    }
+
+    #[test]
+    fn out_of_range_values_are_clipped_and_degenerate_ranges_are_centered() {
+        let mut stick = RawStick::default();
+        stick.set_axis(StickAxis::Trigger, 400);
+        stick.set_axis(StickAxis::Broken, 12);
+
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 1.0);
+        assert_eq!(stick.normalized_axis(StickAxis::Broken), 0.0);
+        assert_eq!(stick.axes_pressed(), [StickAxis::Trigger]);
+    }
+
+    /// [`RawStick`] con zona muerta y curva: `normalized_axis` pasa por `shape`.
+    #[derive(Default)]
+    struct ShapedStick {
+        raw: RawStick,
+        deadzones: HashMap<StickAxis, (f32, f32)>,
+        curves: HashMap<StickAxis, ResponseCurve>,
+    }
+
+    impl AxisExt<StickAxis> for ShapedStick {
+        fn raw_axis(&self, axis: StickAxis) -> i32 {
+            self.raw.raw_axis(axis)
+        }
+
+        fn axis_range(&self, axis: StickAxis) -> (i32, i32) {
+            self.raw.axis_range(axis)
+        }
+
+        fn normalized_axis(&self, axis: StickAxis) -> f32 {
+            self.shape(axis, self.raw.normalized_axis(axis))
+        }
+
+        fn axes_pressed(&self) -> Vec<StickAxis> {
+            self.raw.raw.keys().copied().filter(|axis| self.normalized_axis(*axis) != 0.0).collect()
+        }
+
+        fn set_axis(&mut self, axis: StickAxis, raw: i32) {
+            self.raw.set_axis(axis, raw);
+        }
+    }
+
+    impl DeadzoneExt<StickAxis> for ShapedStick {
+        fn set_deadzone(&mut self, axis: StickAxis, inner: f32, outer: f32) {
+            self.deadzones.insert(axis, (inner, outer));
+        }
+
+        fn deadzone(&self, axis: StickAxis) -> (f32, f32) {
+            self.deadzones.get(&axis).copied().unwrap_or((0.0, 1.0))
+        }
+
+        fn set_curve(&mut self, axis: StickAxis, curve: ResponseCurve) {
+            self.curves.insert(axis, curve);
+        }
+
+        fn curve(&self, axis: StickAxis) -> ResponseCurve {
+            self.curves.get(&axis).copied().unwrap_or_default()
+        }
+    }
+
+    fn close(actual: f32, expected: f32) -> bool {
+        (actual - expected).abs() < 1e-6
+    }
+
+    #[test]
+    fn deadzone_rescales_between_inner_and_outer() {
+        let mut stick = ShapedStick::default();
+        stick.set_deadzone(StickAxis::X, 0.1, 0.9);
+
+        assert_eq!(stick.shape(StickAxis::X, 0.05), 0.0);
+        assert_eq!(stick.shape(StickAxis::X, -0.1), 0.0);
+        assert!(close(stick.shape(StickAxis::X, 0.5), 0.5));
+        assert!(close(stick.shape(StickAxis::X, -0.3), -0.25));
+        assert_eq!(stick.shape(StickAxis::X, 0.95), 1.0);
+        assert_eq!(stick.shape(StickAxis::X, -0.9), -1.0);
+    }
+
+    #[test]
+    fn curves_shape_the_magnitude_and_keep_the_sign() {
+        let mut stick = ShapedStick::default();
+        stick.set_curve(StickAxis::X, ResponseCurve::Quadratic);
+        assert!(close(stick.shape(StickAxis::X, -0.5), -0.25));
+
+        stick.set_curve(StickAxis::X, ResponseCurve::Cubic);
+        assert!(close(stick.shape(StickAxis::X, 0.5), 0.125));
+
+        stick.set_curve(StickAxis::X, ResponseCurve::Custom(|_| 2.0));
+        assert_eq!(stick.shape(StickAxis::X, -0.5), -1.0);
+        assert_eq!(ResponseCurve::default().apply(0.3), 0.3);
+    }
+
+    #[test]
+    fn collapsed_deadzone_treats_everything_outside_inner_as_full() {
+        let mut stick = ShapedStick::default();
+        stick.set_deadzone(StickAxis::X, 0.5, 0.5);
+
+        assert_eq!(stick.shape(StickAxis::X, 0.4), 0.0);
+        assert_eq!(stick.shape(StickAxis::X, 0.6), 1.0);
+    }
+
+    #[test]
+    fn normalized_axis_is_shaped_and_raw_axis_stays_raw() {
+        let mut stick = ShapedStick::default();
+        stick.set_deadzone(StickAxis::Trigger, 0.6, 1.0);
+        stick.set_axis(StickAxis::Trigger, 140);
+
+        assert_eq!(stick.raw_axis(StickAxis::Trigger), 140);
+        assert!(stick.raw.normalized_axis(StickAxis::Trigger) > 0.5);
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 0.0);
+        assert!(stick.axes_pressed().is_empty());
+
+        stick.set_axis(StickAxis::Trigger, 255);
+        assert_eq!(stick.normalized_axis(StickAxis::Trigger), 1.0);
+        assert_eq!(stick.axes_pressed(), [StickAxis::Trigger]);
+    }
+}
@@ -0,0 +1,232 @@
+use core::hash::Hash;
+use core::time::Duration;
+
+use crate::{InputStateExt, StateSemantics};
+
+/// # Trait `Clock`
+///
+/// Abstrae la **fuente de tiempo** del historial de eventos.
+///
+/// `std::time::Instant` no existe sin `std`, así que los traits de historial no
+/// pueden depender de él directamente. Cada [`WithHistoryExt`](crate::WithHistoryExt)
+/// declara el reloj cuyo `Timestamp` coincide con el
+/// [`Time`](crate::InputEvent::Time) de sus eventos, y el crate mide intervalos
+/// entre marcas de tiempo solo a través de este trait.
+///
+/// - Con `std`, [`StdClock`] usa `Instant`.
+/// - En sistemas embebidos, un contador de ticks (por ejemplo, un `u32` de un timer
+///   de hardware) implementa el trait convirtiendo ticks a [`Duration`] con la
+///   frecuencia del timer.
+///
+/// Las duraciones siempre son [`core::time::Duration`], que está disponible sin `std`.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use core::time::Duration;
+/// use orbit_input_core::Clock;
+///
+/// /// Timer de hardware a 1 kHz.
+/// struct TickClock;
+///
+/// impl Clock for TickClock {
+///     type Timestamp = u32;
+///
+///     fn now(&self) -> u32 {
+///         hal::timer::ticks()
+///     }
+///
+///     fn duration_between(earlier: u32, later: u32) -> Duration {
+///         Duration::from_millis(u64::from(later.saturating_sub(earlier)))
+///     }
+/// }
+/// ```
+pub trait Clock {
+    /// Marca de tiempo de un instante. Mayor significa más reciente.
+    type Timestamp: Copy + Ord;
+
+    /// Retorna el instante actual.
+    fn now(&self) -> Self::Timestamp;
+
+    /// Retorna el tiempo transcurrido de `earlier` a `later`.
+    ///
+    /// Debe saturar: si `later` es anterior a `earlier`, retorna [`Duration::ZERO`].
+    fn duration_between(earlier: Self::Timestamp, later: Self::Timestamp) -> Duration;
+}
+
+/// Reloj basado en [`std::time::Instant`], disponible con el feature `std`.
+///
+/// Es el reloj habitual de los backends de escritorio.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StdClock;
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    type Timestamp = std::time::Instant;
+
+    fn now(&self) -> Self::Timestamp {
+        std::time::Instant::now()
+    }
+
+    fn duration_between(earlier: Self::Timestamp, later: Self::Timestamp) -> Duration {
+        later.saturating_duration_since(earlier)
+    }
+}
+
+/// # Trait `FrameTimeExt`
+///
+/// Estado cuya noción del **tiempo actual** la inyecta el game loop, en lugar de
+/// leer el reloj de pared.
+///
+/// [`InputStateExt::time_pressed`] necesita saber qué hora es, y un estado sin este
+/// trait solo puede consultarlo por su cuenta (normalmente con `Instant::now()`), lo
+/// que rompe `no_std` y hace que las repeticiones no sean deterministas. Con
+/// `FrameTimeExt`, el runtime o el game loop llama a
+/// [`update_time`](Self::update_time) una vez por frame y el estado **no toca
+/// ningún reloj**.
+///
+/// ## Contrato
+/// - [`time_pressed`](InputStateExt::time_pressed) se calcula contra el último
+///   `now` recibido: es el tiempo entre el `update_time` vigente cuando se presionó
+///   la tecla y el más reciente. Entre dos llamadas, no cambia.
+/// - [`set_key`](InputStateExt::set_key) marca las pulsaciones con el último `now`
+///   recibido.
+/// - El tiempo es **monótono**: valores anteriores al actual se ignoran, igual que en
+///   [`set_tick`](InputStateExt::set_tick).
+///
+/// `Time` suele ser el `Timestamp` del [`Clock`] que usa el resto del backend (por
+/// ejemplo, el de su historial), para medir intervalos con
+/// [`Clock::duration_between`].
+///
+/// ## Parámetros genéricos
+/// - `K`, `S`: Tecla y estado del [`InputStateExt`] subyacente.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let mut now = Duration::ZERO;
+/// loop {
+///     now += frame_delta;
+///     input.update_time(now);
+///     runtime.update();
+///     game.update(&input);   // time_pressed medido contra `now`
+///     input.advance_frame();
+/// }
+/// ```
+pub trait FrameTimeExt<K, S>: InputStateExt<K, S>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    /// Marca de tiempo que recibe el estado. Mayor significa más reciente.
+    type Time: Copy + Ord;
+
+    /// Establece el **instante actual** del estado.
+    ///
+    /// Llamado por el runtime o el game loop, normalmente al comienzo de cada frame.
+    /// Un `now` anterior al actual se ignora.
+    fn update_time(&mut self, now: Self::Time);
+
+    /// Retorna el instante actual del estado: el último recibido con
+    /// [`update_time`](Self::update_time), o `None` si el estado aún no tiene
+    /// ninguno.
+    fn current_time(&self) -> Option<Self::Time>;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Add;
+
+    use super::*;
+    use crate::TemporalQueryExt;
+    use crate::testing::MockInputState;
+    use crate::testing::fixtures::{Btn, Input, Key, ms};
+
+    /// Ticks de un timer de hardware a 1 kHz: un tick por milisegundo.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    struct Ticks(u32);
+
+    impl Add<Duration> for Ticks {
+        type Output = Ticks;
+
+        fn add(self, by: Duration) -> Ticks {
+            Ticks(self.0.saturating_add(u32::try_from(by.as_millis()).unwrap_or(u32::MAX)))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TickClock {
+        now: Ticks,
+    }
+
+    impl Clock for TickClock {
+        type Timestamp = Ticks;
+
+        fn now(&self) -> Ticks {
+            self.now
+        }
+
+        fn duration_between(earlier: Ticks, later: Ticks) -> Duration {
+            Duration::from_millis(u64::from(later.0.saturating_sub(earlier.0)))
+        }
+    }
+
+    #[test]
+    fn tick_clock_measures_and_saturates() {
+        assert_eq!(TickClock::duration_between(Ticks(100), Ticks(350)), ms(250));
+        assert_eq!(TickClock::duration_between(Ticks(350), Ticks(100)), Duration::ZERO);
+    }
+
+    #[test]
+    fn history_runs_on_a_tick_counter() {
+        let mut input = MockInputState::<Key, Btn, TickClock>::default();
+        for at in [0, 100, 220] {
+            input.clock_mut().now = Ticks(at);
+            input.set_key(Key::A, Btn::Down);
+            input.set_key(Key::A, Btn::Up);
+        }
+
+        assert_eq!(input.press_intervals(Key::A), [ms(100), ms(120)]);
+        assert_eq!(input.average_press_interval(Key::A), Some(ms(110)));
+        assert!(input.is_double_tap(Key::A, ms(150)));
+        assert!(!input.is_double_tap(Key::A, ms(100)));
+    }
+
+    #[test]
+    fn std_clock_saturates_backwards_intervals() {
+        let clock = StdClock;
+        let earlier = clock.now();
+        let later = clock.now();
+
+        assert!(later >= earlier);
+        assert_eq!(StdClock::duration_between(later + ms(1), earlier), Duration::ZERO);
+        assert_eq!(StdClock::duration_between(earlier, earlier + ms(5)), ms(5));
+    }
+
+    #[test]
+    fn time_pressed_is_measured_against_the_injected_time() {
+        let mut input = Input::new();
+        // El reloj del mock parte de cero.
+        assert_eq!(input.current_time(), Some(Duration::ZERO));
+
+        input.update_time(ms(100));
+        input.set_key(Key::A, Btn::Down);
+        input.update_time(ms(250));
+
+        assert_eq!(input.current_time(), Some(ms(250)));
+        assert_eq!(input.time_pressed(Key::A), Some(ms(150)));
+        // Entre dos `update_time` el valor no cambia.
+        assert_eq!(input.time_pressed(Key::A), Some(ms(150)));
+    }
+
+    #[test]
+    fn injected_time_never_goes_backwards() {
+        let mut input = Input::new();
+        input.update_time(ms(500));
+        input.set_key(Key::A, Btn::Down);
+
+        input.update_time(ms(200));
+
+        assert_eq!(input.current_time(), Some(ms(500)));
+        assert_eq!(input.time_pressed(Key::A), Some(Duration::ZERO));
+    }
+}
@@ -0,0 +1,139 @@
+use core::hash::Hash;
+
+use crate::{InputStateExt, StateSemantics};
+
+/// # Trait `ContextStackExt`
+///
+/// Mantiene una **pila de contextos de input** (gameplay, menú, diálogo...) sobre un
+/// estado de input, para que la misma tecla física signifique cosas distintas según
+/// lo que esté en pantalla.
+///
+/// Solo el contexto de la **cima** recibe input: abrir un diálogo sobre un menú
+/// sobre el gameplay es apilar tres contextos, y al cerrar el diálogo el menú vuelve
+/// a recibir input sin que el motor tenga que filtrar cada consulta a mano.
+///
+/// ## Parámetros genéricos
+/// - `C`: Identificador de contexto (por ejemplo, un enum `InputContext`).
+/// - `K`, `S`: Tecla y estado del [`InputStateExt`] subyacente.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// input.push_context(Ctx::Gameplay);
+///
+/// // Al pausar:
+/// input.push_context(Ctx::Menu);
+///
+/// // Espacio salta en gameplay y confirma en el menú; solo uno de los dos responde
+/// if input.is_pressed_in(Ctx::Gameplay, KeyCode::Space) {
+///     player.jump();
+/// }
+/// if input.is_just_pressed_in(Ctx::Menu, KeyCode::Space) {
+///     menu.confirm();
+/// }
+///
+/// // Al cerrar el menú:
+/// input.pop_context();
+/// ```
+pub trait ContextStackExt<C, K, S>: InputStateExt<K, S>
+where
+    C: Copy + PartialEq,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    /// Apila `ctx`, que pasa a ser el contexto activo.
+    fn push_context(&mut self, ctx: C);
+
+    /// Desapila y retorna el contexto activo, o `None` si la pila está vacía.
+    fn pop_context(&mut self) -> Option<C>;
+
+    /// Retorna el contexto de la cima de la pila, o `None` si está vacía.
+    fn active_context(&self) -> Option<C>;
+
+    /// Retorna `true` si `key` está presionada **y** `ctx` es el contexto activo.
+    fn is_pressed_in(&self, ctx: C, key: K) -> bool {
+        self.active_context() == Some(ctx) && self.is_pressed(key)
+    }
+
+    /// Retorna `true` si `key` fue presionada en este frame **y** `ctx` es el contexto
+    /// activo.
+    fn is_just_pressed_in(&self, ctx: C, key: K) -> bool {
+        self.active_context() == Some(ctx) && self.is_just_press(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::testing::fixtures::{Btn, Input, Key, delegate_input_state};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Ctx {
+        Gameplay,
+        Menu,
+    }
+
+    /// Estado de referencia: el mock más una pila de contextos.
+    #[derive(Default)]
+    struct Layered {
+        input: Input,
+        stack: Vec<Ctx>,
+    }
+
+    delegate_input_state!(Layered, input);
+
+    impl ContextStackExt<Ctx, Key, Btn> for Layered {
+        fn push_context(&mut self, ctx: Ctx) {
+            self.stack.push(ctx);
+        }
+
+        fn pop_context(&mut self) -> Option<Ctx> {
+            self.stack.pop()
+        }
+
+        fn active_context(&self) -> Option<Ctx> {
+            self.stack.last().copied()
+        }
+    }
+
+    #[test]
+    fn only_the_top_context_receives_input() {
+        let mut input = Layered::default();
+        input.push_context(Ctx::Gameplay);
+        input.push_context(Ctx::Menu);
+        input.set_key(Key::A, Btn::Down);
+
+        assert_eq!(input.active_context(), Some(Ctx::Menu));
+        assert!(input.is_pressed_in(Ctx::Menu, Key::A));
+        assert!(input.is_just_pressed_in(Ctx::Menu, Key::A));
+        assert!(!input.is_pressed_in(Ctx::Gameplay, Key::A));
+
+        assert_eq!(input.pop_context(), Some(Ctx::Menu));
+        // Al cerrar el menú, la tecla que sigue mantenida vuelve al gameplay.
+        assert!(input.is_pressed_in(Ctx::Gameplay, Key::A));
+        assert!(!input.is_pressed_in(Ctx::Menu, Key::A));
+    }
+
+    #[test]
+    fn just_pressed_in_respects_the_frame_edge() {
+        let mut input = Layered::default();
+        input.push_context(Ctx::Gameplay);
+        input.set_key(Key::B, Btn::Down);
+        input.advance_frame();
+
+        assert!(input.is_pressed_in(Ctx::Gameplay, Key::B));
+        assert!(!input.is_just_pressed_in(Ctx::Gameplay, Key::B));
+    }
+
+    #[test]
+    fn empty_stack_blocks_every_context() {
+        let mut input = Layered::default();
+        input.set_key(Key::A, Btn::Down);
+
+        assert_eq!(input.active_context(), None);
+        assert_eq!(input.pop_context(), None);
+        assert!(!input.is_pressed_in(Ctx::Gameplay, Key::A));
+        assert!(!input.is_just_pressed_in(Ctx::Menu, Key::A));
+    }
+}
@@ -0,0 +1,654 @@
+use core::hash::Hash;
+use core::time::Duration;
+
+use crate::{AxisExt, Clock, InputEvent, InputStateExt, StateSemantics, WithHistoryExt};
+
+/// Ejes analógicos **estándar** de un gamepad, comunes a todos los backends.
+///
+/// Usado por [`GamepadStateExt::axis_value`] y [`GamepadStateExt::all_axes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Eje horizontal del stick izquierdo, `-1.0..=1.0`.
+    LeftX,
+    /// Eje vertical del stick izquierdo, `-1.0..=1.0` (positivo hacia arriba).
+    LeftY,
+    /// Eje horizontal del stick derecho, `-1.0..=1.0`.
+    RightX,
+    /// Eje vertical del stick derecho, `-1.0..=1.0` (positivo hacia arriba).
+    RightY,
+    /// Gatillo izquierdo, `0.0..=1.0`.
+    LeftTrigger,
+    /// Gatillo derecho, `0.0..=1.0`.
+    RightTrigger,
+}
+
+impl Axis {
+    /// Todos los ejes, en el orden usado por [`GamepadStateExt::all_axes`].
+    pub const ALL: [Axis; 6] = [
+        Axis::LeftX,
+        Axis::LeftY,
+        Axis::RightX,
+        Axis::RightY,
+        Axis::LeftTrigger,
+        Axis::RightTrigger,
+    ];
+
+    /// Retorna `true` para los gatillos (rango `0.0..=1.0`) y `false` para los ejes de
+    /// stick (rango `-1.0..=1.0`).
+    pub fn is_trigger(self) -> bool {
+        matches!(self, Axis::LeftTrigger | Axis::RightTrigger)
+    }
+}
+
+/// # Trait `GamepadStateExt`
+///
+/// Define el **estado analógico** de un gamepad: sticks y gatillos.
+///
+/// Complementa a [`InputStateExt`], que solo puede representar
+/// entradas digitales (botones). Cada implementación decide cómo identifica sus ejes
+/// mediante el tipo genérico `A`.
+///
+/// ## Rangos de valores
+/// - Ejes de stick: `-1.0..=1.0`, con `0.0` en reposo.
+/// - Gatillos: `0.0..=1.0`, con `0.0` en reposo.
+///
+/// Todos los valores que expone este trait ya tienen aplicada la **compensación de
+/// drift** configurada (ver [`set_drift_compensation`](Self::set_drift_compensation)).
+///
+/// ## Parámetros genéricos
+/// - `A`: Identificador de eje analógico del backend (por ejemplo, un enum `GamepadAxis`).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let (x, y) = pad.left_stick();
+/// player.move_by(x, y);
+///
+/// if pad.right_trigger() > 0.5 {
+///     player.fire();
+/// }
+/// ```
+pub trait GamepadStateExt<A>
+where
+    A: Copy + PartialEq,
+{
+    /// Retorna el valor compensado de un eje analógico.
+    fn axis(&self, axis: A) -> f32;
+
+    /// Retorna el valor compensado de un eje **estándar** de gamepad.
+    ///
+    /// A diferencia de [`axis`](Self::axis), que usa el identificador propio del
+    /// backend, este método usa el enum común [`Axis`], lo que permite recorrer los ejes
+    /// de forma genérica. Rango según [`Axis::is_trigger`]: `-1.0..=1.0` para sticks
+    /// (`y` positivo hacia arriba) y `0.0..=1.0` para gatillos.
+    fn axis_value(&self, axis: Axis) -> f32;
+
+    /// Retorna el valor de los seis ejes estándar, en el orden de [`Axis::ALL`].
+    ///
+    /// Útil para UIs de rebinding y grabadoras de input que tratan todos los ejes igual.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for (axis, value) in pad.all_axes() {
+    ///     recorder.push(frame, axis, value);
+    /// }
+    /// ```
+    fn all_axes(&self) -> [(Axis, f32); 6] {
+        Axis::ALL.map(|axis| (axis, self.axis_value(axis)))
+    }
+
+    /// Retorna la posición `(x, y)` del stick izquierdo.
+    ///
+    /// Atajo sobre [`axis_value`](Self::axis_value) con [`Axis::LeftX`] y [`Axis::LeftY`].
+    fn left_stick(&self) -> (f32, f32) {
+        (self.axis_value(Axis::LeftX), self.axis_value(Axis::LeftY))
+    }
+
+    /// Retorna la posición `(x, y)` del stick derecho.
+    ///
+    /// Atajo sobre [`axis_value`](Self::axis_value) con [`Axis::RightX`] y [`Axis::RightY`].
+    fn right_stick(&self) -> (f32, f32) {
+        (self.axis_value(Axis::RightX), self.axis_value(Axis::RightY))
+    }
+
+    /// Retorna el valor del gatillo izquierdo.
+    ///
+    /// Atajo sobre [`axis_value`](Self::axis_value) con [`Axis::LeftTrigger`].
+    fn left_trigger(&self) -> f32 {
+        self.axis_value(Axis::LeftTrigger)
+    }
+
+    /// Retorna el valor del gatillo derecho.
+    ///
+    /// Atajo sobre [`axis_value`](Self::axis_value) con [`Axis::RightTrigger`].
+    fn right_trigger(&self) -> f32 {
+        self.axis_value(Axis::RightTrigger)
+    }
+
+    // === COMPENSACIÓN DE DRIFT ===
+
+    /// Configura la compensación de *drift* de un eje.
+    ///
+    /// Los sticks desgastados reportan valores pequeños distintos de cero en reposo,
+    /// lo que provoca movimiento no deseado. `calibration` es `(center, band)`:
+    /// - `center`: valor crudo que el eje reporta en reposo; se resta a cada lectura.
+    /// - `band`: banda muerta alrededor del centro; si `|valor - center| <= band`
+    ///   el eje reporta `0.0`.
+    ///
+    /// Fuera de la banda el eje reporta `valor - center`, limitado a su rango.
+    /// La compensación se aplica **antes** de cualquier zona muerta o curva de
+    /// respuesta del backend, por lo que ambas se componen: la zona muerta opera sobre
+    /// el valor ya centrado.
+    ///
+    /// Usar `(0.0, 0.0)` desactiva la compensación.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // El stick reposa en 0.08 en lugar de 0.0
+    /// pad.set_drift_compensation(GamepadAxis::LeftX, (0.08, 0.02));
+    /// ```
+    fn set_drift_compensation(&mut self, axis: A, calibration: (f32, f32));
+
+    /// Toma la lectura **cruda actual** del eje como su nuevo centro de reposo.
+    ///
+    /// Debe llamarse con el stick suelto (por ejemplo, desde una pantalla de
+    /// calibración que pide al usuario no tocar el mando). Conserva la banda muerta
+    /// configurada previamente.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// ui.show("Suelta los sticks...");
+    /// pad.auto_calibrate(GamepadAxis::LeftX);
+    /// pad.auto_calibrate(GamepadAxis::LeftY);
+    /// ```
+    fn auto_calibrate(&mut self, axis: A);
+}
+
+/// # Trait `GamepadExt`
+///
+/// Contrato **compuesto** de un mando: botones digitales
+/// ([`InputStateExt`]), ejes analógicos ([`AxisExt`]) y vibración.
+///
+/// Al ser una composición de traits más pequeños, un backend reutiliza la misma
+/// maquinaria de teclado (flancos, historial, acciones) para los botones y la de
+/// ejes para los sticks.
+///
+/// ## Parámetros genéricos
+/// - `B`: Tipo de botón (la tecla `K` de `InputStateExt`).
+/// - `S`: Estado de botón.
+/// - `A`: Identificador de eje analógico.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// for pad in &mut pads {
+///     if !pad.connected() {
+///         continue;
+///     }
+///     let Some(slot) = pad.player_index() else { continue };
+///     if pad.is_just_press(PadButton::South) {
+///         players[slot as usize].jump();
+///         pad.set_rumble(0.3, 0.0, Duration::from_millis(80));
+///     }
+/// }
+/// ```
+pub trait GamepadExt<B, S, A>: InputStateExt<B, S> + AxisExt<A>
+where
+    B: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    A: Copy + PartialEq,
+{
+    /// Retorna `true` si el mando está conectado.
+    ///
+    /// Un mando desconectado reporta todos los botones liberados y los ejes
+    /// centrados.
+    fn connected(&self) -> bool;
+
+    /// Retorna la ranura de jugador asignada al mando para multijugador local, o
+    /// `None` si aún no tiene una.
+    fn player_index(&self) -> Option<u8>;
+
+    /// Activa la vibración durante `duration`, reemplazando la anterior.
+    ///
+    /// - `low`: intensidad del motor de baja frecuencia (pesado), `0.0..=1.0`.
+    /// - `high`: intensidad del motor de alta frecuencia (ligero), `0.0..=1.0`.
+    ///
+    /// Los valores fuera de rango se recortan. `set_rumble(0.0, 0.0, _)` detiene la
+    /// vibración. Los mandos sin vibración ignoran la llamada.
+    fn set_rumble(&mut self, low: f32, high: f32, duration: Duration);
+}
+
+/// Una de las ocho direcciones cardinales/diagonales de un stick.
+///
+/// Convención de ejes: `x` positivo hacia la derecha, `y` positivo hacia **arriba**.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    /// Arriba: `(0, 1)`.
+    Up,
+    /// Diagonal arriba-derecha.
+    UpRight,
+    /// Derecha: `(1, 0)`.
+    Right,
+    /// Diagonal abajo-derecha.
+    DownRight,
+    /// Abajo: `(0, -1)`.
+    Down,
+    /// Diagonal abajo-izquierda.
+    DownLeft,
+    /// Izquierda: `(-1, 0)`.
+    Left,
+    /// Diagonal arriba-izquierda.
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clasifica un vector `(x, y)` en la dirección más cercana.
+    ///
+    /// Cada dirección cubre un sector de 45° centrado en ella; un vector justo en el
+    /// límite entre dos sectores se clasifica como la diagonal.
+    /// Retorna `None` para el vector nulo.
+    pub fn from_vector(x: f32, y: f32) -> Option<Self> {
+        // tan(22.5°): límite entre un eje y la diagonal vecina. Se compara con
+        // pendientes en lugar de `atan2`, que no está disponible sin `std`.
+        const TAN_22_5: f32 = 0.414_213_57;
+
+        if x == 0.0 && y == 0.0 {
+            return None;
+        }
+
+        let (ax, ay) = (if x < 0.0 { -x } else { x }, if y < 0.0 { -y } else { y });
+        Some(if ay < ax * TAN_22_5 {
+            if x > 0.0 { Self::Right } else { Self::Left }
+        } else if ax < ay * TAN_22_5 {
+            if y > 0.0 { Self::Up } else { Self::Down }
+        } else {
+            match (x > 0.0, y > 0.0) {
+                (true, true) => Self::UpRight,
+                (false, true) => Self::UpLeft,
+                (false, false) => Self::DownLeft,
+                (true, false) => Self::DownRight,
+            }
+        })
+    }
+}
+
+/// Muestra temporal de la posición de un stick.
+///
+/// `Ts` es el tipo de marca de tiempo ([`InputEvent::Time`]) de los eventos del
+/// historial con los que se combina (por ejemplo, `Instant`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickSample<Ts> {
+    /// Eje horizontal, `-1.0..=1.0`.
+    pub x: f32,
+    /// Eje vertical, `-1.0..=1.0` (positivo hacia arriba).
+    pub y: f32,
+    /// Instante en que se tomó la muestra.
+    pub timestamp: Ts,
+}
+
+impl<Ts> StickSample<Ts> {
+    /// Magnitud del vector `(x, y)`. Disponible con el feature `std`.
+    #[cfg(feature = "std")]
+    pub fn magnitude(&self) -> f32 {
+        self.x.hypot(self.y)
+    }
+
+    /// Cuadrado de la magnitud del vector `(x, y)`; no requiere `std`.
+    pub fn magnitude_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+}
+
+/// # Trait `GamepadHistoryExt`
+///
+/// Combina el estado analógico de [`GamepadStateExt`] con el historial digital de
+/// [`WithHistoryExt`] para detectar **combos mixtos** stick + botón
+/// (por ejemplo, "flick del stick hacia un lado + botón" para un dodge-roll).
+///
+/// ## Parámetros genéricos
+/// - `A`: Identificador de eje analógico.
+/// - `B`: Tipo de botón (la tecla `K` del historial).
+/// - `S`: Estado de botón.
+/// - `T`: Tipo de evento histórico.
+pub trait GamepadHistoryExt<A, B, S, T>: GamepadStateExt<A> + WithHistoryExt<B, S, T>
+where
+    A: Copy + PartialEq,
+    B: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = B, State = S>,
+{
+    /// Devuelve las muestras recientes del stick usado para detectar flicks,
+    /// ordenadas de la más antigua a la más reciente.
+    fn stick_samples(&self) -> &[StickSample<T::Time>];
+
+    /// Magnitud a partir de la cual un movimiento del stick cuenta como *flick*.
+    ///
+    /// Por defecto `0.8`.
+    fn flick_threshold(&self) -> f32 {
+        0.8
+    }
+
+    /// Retorna `true` si hubo un *flick* del stick hacia `direction` seguido de una
+    /// pulsación de `button` dentro de `window`.
+    ///
+    /// ## Detección del flick
+    /// Un flick ocurre cuando dos muestras consecutivas de
+    /// [`stick_samples`](Self::stick_samples) **cruzan** el umbral
+    /// [`flick_threshold`](Self::flick_threshold): la anterior por debajo y la
+    /// siguiente igual o por encima, con esta última apuntando a `direction`
+    /// según [`Direction8::from_vector`]. Mantener el stick inclinado no genera
+    /// flicks nuevos.
+    ///
+    /// ## Ancla temporal
+    /// La ventana se mide desde el instante de la muestra que cruzó el umbral: la
+    /// pulsación del botón debe ocurrir en `[cruce, cruce + window]`. Un botón
+    /// presionado antes del flick no cuenta.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if pad.stick_button_combo(Direction8::Left, Button::B, Duration::from_millis(150)) {
+    ///     player.dodge_roll_left();
+    /// }
+    /// ```
+    fn stick_button_combo(&self, direction: Direction8, button: B, window: Duration) -> bool {
+        let threshold = self.flick_threshold() * self.flick_threshold();
+
+        self.stick_samples()
+            .windows(2)
+            .filter(|pair| {
+                pair[0].magnitude_squared() < threshold
+                    && pair[1].magnitude_squared() >= threshold
+                    && Direction8::from_vector(pair[1].x, pair[1].y) == Some(direction)
+            })
+            .any(|pair| {
+                let anchor = pair[1].timestamp;
+                self.history().iter().any(|event| {
+                    event.key() == button
+                        && event.is_press()
+                        && event.timestamp() >= anchor
+                        && Self::Clock::duration_between(anchor, event.timestamp()) <= window
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, Event, Input, Key, delegate_history_access, delegate_input_state, ms};
+    use crate::FrameTimeExt;
+
+    /// Mando con lecturas crudas fijadas por el test y la compensación documentada
+    /// en [`GamepadStateExt::set_drift_compensation`].
+    #[derive(Default)]
+    struct DriftingPad {
+        raw: [f32; 6],
+        calibration: [(f32, f32); 6],
+    }
+
+    fn slot(axis: Axis) -> usize {
+        Axis::ALL.iter().position(|candidate| *candidate == axis).unwrap()
+    }
+
+    impl GamepadStateExt<Axis> for DriftingPad {
+        fn axis(&self, axis: Axis) -> f32 {
+            let (center, band) = self.calibration[slot(axis)];
+            let centered = self.raw[slot(axis)] - center;
+            let range = if axis.is_trigger() { 0.0 } else { -1.0 };
+            if centered.abs() <= band { 0.0 } else { centered.clamp(range, 1.0) }
+        }
+
+        fn axis_value(&self, axis: Axis) -> f32 {
+            self.axis(axis)
+        }
+
+        fn set_drift_compensation(&mut self, axis: Axis, calibration: (f32, f32)) {
+            self.calibration[slot(axis)] = calibration;
+        }
+
+        fn auto_calibrate(&mut self, axis: Axis) {
+            self.calibration[slot(axis)].0 = self.raw[slot(axis)];
+        }
+    }
+
+    #[test]
+    fn compensation_zeroes_a_drifting_stick_at_rest() {
+        let mut pad = DriftingPad::default();
+        pad.raw[slot(Axis::LeftX)] = 0.08;
+        assert_eq!(pad.left_stick(), (0.08, 0.0));
+
+        pad.set_drift_compensation(Axis::LeftX, (0.08, 0.02));
+        assert_eq!(pad.left_stick(), (0.0, 0.0));
+
+        pad.raw[slot(Axis::LeftX)] = 0.09;
+        assert_eq!(pad.axis_value(Axis::LeftX), 0.0);
+    }
+
+    #[test]
+    fn compensation_recenters_readings_outside_the_band() {
+        let mut pad = DriftingPad::default();
+        pad.set_drift_compensation(Axis::LeftX, (0.25, 0.02));
+        pad.raw[slot(Axis::LeftX)] = 0.75;
+
+        assert_eq!(pad.axis_value(Axis::LeftX), 0.5);
+    }
+
+    #[test]
+    fn auto_calibrate_samples_the_resting_value() {
+        let mut pad = DriftingPad::default();
+        pad.set_drift_compensation(Axis::RightY, (0.0, 0.05));
+        pad.raw[slot(Axis::RightY)] = -0.25;
+
+        pad.auto_calibrate(Axis::RightY);
+
+        assert_eq!(pad.right_stick(), (0.0, 0.0));
+        assert_eq!(pad.calibration[slot(Axis::RightY)], (-0.25, 0.05));
+        assert_eq!(pad.all_axes()[slot(Axis::RightY)], (Axis::RightY, 0.0));
+    }
+
+    #[test]
+    fn conveniences_agree_with_axis_value() {
+        let pad = DriftingPad { raw: [-0.5, 0.25, 0.75, -1.0, 0.125, 1.0], ..Default::default() };
+
+        assert_eq!(pad.left_stick(), (pad.axis_value(Axis::LeftX), pad.axis_value(Axis::LeftY)));
+        assert_eq!(pad.right_stick(), (pad.axis_value(Axis::RightX), pad.axis_value(Axis::RightY)));
+        assert_eq!(pad.left_trigger(), pad.axis_value(Axis::LeftTrigger));
+        assert_eq!(pad.right_trigger(), pad.axis_value(Axis::RightTrigger));
+        assert_eq!(pad.left_stick(), (-0.5, 0.25));
+        assert_eq!(pad.right_trigger(), 1.0);
+    }
+
+    #[test]
+    fn all_axes_follows_the_standard_order() {
+        let pad = DriftingPad { raw: [0.1, 0.2, 0.3, 0.4, 0.5, 0.6], ..Default::default() };
+
+        let axes = pad.all_axes();
+
+        assert_eq!(axes.map(|(axis, _)| axis), Axis::ALL);
+        assert!(axes.iter().all(|&(axis, value)| value == pad.axis_value(axis)));
+        assert_eq!(Axis::ALL.map(Axis::is_trigger), [false, false, false, false, true, true]);
+    }
+
+    /// Mando con botones e historial del mock, muestras del stick fijadas por el test y
+    /// conexión, ranura de jugador y vibración simuladas.
+    #[derive(Default)]
+    struct FlickPad {
+        buttons: Input,
+        samples: Vec<StickSample<Duration>>,
+        connected: bool,
+        player: Option<u8>,
+        rumble: Option<(f32, f32, Duration)>,
+    }
+
+    impl FlickPad {
+        fn stick(&mut self, at: u64, x: f32, y: f32) {
+            self.samples.push(StickSample { x, y, timestamp: ms(at) });
+        }
+
+        fn press(&mut self, at: u64, button: Key) {
+            self.buttons.update_time(ms(at));
+            self.buttons.set_key(button, Btn::Down);
+        }
+    }
+
+    delegate_input_state!(FlickPad, buttons);
+    delegate_history_access!(FlickPad, buttons);
+
+    impl GamepadStateExt<Axis> for FlickPad {
+        fn axis(&self, axis: Axis) -> f32 {
+            self.axis_value(axis)
+        }
+
+        fn axis_value(&self, axis: Axis) -> f32 {
+            let last = self.samples.last();
+            match axis {
+                Axis::LeftX => last.map_or(0.0, |sample| sample.x),
+                Axis::LeftY => last.map_or(0.0, |sample| sample.y),
+                _ => 0.0,
+            }
+        }
+
+        fn set_drift_compensation(&mut self, _axis: Axis, _calibration: (f32, f32)) {}
+
+        fn auto_calibrate(&mut self, _axis: Axis) {}
+    }
+
+    impl GamepadHistoryExt<Axis, Key, Btn, Event> for FlickPad {
+        fn stick_samples(&self) -> &[StickSample<Duration>] {
+            &self.samples
+        }
+    }
+
+    impl AxisExt<Axis> for FlickPad {
+        fn raw_axis(&self, axis: Axis) -> i32 {
+            (self.axis_value(axis) * 32767.0) as i32
+        }
+
+        fn axis_range(&self, _axis: Axis) -> (i32, i32) {
+            (-32767, 32767)
+        }
+
+        fn axes_pressed(&self) -> Vec<Axis> {
+            Axis::ALL.into_iter().filter(|axis| self.raw_axis(*axis) != 0).collect()
+        }
+
+        fn set_axis(&mut self, axis: Axis, raw: i32) {
+            let last = self.samples.last().copied();
+            let mut sample = last.unwrap_or(StickSample { x: 0.0, y: 0.0, timestamp: Duration::ZERO });
+            match axis {
+                Axis::LeftX => sample.x = raw as f32 / 32767.0,
+                Axis::LeftY => sample.y = raw as f32 / 32767.0,
+                _ => return,
+            }
+            self.samples.push(sample);
+        }
+    }
+
+    impl GamepadExt<Key, Btn, Axis> for FlickPad {
+        fn connected(&self) -> bool {
+            self.connected
+        }
+
+        fn player_index(&self) -> Option<u8> {
+            self.player
+        }
+
+        fn set_rumble(&mut self, low: f32, high: f32, duration: Duration) {
+            self.rumble = Some((low.clamp(0.0, 1.0), high.clamp(0.0, 1.0), duration));
+        }
+    }
+
+    #[test]
+    fn flick_then_button_within_the_window() {
+        let mut pad = FlickPad::default();
+        pad.stick(0, 0.0, 0.0);
+        pad.stick(10, -0.9, 0.0);
+        pad.press(100, Key::A);
+
+        assert!(pad.stick_button_combo(Direction8::Left, Key::A, ms(90)));
+        assert!(!pad.stick_button_combo(Direction8::Right, Key::A, ms(90)));
+        assert!(!pad.stick_button_combo(Direction8::Left, Key::B, ms(90)));
+    }
+
+    #[test]
+    fn button_outside_the_window_does_not_count() {
+        let mut pad = FlickPad::default();
+        pad.stick(0, 0.0, 0.0);
+        pad.stick(10, -0.9, 0.0);
+        pad.press(200, Key::A);
+
+        assert!(!pad.stick_button_combo(Direction8::Left, Key::A, ms(150)));
+    }
+
+    #[test]
+    fn button_before_the_flick_does_not_count() {
+        let mut pad = FlickPad::default();
+        pad.press(0, Key::A);
+        pad.stick(10, 0.0, 0.0);
+        pad.stick(20, -0.9, 0.0);
+
+        assert!(!pad.stick_button_combo(Direction8::Left, Key::A, ms(150)));
+    }
+
+    #[test]
+    fn holding_the_stick_is_not_a_new_flick() {
+        let mut pad = FlickPad::default();
+        pad.stick(0, -0.9, 0.0);
+        pad.stick(10, -0.95, 0.0);
+        pad.press(20, Key::A);
+
+        assert!(!pad.stick_button_combo(Direction8::Left, Key::A, ms(150)));
+    }
+
+    /// Lógica de juego que solo conoce [`GamepadExt`]: salto con vibración corta.
+    fn jump(pad: &mut impl GamepadExt<Key, Btn, Axis>) -> Option<u8> {
+        if !pad.connected() {
+            return None;
+        }
+        let slot = pad.player_index()?;
+        if !pad.is_just_press(Key::A) {
+            return None;
+        }
+        pad.set_rumble(0.3, 0.0, ms(80));
+        Some(slot)
+    }
+
+    #[test]
+    fn composite_pad_drives_buttons_and_rumble() {
+        let mut pad = FlickPad { connected: true, player: Some(1), ..Default::default() };
+        assert_eq!(jump(&mut pad), None);
+
+        pad.press(0, Key::A);
+        assert_eq!(jump(&mut pad), Some(1));
+        assert_eq!(pad.rumble, Some((0.3, 0.0, ms(80))));
+
+        pad.advance_frame();
+        pad.rumble = None;
+        assert_eq!(jump(&mut pad), None);
+        assert_eq!(pad.rumble, None);
+    }
+
+    #[test]
+    fn unassigned_or_disconnected_pads_are_skipped() {
+        let mut pad = FlickPad { player: Some(0), ..Default::default() };
+        pad.press(0, Key::A);
+        assert_eq!(jump(&mut pad), None);
+
+        pad.connected = true;
+        pad.player = None;
+        assert_eq!(jump(&mut pad), None);
+        assert_eq!(pad.rumble, None);
+    }
+
+    #[test]
+    fn composite_pad_exposes_the_raw_axes() {
+        let mut pad = FlickPad { connected: true, ..Default::default() };
+        pad.set_axis(Axis::LeftX, -32767);
+        pad.set_axis(Axis::LeftY, 16383);
+
+        assert_eq!(pad.raw_axis(Axis::LeftX), -32767);
+        assert_eq!(pad.normalized_axis(Axis::LeftX), -1.0);
+        assert_eq!(pad.axes_pressed(), [Axis::LeftX, Axis::LeftY]);
+
+        pad.set_rumble(1.5, -0.5, ms(10));
+        assert_eq!(pad.rumble, Some((1.0, 0.0, ms(10))));
+    }
+}
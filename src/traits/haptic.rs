@@ -0,0 +1,189 @@
+use core::time::Duration;
+
+/// Forma de onda de un [`HapticEffect::Periodic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    /// Senoidal: vibración suave.
+    Sine,
+    /// Cuadrada: pulsos secos.
+    Square,
+    /// Triangular.
+    Triangle,
+    /// Diente de sierra ascendente.
+    Sawtooth,
+}
+
+/// Efecto de vibración reproducido con [`HapticExt::rumble`].
+///
+/// Todas las intensidades van de `0.0` (sin vibración) a `1.0` (máxima) y los
+/// valores fuera de rango se recortan. El conjunto es deliberadamente pequeño para
+/// que se corresponda con los efectos de Linux FF (`FF_CONSTANT`, `FF_RAMP`,
+/// `FF_PERIODIC`); los backends más simples, como XInput, que solo fijan la
+/// intensidad de dos motores, aproximan las rampas y ondas actualizando la
+/// intensidad en el tiempo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HapticEffect {
+    /// Intensidad fija durante `duration`.
+    Constant {
+        /// Intensidad de la vibración.
+        intensity: f32,
+        /// Duración del efecto.
+        duration: Duration,
+    },
+    /// Intensidad que varía linealmente de `start` a `end` durante `duration`.
+    Ramp {
+        /// Intensidad al comenzar.
+        start: f32,
+        /// Intensidad al terminar.
+        end: f32,
+        /// Duración del efecto.
+        duration: Duration,
+    },
+    /// Onda periódica de amplitud `intensity` durante `duration`.
+    Periodic {
+        /// Forma de la onda.
+        waveform: Waveform,
+        /// Amplitud de la onda.
+        intensity: f32,
+        /// Duración de un ciclo.
+        period: Duration,
+        /// Duración del efecto.
+        duration: Duration,
+    },
+}
+
+impl HapticEffect {
+    /// Duración total del efecto.
+    pub fn duration(&self) -> Duration {
+        match self {
+            HapticEffect::Constant { duration, .. }
+            | HapticEffect::Ramp { duration, .. }
+            | HapticEffect::Periodic { duration, .. } => *duration,
+        }
+    }
+}
+
+/// # Trait `HapticExt`
+///
+/// Define el **feedback háptico** de un dispositivo con motores de vibración.
+///
+/// Es independiente de los traits de teclado: un backend de mando puede implementar
+/// [`GamepadExt`](crate::GamepadExt) y `HapticExt` a la vez. `GamepadExt::set_rumble`
+/// cubre el caso simple de dos motores; este trait añade efectos con forma.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// if pad.supports_haptics() {
+///     pad.rumble(HapticEffect::Ramp {
+///         start: 1.0,
+///         end: 0.0,
+///         duration: Duration::from_millis(300),
+///     })?;
+/// }
+/// ```
+pub trait HapticExt {
+    /// Tipo de error retornado por el backend.
+    type Error;
+
+    /// Retorna `true` si el dispositivo tiene motores de vibración.
+    ///
+    /// Si retorna `false`, [`rumble`](Self::rumble) no tiene efecto.
+    fn supports_haptics(&self) -> bool;
+
+    /// Reproduce `effect`, reemplazando cualquier efecto en curso.
+    ///
+    /// # Errores
+    ///
+    /// Específicos del backend, por ejemplo si el dispositivo se desconectó o no
+    /// admite la forma de onda pedida.
+    fn rumble(&mut self, effect: HapticEffect) -> Result<(), Self::Error>;
+
+    /// Detiene inmediatamente cualquier vibración en curso.
+    fn stop_haptics(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Error del mando de prueba.
+    #[derive(Debug, PartialEq)]
+    struct Unplugged;
+
+    /// Mando de referencia que recuerda el efecto en curso.
+    struct Pad {
+        motors: bool,
+        connected: bool,
+        playing: Option<HapticEffect>,
+    }
+
+    impl HapticExt for Pad {
+        type Error = Unplugged;
+
+        fn supports_haptics(&self) -> bool {
+            self.motors
+        }
+
+        fn rumble(&mut self, effect: HapticEffect) -> Result<(), Unplugged> {
+            if !self.connected {
+                return Err(Unplugged);
+            }
+            if self.motors {
+                self.playing = Some(effect);
+            }
+            Ok(())
+        }
+
+        fn stop_haptics(&mut self) {
+            self.playing = None;
+        }
+    }
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn duration_is_the_total_length_of_every_effect() {
+        let constant = HapticEffect::Constant { intensity: 0.5, duration: ms(100) };
+        let ramp = HapticEffect::Ramp { start: 1.0, end: 0.0, duration: ms(300) };
+        let periodic = HapticEffect::Periodic {
+            waveform: Waveform::Square,
+            intensity: 1.0,
+            period: ms(20),
+            duration: ms(500),
+        };
+
+        assert_eq!(constant.duration(), ms(100));
+        assert_eq!(ramp.duration(), ms(300));
+        // La duración es la del efecto completo, no la de un ciclo.
+        assert_eq!(periodic.duration(), ms(500));
+    }
+
+    #[test]
+    fn rumble_replaces_the_current_effect_until_stopped() {
+        let mut pad = Pad { motors: true, connected: true, playing: None };
+        let first = HapticEffect::Constant { intensity: 1.0, duration: ms(100) };
+        let second = HapticEffect::Ramp { start: 1.0, end: 0.0, duration: ms(300) };
+
+        assert_eq!(pad.rumble(first), Ok(()));
+        assert_eq!(pad.rumble(second), Ok(()));
+        assert_eq!(pad.playing, Some(second));
+
+        pad.stop_haptics();
+        assert_eq!(pad.playing, None);
+    }
+
+    #[test]
+    fn devices_without_motors_ignore_effects_and_errors_surface() {
+        let effect = HapticEffect::Constant { intensity: 1.0, duration: ms(100) };
+
+        let mut silent = Pad { motors: false, connected: true, playing: None };
+        assert!(!silent.supports_haptics());
+        assert_eq!(silent.rumble(effect), Ok(()));
+        assert_eq!(silent.playing, None);
+
+        let mut gone = Pad { motors: true, connected: false, playing: None };
+        assert_eq!(gone.rumble(effect), Err(Unplugged));
+    }
+}
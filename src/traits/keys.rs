@@ -34,6 +34,7 @@
 //! impl KeyStateExt<MyBackendState, KeyState> for MyBackendState { ... }
 //! ```
 
+use alloc::vec::Vec;
 
 /// El trait [`KeyExt`] define la interfaz para **convertir entre códigos de tecla nativos**
 /// y una representación unificada, ya sea el [`KeyCode`] del crate o uno definido por el usuario.
@@ -144,6 +145,287 @@ where
 
     /// Convierte una tecla normalizada (`N`) a su equivalente nativo del backend (`B`).
     fn to_backend_key(code: N) -> B;
+
+    /// Convierte un **lote** de teclas del backend, escribiendo el resultado en `out`.
+    ///
+    /// Pensado para runtimes que drenan ráfagas de eventos del SO (por ejemplo,
+    /// reportes evdev con cientos de eventos por frame) y quieren evitar el costo de
+    /// una llamada por evento. La implementación por defecto recorre `keys` y llama a
+    /// [`from_backend_key`](Self::from_backend_key) para cada una; los backends con
+    /// conversiones basadas en tablas o vectorizables pueden sobrescribirla.
+    ///
+    /// `out[i]` recibe la conversión de `keys[i]`.
+    ///
+    /// # Panics
+    /// Si `out.len() != keys.len()`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let mut codes = [KeyCode::Unknown; 64];
+    /// let n = raw_keys.len();
+    /// EvdevKey::from_backend_keys(&raw_keys, &mut codes[..n]);
+    /// ```
+    fn from_backend_keys(keys: &[B], out: &mut [N]) {
+        assert_eq!(
+            keys.len(),
+            out.len(),
+            "from_backend_keys: `out` debe tener la misma longitud que `keys`"
+        );
+
+        for (slot, &key) in out.iter_mut().zip(keys) {
+            *slot = Self::from_backend_key(key);
+        }
+    }
+
+    /// Retorna la **tabla completa** de correspondencias `(backend, normalizada)`.
+    ///
+    /// Disponible cuando el implementador también implementa [`AllKeys<N>`]: recorre
+    /// [`AllKeys::all_keys`] y obtiene el equivalente nativo de cada tecla con
+    /// [`to_backend_key`](Self::to_backend_key). Útil para UIs de rebinding o
+    /// generadores de documentación que muestran qué teclas físicas están soportadas.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for (native, key) in <u32 as KeyExt<u32, MyKeyCode>>::all_mappings() {
+    ///     println!("0x{native:02X} -> {key:?}");
+    /// }
+    /// ```
+    fn all_mappings() -> Vec<(B, N)>
+    where
+        Self: AllKeys<N>,
+        N: 'static,
+    {
+        Self::all_keys()
+            .iter()
+            .map(|&code| (Self::to_backend_key(code), code))
+            .collect()
+    }
+}
+
+/// El trait [`AllKeys`] permite **enumerar** todas las teclas normalizadas que un
+/// backend sabe mapear.
+///
+/// Se implementa normalmente sobre el mismo tipo que implementa [`KeyExt`], y habilita
+/// [`KeyExt::all_mappings`].
+///
+/// # Reglas de implementación
+/// - La lista **no debe incluir** teclas "comodín" como `Unknown`: solo teclas con un
+///   equivalente nativo real.
+/// - Cada tecla debe aparecer una sola vez.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::AllKeys;
+///
+/// impl AllKeys<MyKeyCode> for u32 {
+///     fn all_keys() -> &'static [MyKeyCode] {
+///         &[MyKeyCode::Jump, MyKeyCode::Crouch, MyKeyCode::Attack]
+///     }
+/// }
+/// ```
+pub trait AllKeys<N>
+where
+    N: 'static,
+{
+    /// Retorna todas las teclas normalizadas soportadas, sin valores "comodín".
+    fn all_keys() -> &'static [N];
+}
+
+/// Error de conversión de tecla producido por [`TryKeyExt`].
+///
+/// Contiene el **valor exacto** que no pudo convertirse, para que el runtime pueda
+/// registrar qué scancode (o tecla normalizada) carece de correspondencia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyConversionError<T> {
+    /// Valor que no tiene equivalente en el otro espacio de teclas.
+    pub value: T,
+}
+
+impl<T: core::fmt::Debug> core::fmt::Display for KeyConversionError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "tecla sin correspondencia: {:?}", self.value)
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for KeyConversionError<T> {}
+
+/// El trait [`TryKeyExt`] es la variante **falible** de [`KeyExt`].
+///
+/// Con [`KeyExt::from_backend_key`] el implementador está obligado a inventar una
+/// variante `Unknown` donde caen todos los códigos sin mapear, perdiendo información.
+/// `TryKeyExt` retorna en su lugar un [`KeyConversionError`] con el valor original,
+/// algo especialmente útil en backends como evdev, con miles de scancodes posibles.
+///
+/// # Implementación automática
+/// Cualquier tipo que ya implemente [`KeyExt`] con una tecla "comodín" obtiene
+/// `TryKeyExt` implementando [`UnmappedKeyExt`], que solo pide un predicado.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// match EvdevKey::try_from_backend_key(raw) {
+///     Ok(key) => state.set_key(key, KeyState::Pressed),
+///     Err(err) => log::debug!("{err}"), // "tecla sin correspondencia: KEY_PROG3"
+/// }
+/// ```
+pub trait TryKeyExt<B, N>
+where
+    B: Copy + PartialEq,
+    N: Copy + PartialEq,
+{
+    /// Convierte una tecla del backend (`B`) a su representación normalizada (`N`).
+    ///
+    /// # Errores
+    /// Retorna el valor de backend original si no tiene equivalente normalizado.
+    fn try_from_backend_key(key: B) -> Result<N, KeyConversionError<B>>;
+
+    /// Convierte una tecla normalizada (`N`) a su equivalente nativo del backend (`B`).
+    ///
+    /// # Errores
+    /// Retorna la tecla normalizada original si no tiene equivalente nativo.
+    fn try_to_backend_key(code: N) -> Result<B, KeyConversionError<N>>;
+}
+
+/// Predicado que identifica la tecla "comodín" (`Unknown`) de una implementación de
+/// [`KeyExt`], habilitando la implementación automática de [`TryKeyExt`].
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// impl UnmappedKeyExt<u32, MyKeyCode> for u32 {
+///     fn is_unmapped(code: MyKeyCode) -> bool {
+///         code == MyKeyCode::Unknown
+///     }
+/// }
+///
+/// // Ahora `u32` implementa `TryKeyExt<u32, MyKeyCode>`
+/// let key = u32::try_from_backend_key(0x99)?;
+/// ```
+pub trait UnmappedKeyExt<B, N>: KeyExt<B, N>
+where
+    B: Copy + PartialEq,
+    N: Copy + PartialEq,
+{
+    /// Retorna `true` si `code` es la tecla comodín que representa "sin correspondencia".
+    fn is_unmapped(code: N) -> bool;
+}
+
+impl<T, B, N> TryKeyExt<B, N> for T
+where
+    T: UnmappedKeyExt<B, N>,
+    B: Copy + PartialEq,
+    N: Copy + PartialEq,
+{
+    fn try_from_backend_key(key: B) -> Result<N, KeyConversionError<B>> {
+        let code = T::from_backend_key(key);
+        if T::is_unmapped(code) {
+            Err(KeyConversionError { value: key })
+        } else {
+            Ok(code)
+        }
+    }
+
+    fn try_to_backend_key(code: N) -> Result<B, KeyConversionError<N>> {
+        if T::is_unmapped(code) {
+            Err(KeyConversionError { value: code })
+        } else {
+            Ok(T::to_backend_key(code))
+        }
+    }
+}
+
+/// Implementación **identidad** de [`KeyExt`] para backends que ya usan el tipo de
+/// tecla del motor (backends experimentales, mocks y tests).
+///
+/// Tanto [`from_backend_key`](KeyExt::from_backend_key) como
+/// [`to_backend_key`](KeyExt::to_backend_key) retornan su argumento sin cambios.
+///
+/// # Coherencia
+/// Esta implementación cubre `KeyExt<T, T>` para **cualquier** `T: Copy + PartialEq`,
+/// por lo que con la feature activa ningún crate puede implementar `KeyExt<T, T>` por
+/// su cuenta: el compilador lo rechaza como implementación conflictiva (E0119). Como
+/// las features de Cargo se unifican en todo el grafo de dependencias, activarla en
+/// un crate afecta a todos los demás que compartan `orbit_input_core`.
+///
+/// Por eso está detrás de la feature `identity` (desactivada por defecto), pensada
+/// sobre todo para `dev-dependencies`. Las implementaciones entre tipos distintos
+/// (`KeyExt<B, N>` con `B != N`) no se ven afectadas.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// // Cargo.toml: orbit_input_core = { version = "...", features = ["identity"] }
+/// let code = <KeyCode as KeyExt<KeyCode, KeyCode>>::from_backend_key(KeyCode::A);
+/// assert_eq!(code, KeyCode::A);
+/// ```
+#[cfg(feature = "identity")]
+impl<T> KeyExt<T, T> for T
+where
+    T: Copy + PartialEq,
+{
+    fn from_backend_key(key: T) -> T {
+        key
+    }
+
+    fn to_backend_key(code: T) -> T {
+        code
+    }
+}
+
+/// Fallo de simetría detectado por [`verify_roundtrip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundtripFailure<N, B> {
+    /// Tecla normalizada de partida.
+    pub key: N,
+    /// Valor nativo obtenido con [`KeyExt::to_backend_key`].
+    pub backend: B,
+    /// Tecla normalizada obtenida al convertir `backend` de vuelta con
+    /// [`KeyExt::from_backend_key`]; distinta de `key`.
+    pub roundtripped: N,
+}
+
+impl<N: core::fmt::Debug, B: core::fmt::Debug> core::fmt::Display for RoundtripFailure<N, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "conversión asimétrica: {:?} -> {:?} -> {:?}",
+            self.key, self.backend, self.roundtripped
+        )
+    }
+}
+
+impl<N: core::fmt::Debug, B: core::fmt::Debug> core::error::Error for RoundtripFailure<N, B> {}
+
+/// Verifica que la implementación de [`KeyExt`] de `K` sea **simétrica** para las
+/// teclas dadas: `from_backend_key(to_backend_key(k)) == k`.
+///
+/// Es una ayuda para los tests de los backends, que suelen desincronizar ambas
+/// direcciones al añadir teclas. Con [`AllKeys`] se puede verificar la tabla completa.
+///
+/// # Errores
+/// Retorna la primera tecla de `samples` que no sobrevive la ida y vuelta, junto con
+/// el valor nativo intermedio y el resultado obtenido.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// #[test]
+/// fn evdev_mapping_is_symmetric() {
+///     let keys = <EvdevKey as AllKeys<KeyCode>>::all_keys();
+///     verify_roundtrip::<EvdevKey, KeyCode, EvdevKey>(keys).unwrap();
+/// }
+/// ```
+pub fn verify_roundtrip<B, N, K>(samples: &[N]) -> Result<(), RoundtripFailure<N, B>>
+where
+    B: Copy + PartialEq,
+    N: Copy + PartialEq,
+    K: KeyExt<B, N>,
+{
+    for &key in samples {
+        let backend = K::to_backend_key(key);
+        let roundtripped = K::from_backend_key(backend);
+        if roundtripped != key {
+            return Err(RoundtripFailure { key, backend, roundtripped });
+        }
+    }
+    Ok(())
 }
 
 /// El trait [`KeyStateExt`] define la interfaz para **traducir entre los estados de tecla nativos**
@@ -232,4 +514,209 @@ where
 
     /// Convierte un estado interno (`O`) a su equivalente nativo del backend (`I`).
     fn to_external_state(state: O) -> I;
-}
\ No newline at end of file
+
+    /// Retorna `true` si `state` es una **repetición automática** generada por el
+    /// teclado o el sistema operativo, y no una pulsación física nueva.
+    ///
+    /// [`from_external_state`](Self::from_external_state) suele mapear la repetición a
+    /// un estado "mantenida", perdiendo la distinción. Con este método el runtime puede
+    /// decidir, según el juego, si una repetición cuenta como un flanco nuevo de
+    /// [`is_just_press`](crate::InputStateExt::is_just_press) (entrada de texto) o se
+    /// ignora (juegos de pelea, que solo quieren la pulsación inicial).
+    ///
+    /// Por defecto retorna `false`: el backend no distingue repeticiones.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// impl KeyStateExt<i32, KeyState> for i32 {
+    ///     // ...
+    ///     fn is_repeat(state: i32) -> bool {
+    ///         state == 2 // evdev: 0 = soltada, 1 = presionada, 2 = autorepeat
+    ///     }
+    /// }
+    /// ```
+    fn is_repeat(state: I) -> bool {
+        let _ = state;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Code {
+        Jump,
+        Crouch,
+        Attack,
+        Unknown,
+    }
+
+    /// Tabla de scancodes `u8` de un backend de juguete.
+    struct Scancodes;
+
+    impl KeyExt<u8, Code> for Scancodes {
+        fn from_backend_key(key: u8) -> Code {
+            match key {
+                0x10 => Code::Jump,
+                0x11 => Code::Crouch,
+                0x12 => Code::Attack,
+                _ => Code::Unknown,
+            }
+        }
+
+        fn to_backend_key(code: Code) -> u8 {
+            match code {
+                Code::Jump => 0x10,
+                Code::Crouch => 0x11,
+                Code::Attack => 0x12,
+                Code::Unknown => 0x00,
+            }
+        }
+    }
+
+    impl AllKeys<Code> for Scancodes {
+        fn all_keys() -> &'static [Code] {
+            &[Code::Jump, Code::Crouch, Code::Attack]
+        }
+    }
+
+    #[test]
+    fn all_mappings_lists_the_table() {
+        assert_eq!(
+            Scancodes::all_mappings(),
+            [(0x10, Code::Jump), (0x11, Code::Crouch), (0x12, Code::Attack)]
+        );
+    }
+
+    #[test]
+    fn every_mapped_pair_round_trips() {
+        for (native, code) in Scancodes::all_mappings() {
+            assert_eq!(Scancodes::from_backend_key(native), code);
+        }
+        assert!(!Scancodes::all_keys().contains(&Code::Unknown));
+    }
+
+    impl UnmappedKeyExt<u8, Code> for Scancodes {
+        fn is_unmapped(code: Code) -> bool {
+            code == Code::Unknown
+        }
+    }
+
+    #[test]
+    fn try_conversion_reports_the_unmapped_value() {
+        assert_eq!(Scancodes::try_from_backend_key(0x11), Ok(Code::Crouch));
+        assert_eq!(Scancodes::try_from_backend_key(0x99), Err(KeyConversionError { value: 0x99 }));
+        assert_eq!(Scancodes::try_to_backend_key(Code::Attack), Ok(0x12));
+        assert_eq!(
+            Scancodes::try_to_backend_key(Code::Unknown),
+            Err(KeyConversionError { value: Code::Unknown })
+        );
+    }
+
+    #[test]
+    fn batch_conversion_matches_single_conversions() {
+        let raw = [0x12, 0x10, 0x99];
+        let mut codes = [Code::Unknown; 3];
+
+        Scancodes::from_backend_keys(&raw, &mut codes);
+
+        assert_eq!(codes, raw.map(Scancodes::from_backend_key));
+        assert_eq!(codes, [Code::Attack, Code::Jump, Code::Unknown]);
+    }
+
+    #[test]
+    #[should_panic(expected = "misma longitud")]
+    fn batch_conversion_rejects_a_short_output() {
+        Scancodes::from_backend_keys(&[0x10, 0x11], &mut [Code::Unknown; 1]);
+    }
+
+    /// Tabla con un error de copia: `Attack` se convierte al scancode de `Crouch`.
+    struct Asymmetric;
+
+    impl KeyExt<u8, Code> for Asymmetric {
+        fn from_backend_key(key: u8) -> Code {
+            Scancodes::from_backend_key(key)
+        }
+
+        fn to_backend_key(code: Code) -> u8 {
+            match code {
+                Code::Attack => 0x11,
+                code => Scancodes::to_backend_key(code),
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_table_round_trips() {
+        assert_eq!(verify_roundtrip::<u8, Code, Scancodes>(Scancodes::all_keys()), Ok(()));
+    }
+
+    #[test]
+    fn roundtrip_reports_the_first_asymmetric_key() {
+        assert_eq!(
+            verify_roundtrip::<u8, Code, Asymmetric>(&[Code::Jump, Code::Attack, Code::Crouch]),
+            Err(RoundtripFailure { key: Code::Attack, backend: 0x11, roundtripped: Code::Crouch })
+        );
+    }
+
+    /// Estados evdev: 0 = soltada, 1 = presionada, 2 = autorepeat.
+    struct Evdev;
+
+    impl KeyStateExt<i32, bool> for Evdev {
+        fn from_external_state(state: i32) -> bool {
+            state != 0
+        }
+
+        fn to_external_state(state: bool) -> i32 {
+            i32::from(state)
+        }
+
+        fn is_repeat(state: i32) -> bool {
+            state == 2
+        }
+    }
+
+    /// Backend que no distingue repeticiones.
+    struct Plain;
+
+    impl KeyStateExt<i32, bool> for Plain {
+        fn from_external_state(state: i32) -> bool {
+            state != 0
+        }
+
+        fn to_external_state(state: bool) -> i32 {
+            i32::from(state)
+        }
+    }
+
+    #[test]
+    fn repeat_is_distinguished_only_when_the_backend_says_so() {
+        assert!(Evdev::from_external_state(2));
+        assert!(Evdev::is_repeat(2));
+        assert!(!Evdev::is_repeat(1));
+        assert!(!Plain::is_repeat(2));
+    }
+
+    #[cfg(feature = "identity")]
+    #[test]
+    fn identity_conversion_returns_its_argument() {
+        assert_eq!(<u16 as KeyExt<u16, u16>>::from_backend_key(0x1E), 0x1E);
+        assert_eq!(<Code as KeyExt<Code, Code>>::to_backend_key(Code::Crouch), Code::Crouch);
+
+        let mut out = [Code::Unknown; 2];
+        Code::from_backend_keys(&[Code::Jump, Code::Attack], &mut out);
+        assert_eq!(out, [Code::Jump, Code::Attack]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn conversion_error_roundtrips_through_json() {
+        let error = Scancodes::try_from_backend_key(0xFF).unwrap_err();
+
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"value":255}"#);
+        assert_eq!(serde_json::from_str::<KeyConversionError<u8>>(&json).unwrap(), error);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::KeyboardLayout;
+
+/// # Trait `LayoutExt`
+///
+/// Traduce entre la **posición física** de una tecla y la tecla **lógica** que
+/// produce según la [`KeyboardLayout`] activa.
+///
+/// Los bindings de movimiento (WASD) deben ir por posición: en AZERTY, la tecla
+/// bajo la posición de `W` es `Z`, y el jugador espera moverse con ella. La entrada
+/// de texto, en cambio, sigue la distribución. Este trait da al motor una forma
+/// estándar de preguntar "¿qué tecla hay en la posición de W con esta
+/// distribución?".
+///
+/// ## Convención
+/// Las teclas **físicas** se nombran por su posición en QWERTY (EE. UU.), como los
+/// scancodes USB HID. Con [`KeyboardLayout::Qwerty`] ambas funciones son la
+/// identidad, y para cualquier distribución son inversas entre sí.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// keyboard.set_layout(KeyboardLayout::Azerty);
+///
+/// // Mostrar en la UI la tecla que realmente hay que pulsar para "avanzar"
+/// let forward = keyboard.physical_to_logical(KeyCode::W); // KeyCode::Z
+///
+/// // Un binding por posición a partir de lo que el jugador pulsó
+/// let position = keyboard.logical_to_physical(KeyCode::Z); // KeyCode::W
+/// ```
+pub trait LayoutExt<K>
+where
+    K: Copy + PartialEq,
+{
+    /// Retorna la tecla lógica que produce la posición física `key` en la
+    /// distribución activa.
+    fn physical_to_logical(&self, key: K) -> K;
+
+    /// Retorna la posición física en la que está la tecla lógica `key` en la
+    /// distribución activa.
+    fn logical_to_physical(&self, key: K) -> K;
+
+    /// Cambia la distribución activa.
+    fn set_layout(&mut self, layout: KeyboardLayout);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Teclas nombradas por su posición en QWERTY.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Letter {
+        Q,
+        W,
+        A,
+        Z,
+        S,
+    }
+
+    /// Pares de posiciones que AZERTY intercambia respecto a QWERTY.
+    const AZERTY_SWAPS: [(Letter, Letter); 2] = [(Letter::Q, Letter::A), (Letter::W, Letter::Z)];
+
+    /// Teclado de referencia que solo conoce QWERTY y AZERTY.
+    #[derive(Default)]
+    struct Keyboard {
+        layout: KeyboardLayout,
+    }
+
+    impl Keyboard {
+        fn swap(&self, key: Letter) -> Letter {
+            if self.layout != KeyboardLayout::Azerty {
+                return key;
+            }
+            AZERTY_SWAPS
+                .iter()
+                .find_map(|&(a, b)| {
+                    if key == a {
+                        Some(b)
+                    } else if key == b {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(key)
+        }
+    }
+
+    impl LayoutExt<Letter> for Keyboard {
+        fn physical_to_logical(&self, key: Letter) -> Letter {
+            self.swap(key)
+        }
+
+        fn logical_to_physical(&self, key: Letter) -> Letter {
+            self.swap(key)
+        }
+
+        fn set_layout(&mut self, layout: KeyboardLayout) {
+            self.layout = layout;
+        }
+    }
+
+    const ALL: [Letter; 5] = [Letter::Q, Letter::W, Letter::A, Letter::Z, Letter::S];
+
+    #[test]
+    fn qwerty_is_the_identity() {
+        let keyboard = Keyboard::default();
+
+        for key in ALL {
+            assert_eq!(keyboard.physical_to_logical(key), key);
+            assert_eq!(keyboard.logical_to_physical(key), key);
+        }
+    }
+
+    #[test]
+    fn azerty_moves_wasd_and_both_directions_are_inverse() {
+        let mut keyboard = Keyboard::default();
+        keyboard.set_layout(KeyboardLayout::Azerty);
+
+        assert_eq!(keyboard.physical_to_logical(Letter::W), Letter::Z);
+        assert_eq!(keyboard.logical_to_physical(Letter::Z), Letter::W);
+        assert_eq!(keyboard.physical_to_logical(Letter::S), Letter::S);
+        for key in ALL {
+            assert_eq!(keyboard.logical_to_physical(keyboard.physical_to_logical(key)), key);
+        }
+    }
+}
@@ -1,3 +1,17 @@
+pub mod action;
+pub mod axis;
+pub mod clock;
+pub mod context;
+pub mod gamepad;
+pub mod haptic;
 pub mod keys;
+pub mod layout;
+pub mod motion;
+pub mod mouse;
+pub mod observe;
+pub mod record;
 pub mod runtime;
-pub mod state;
\ No newline at end of file
+pub mod state;
+pub mod sticky;
+pub mod text;
+pub mod touch;
\ No newline at end of file
@@ -0,0 +1,164 @@
+/// Cuaternión unitario que representa una orientación en el espacio.
+///
+/// Componentes en el orden `(w, x, y, z)`, donde `w` es la parte escalar. Una
+/// rotación de ángulo `θ` alrededor del eje unitario `(ax, ay, az)`, en las
+/// coordenadas de [`MotionExt`], es `(cos(θ/2), ax·sin(θ/2), ay·sin(θ/2), az·sin(θ/2))`.
+///
+/// ## Normalización
+/// Quien produce el valor (normalmente [`MotionExt::orientation`]) lo entrega
+/// **normalizado**: `w² + x² + y² + z² = 1`, salvo el error de redondeo de `f32`.
+/// `q` y `-q` representan la misma orientación y no se fija el signo de `w`, así que
+/// para comparar orientaciones conviene usar el producto escalar y no `==`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    /// Parte escalar: `cos(θ/2)`.
+    pub w: f32,
+    /// Componente vectorial sobre `X`: `ax·sin(θ/2)`.
+    pub x: f32,
+    /// Componente vectorial sobre `Y`: `ay·sin(θ/2)`.
+    pub y: f32,
+    /// Componente vectorial sobre `Z`: `az·sin(θ/2)`.
+    pub z: f32,
+}
+
+impl Quaternion {
+    /// Orientación neutra (sin rotación).
+    pub const IDENTITY: Self = Self { w: 1.0, x: 0.0, y: 0.0, z: 0.0 };
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// # Trait `MotionExt`
+///
+/// Define el acceso a los **sensores de movimiento** de un mando (giroscopio y
+/// acelerómetro), como los de DualSense o Switch Pro, usados para apuntado por
+/// movimiento.
+///
+/// Es independiente de los traits de teclado y gamepad: un backend de mando puede
+/// implementar [`GamepadStateExt`](crate::GamepadStateExt) y `MotionExt` a la vez.
+///
+/// ## Convenciones de coordenadas
+/// Sistema **diestro** relativo al mando apoyado en una mesa, con los sticks hacia arriba:
+/// - `X`: hacia la derecha del mando.
+/// - `Y`: hacia arriba (perpendicular a la cara de los botones).
+/// - `Z`: hacia el jugador.
+///
+/// Las rotaciones siguen la regla de la mano derecha: *pitch* alrededor de `X`,
+/// *yaw* alrededor de `Y` y *roll* alrededor de `Z`.
+///
+/// ## Unidades
+/// - Giroscopio: velocidad angular en **radianes por segundo**.
+/// - Acelerómetro: aceleración en **g** (`1.0` ≈ 9.81 m/s²); en reposo reporta
+///   aproximadamente `(0.0, 1.0, 0.0)` por la gravedad.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// if pad.supports_motion() {
+///     let (pitch_rate, yaw_rate, _) = pad.gyro();
+///     camera.rotate(yaw_rate * dt * sensitivity, pitch_rate * dt * sensitivity);
+/// }
+/// ```
+pub trait MotionExt {
+    /// Retorna `true` si el dispositivo tiene sensores de movimiento.
+    ///
+    /// Si retorna `false`, el resto de métodos deben reportar valores neutros
+    /// (velocidades nulas, [`Quaternion::IDENTITY`]).
+    fn supports_motion(&self) -> bool;
+
+    /// Retorna la velocidad angular actual `(x, y, z)` en rad/s.
+    fn gyro(&self) -> (f32, f32, f32);
+
+    /// Retorna la aceleración lineal actual `(x, y, z)` en g.
+    fn accel(&self) -> (f32, f32, f32);
+
+    /// Retorna la orientación actual **relativa a la referencia** establecida por
+    /// [`recenter`](Self::recenter).
+    ///
+    /// Es un valor derivado: la implementación integra el giroscopio (y opcionalmente
+    /// corrige la deriva con el acelerómetro).
+    fn orientation(&self) -> Quaternion;
+
+    /// Toma la orientación actual como nueva referencia.
+    ///
+    /// Inmediatamente después, [`orientation`](Self::orientation) retorna
+    /// [`Quaternion::IDENTITY`]. Útil para "centrar la mira" con un botón.
+    fn recenter(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mando que integra muestras de giroscopio alrededor del eje `Y` (*yaw*).
+    #[derive(Default)]
+    struct MotionPad {
+        gyro: (f32, f32, f32),
+        yaw: f32,
+        reference: f32,
+    }
+
+    impl MotionPad {
+        fn feed(&mut self, angular_velocity: (f32, f32, f32), dt: f32) {
+            self.gyro = angular_velocity;
+            self.yaw += angular_velocity.1 * dt;
+        }
+    }
+
+    impl MotionExt for MotionPad {
+        fn supports_motion(&self) -> bool {
+            true
+        }
+
+        fn gyro(&self) -> (f32, f32, f32) {
+            self.gyro
+        }
+
+        fn accel(&self) -> (f32, f32, f32) {
+            (0.0, 1.0, 0.0)
+        }
+
+        fn orientation(&self) -> Quaternion {
+            let half = (self.yaw - self.reference) / 2.0;
+            if half == 0.0 {
+                return Quaternion::IDENTITY;
+            }
+            Quaternion { w: half.cos(), x: 0.0, y: half.sin(), z: 0.0 }
+        }
+
+        fn recenter(&mut self) {
+            self.reference = self.yaw;
+        }
+    }
+
+    #[test]
+    fn gyro_reports_the_latest_sample() {
+        let mut pad = MotionPad::default();
+        pad.feed((0.0, 1.0, 0.0), 0.5);
+        pad.feed((0.25, 2.0, -0.5), 0.5);
+
+        assert_eq!(pad.gyro(), (0.25, 2.0, -0.5));
+        assert_eq!(pad.accel(), (0.0, 1.0, 0.0));
+        assert_ne!(pad.orientation(), Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn recenter_zeroes_the_reference() {
+        let mut pad = MotionPad::default();
+        pad.feed((0.0, 1.0, 0.0), 0.5);
+
+        pad.recenter();
+        assert_eq!(pad.orientation(), Quaternion::IDENTITY);
+
+        pad.feed((0.0, 2.0, 0.0), 0.5);
+        assert_eq!(pad.orientation().y, 0.5f32.sin());
+    }
+
+    #[test]
+    fn default_orientation_is_identity() {
+        assert_eq!(Quaternion::default(), Quaternion::IDENTITY);
+    }
+}
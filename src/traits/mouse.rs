@@ -0,0 +1,204 @@
+use core::hash::Hash;
+
+use crate::StateSemantics;
+
+/// # Trait `MouseStateExt`
+///
+/// Define el **estado del ratón** en el frame actual: posición del cursor,
+/// movimiento relativo, rueda y botones.
+///
+/// Sigue el mismo diseño que [`InputStateExt`](crate::InputStateExt): los botones
+/// tienen estado mantenido y flancos de frame, y el runtime alimenta el estado con
+/// los métodos `set_*`. Un backend puede implementar ambos traits sobre el mismo
+/// tipo, y el motor aceptar `impl MouseStateExt` junto al contrato de teclado.
+///
+/// ## Unidades
+/// - Posición: píxeles de la ventana, con el origen en la esquina superior
+///   izquierda y `y` creciendo hacia abajo.
+/// - Delta: píxeles acumulados desde el último
+///   [`advance_pointer_frame`](Self::advance_pointer_frame). Refleja el movimiento
+///   físico, así que sigue cambiando con el cursor capturado.
+/// - Rueda: "líneas" `(horizontal, vertical)`, positivo hacia la derecha y hacia
+///   arriba; los touchpads pueden reportar fracciones.
+///
+/// ## Parámetros genéricos
+/// - `B`: Identificador de botón (por ejemplo, un enum `MouseButton`).
+/// - `S`: Estado de botón, con la misma semántica que en `InputStateExt`.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let (dx, dy) = mouse.delta();
+/// camera.rotate(dx * sensitivity, dy * sensitivity);
+///
+/// if mouse.is_button_just_pressed(MouseButton::Left) {
+///     player.fire();
+/// }
+/// ```
+pub trait MouseStateExt<B, S>
+where
+    B: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    // === CONSULTAS ===
+
+    /// Retorna la posición actual del cursor `(x, y)`.
+    fn position(&self) -> (f64, f64);
+
+    /// Retorna el movimiento `(dx, dy)` acumulado en el frame actual.
+    fn delta(&self) -> (f64, f64);
+
+    /// Retorna el desplazamiento de la rueda `(horizontal, vertical)` acumulado en el
+    /// frame actual.
+    fn wheel_delta(&self) -> (f64, f64);
+
+    /// Retorna `true` si el botón está presionado.
+    fn is_button_pressed(&self, button: B) -> bool;
+
+    /// Retorna `true` si el botón fue presionado en el frame actual.
+    fn is_button_just_pressed(&self, button: B) -> bool;
+
+    /// Retorna `true` si el botón fue liberado en el frame actual.
+    fn is_button_just_released(&self, button: B) -> bool;
+
+    // === ACTUALIZACIÓN (runtime) ===
+
+    /// Actualiza el estado de un botón. Igual que
+    /// [`set_key`](crate::InputStateExt::set_key), genera los flancos visibles hasta el
+    /// próximo [`advance_pointer_frame`](Self::advance_pointer_frame).
+    fn set_button(&mut self, button: B, state: S);
+
+    /// Mueve el cursor a `(x, y)` y suma la diferencia con la posición anterior al
+    /// delta del frame.
+    fn set_position(&mut self, x: f64, y: f64);
+
+    /// Suma movimiento **relativo** al delta del frame sin mover el cursor, para
+    /// backends que reportan movimiento crudo (por ejemplo, con el cursor capturado).
+    fn add_motion(&mut self, dx: f64, dy: f64);
+
+    /// Suma desplazamiento de rueda al frame actual.
+    fn add_wheel(&mut self, horizontal: f64, vertical: f64);
+
+    /// Cierra el frame actual: consume los flancos de los botones y pone a cero
+    /// [`delta`](Self::delta) y [`wheel_delta`](Self::wheel_delta).
+    ///
+    /// No modifica la posición ni qué botones están presionados. Se llama distinto
+    /// de [`InputStateExt::advance_frame`](crate::InputStateExt::advance_frame) para
+    /// que un tipo que implemente ambos traits pueda cerrar cada frame sin ambigüedad.
+    fn advance_pointer_frame(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::testing::fixtures::Btn;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Button {
+        Left,
+        Right,
+    }
+
+    /// Ratón de referencia: flancos por frame como en el mock de teclado.
+    #[derive(Default)]
+    struct Mouse {
+        position: (f64, f64),
+        delta: (f64, f64),
+        wheel: (f64, f64),
+        buttons: HashMap<Button, Btn>,
+        pressed_now: Vec<Button>,
+        released_now: Vec<Button>,
+    }
+
+    impl MouseStateExt<Button, Btn> for Mouse {
+        fn position(&self) -> (f64, f64) {
+            self.position
+        }
+
+        fn delta(&self) -> (f64, f64) {
+            self.delta
+        }
+
+        fn wheel_delta(&self) -> (f64, f64) {
+            self.wheel
+        }
+
+        fn is_button_pressed(&self, button: Button) -> bool {
+            self.buttons.get(&button).is_some_and(Btn::is_down)
+        }
+
+        fn is_button_just_pressed(&self, button: Button) -> bool {
+            self.pressed_now.contains(&button)
+        }
+
+        fn is_button_just_released(&self, button: Button) -> bool {
+            self.released_now.contains(&button)
+        }
+
+        fn set_button(&mut self, button: Button, state: Btn) {
+            let was_down = self.is_button_pressed(button);
+            if state.is_down() && !was_down {
+                self.pressed_now.push(button);
+            } else if !state.is_down() && was_down {
+                self.released_now.push(button);
+            }
+            self.buttons.insert(button, state);
+        }
+
+        fn set_position(&mut self, x: f64, y: f64) {
+            self.add_motion(x - self.position.0, y - self.position.1);
+            self.position = (x, y);
+        }
+
+        fn add_motion(&mut self, dx: f64, dy: f64) {
+            self.delta = (self.delta.0 + dx, self.delta.1 + dy);
+        }
+
+        fn add_wheel(&mut self, horizontal: f64, vertical: f64) {
+            self.wheel = (self.wheel.0 + horizontal, self.wheel.1 + vertical);
+        }
+
+        fn advance_pointer_frame(&mut self) {
+            self.delta = (0.0, 0.0);
+            self.wheel = (0.0, 0.0);
+            self.pressed_now.clear();
+            self.released_now.clear();
+        }
+    }
+
+    #[test]
+    fn deltas_accumulate_within_a_frame_and_reset_after() {
+        let mut mouse = Mouse::default();
+        mouse.set_position(100.0, 50.0);
+        mouse.set_position(110.0, 40.0);
+        mouse.add_motion(2.5, 0.0);
+        mouse.add_wheel(0.0, 1.0);
+        mouse.add_wheel(0.5, 1.0);
+
+        assert_eq!(mouse.position(), (110.0, 40.0));
+        assert_eq!(mouse.delta(), (112.5, 40.0));
+        assert_eq!(mouse.wheel_delta(), (0.5, 2.0));
+
+        mouse.advance_pointer_frame();
+        assert_eq!(mouse.delta(), (0.0, 0.0));
+        assert_eq!(mouse.wheel_delta(), (0.0, 0.0));
+        assert_eq!(mouse.position(), (110.0, 40.0));
+    }
+
+    #[test]
+    fn button_edges_last_one_frame() {
+        let mut mouse = Mouse::default();
+        mouse.set_button(Button::Left, Btn::Down);
+        mouse.set_button(Button::Left, Btn::Held);
+        assert!(mouse.is_button_just_pressed(Button::Left));
+
+        mouse.advance_pointer_frame();
+        assert!(mouse.is_button_pressed(Button::Left));
+        assert!(!mouse.is_button_just_pressed(Button::Left));
+
+        mouse.set_button(Button::Left, Btn::Up);
+        assert!(mouse.is_button_just_released(Button::Left));
+        assert!(!mouse.is_button_just_released(Button::Right));
+    }
+}
@@ -0,0 +1,156 @@
+use core::hash::Hash;
+
+use crate::{InputStateExt, StateSemantics};
+
+/// # Trait `ObservableInputExt`
+///
+/// Permite **suscribirse** a los cambios de estado de las teclas en lugar de
+/// consultarlos cada frame con [`is_just_press`](InputStateExt::is_just_press).
+///
+/// Pensado para código dirigido por eventos: overlays de accesibilidad que muestran
+/// la tecla pulsada, herramientas de depuración o UI que no tiene un game loop
+/// propio.
+///
+/// ## Cuándo se invocan los callbacks
+/// - Desde [`set_key`](InputStateExt::set_key), **después** de actualizar el estado:
+///   dentro del callback, las consultas ya reflejan el nuevo estado.
+/// - Solo en **transiciones**: un `set_key` que repite el estado actual de la tecla
+///   no invoca nada.
+/// - Primero los callbacks de [`on_key`](Self::on_key) de esa tecla y después los de
+///   [`on_any`](Self::on_any), cada grupo en orden de registro.
+///
+/// ## Reentrada
+/// Un callback **no puede** llamar a `set_key` sobre la entrada que lo invocó: no
+/// recibe acceso a ella y, al ser `'static`, tampoco puede tomarla prestada. Si la
+/// comparte mediante `Rc<RefCell<_>>`, el `RefCell` ya está prestado de forma mutable
+/// durante `set_key` y el intento entra en pánico. Para reaccionar produciendo más
+/// input, el callback debe encolarlo y aplicarlo después de que `set_key` retorne.
+///
+/// ## Parámetros genéricos
+/// - `K`, `S`: Tecla y estado del [`InputStateExt`] subyacente.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let overlay = Rc::new(RefCell::new(KeyOverlay::default()));
+///
+/// let target = Rc::clone(&overlay);
+/// input.on_any(move |key, state| target.borrow_mut().show(key, state));
+///
+/// input.on_key(KeyCode::Escape, |state| {
+///     if state.is_press() {
+///         println!("Escape presionada");
+///     }
+/// });
+/// ```
+pub trait ObservableInputExt<K, S>: InputStateExt<K, S>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    /// Registra `callback`, que recibe el nuevo estado cada vez que `key` cambia de
+    /// estado.
+    fn on_key(&mut self, key: K, callback: impl FnMut(S) + 'static);
+
+    /// Registra `callback`, que recibe la tecla y su nuevo estado cada vez que
+    /// **cualquier** tecla cambia de estado.
+    fn on_any(&mut self, callback: impl FnMut(K, S) + 'static);
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use super::*;
+    use crate::testing::fixtures::{Btn, Input, Key, delegate_input_state};
+
+    type KeyCallback = Box<dyn FnMut(Btn)>;
+
+    type AnyCallback = Box<dyn FnMut(Key, Btn)>;
+
+    /// Estado de referencia que avisa a sus suscriptores en cada transición.
+    #[derive(Default)]
+    struct Observed {
+        input: Input,
+        per_key: Vec<(Key, KeyCallback)>,
+        any: Vec<AnyCallback>,
+    }
+
+    impl Observed {
+        fn notify(&mut self, key: Key, state: Btn) {
+            let before = self.input.state_of(key);
+            self.input.set_key(key, state);
+            if before == Some(state) || (before.is_none() && state == Btn::released()) {
+                return;
+            }
+            for (_, callback) in self.per_key.iter_mut().filter(|(watched, _)| *watched == key) {
+                callback(state);
+            }
+            for callback in &mut self.any {
+                callback(key, state);
+            }
+        }
+    }
+
+    delegate_input_state!(
+        Observed,
+        input,
+        set_key: Observed::notify,
+        advance_frame: |this: &mut Observed| this.input.advance_frame(),
+    );
+
+    impl ObservableInputExt<Key, Btn> for Observed {
+        fn on_key(&mut self, key: Key, callback: impl FnMut(Btn) + 'static) {
+            self.per_key.push((key, Box::new(callback)));
+        }
+
+        fn on_any(&mut self, callback: impl FnMut(Key, Btn) + 'static) {
+            self.any.push(Box::new(callback));
+        }
+    }
+
+    #[test]
+    fn callbacks_fire_on_transitions_in_registration_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut input = Observed::default();
+
+        let any = Rc::clone(&log);
+        input.on_any(move |key, state| any.borrow_mut().push(("any", key, state)));
+        let first = Rc::clone(&log);
+        input.on_key(Key::A, move |state| first.borrow_mut().push(("a1", Key::A, state)));
+        let second = Rc::clone(&log);
+        input.on_key(Key::A, move |state| second.borrow_mut().push(("a2", Key::A, state)));
+
+        input.set_key(Key::A, Btn::Down);
+        input.set_key(Key::B, Btn::Down);
+
+        // Los callbacks de la tecla van antes que los generales, aunque se registraran después.
+        assert_eq!(
+            *log.borrow(),
+            [
+                ("a1", Key::A, Btn::Down),
+                ("a2", Key::A, Btn::Down),
+                ("any", Key::A, Btn::Down),
+                ("any", Key::B, Btn::Down),
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_states_do_not_notify() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut input = Observed::default();
+        let sink = Rc::clone(&calls);
+        input.on_key(Key::C, move |state| sink.borrow_mut().push(state));
+
+        input.set_key(Key::C, Btn::Up);
+        input.set_key(Key::C, Btn::Down);
+        input.set_key(Key::C, Btn::Down);
+        input.set_key(Key::C, Btn::Held);
+        input.set_key(Key::C, Btn::Up);
+
+        assert_eq!(*calls.borrow(), [Btn::Down, Btn::Held, Btn::Up]);
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::time::Duration;
+
+use crate::{DeviceId, HistoryRecord};
+#[cfg(feature = "std")]
+use crate::traits::state::replay_timed;
+#[cfg(feature = "std")]
+use crate::{InputStateExt, StateSemantics};
+
+/// Evento capturado por un [`RecorderExt`], dentro de una [`Recording`].
+///
+/// A diferencia de [`HistoryRecord`], conserva el dispositivo de origen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent<K, S> {
+    /// Tecla del evento.
+    pub key: K,
+    /// Estado de la tecla en el evento.
+    pub state: S,
+    /// Microsegundos transcurridos desde el inicio de la grabación.
+    pub offset_micros: u64,
+    /// Dispositivo que produjo el evento, o `None` si el backend no lo distingue.
+    pub device: Option<DeviceId>,
+}
+
+/// Grabación **sin pérdidas** de la entrada, producida por
+/// [`RecorderExt::stop_recording`].
+///
+/// Es el formato de los reportes de bugs: con la feature `serde` implementa
+/// `Serialize`/`Deserialize` (si `K` y `S` lo implementan) para adjuntarla a un
+/// reporte, y se reproduce con [`replay_into`](Self::replay_into) o, a través del
+/// historial, con [`to_history_records`](Self::to_history_records).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recording<K, S> {
+    /// Hora de pared del inicio de la grabación, en microsegundos desde la época
+    /// Unix, o `None` si el backend no tiene reloj de pared (por ejemplo, `no_std`).
+    pub started_at_unix_micros: Option<u64>,
+    /// Eventos capturados, del más antiguo al más reciente.
+    pub events: Vec<RecordedEvent<K, S>>,
+}
+
+impl<K, S> Recording<K, S> {
+    /// Crea una grabación vacía que comenzó en `started_at_unix_micros`.
+    pub fn new(started_at_unix_micros: Option<u64>) -> Self {
+        Self { started_at_unix_micros, events: Vec::new() }
+    }
+
+    /// Número de eventos capturados.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Retorna `true` si no se capturó ningún evento.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Tiempo desde el inicio de la grabación hasta el último evento capturado.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map_or(Duration::ZERO, |event| Duration::from_micros(event.offset_micros))
+    }
+}
+
+impl<K: Copy, S: Copy> Recording<K, S> {
+    /// Convierte la grabación al formato de
+    /// [`HistoryAccessExt::import_history`](crate::HistoryAccessExt::import_history),
+    /// descartando los dispositivos.
+    pub fn to_history_records(&self) -> Vec<HistoryRecord<K, S>> {
+        self.events
+            .iter()
+            .map(|event| HistoryRecord { key: event.key, state: event.state, offset_micros: event.offset_micros })
+            .collect()
+    }
+
+    /// Reproduce la grabación sobre `target`, aplicando cada evento con
+    /// [`set_key`](InputStateExt::set_key) en orden y respetando los intervalos
+    /// grabados.
+    ///
+    /// `speed` tiene el mismo significado que en
+    /// [`HistoryAccessExt::replay_into`](crate::HistoryAccessExt::replay_into): `1.0`
+    /// es tiempo real, y un valor no positivo o no finito (o un intervalo que escalado
+    /// no cabe en un [`Duration`]) aplica los eventos de inmediato. **Bloquea el hilo
+    /// actual**, por lo que solo está disponible con el feature `std`.
+    #[cfg(feature = "std")]
+    pub fn replay_into<I>(&self, target: &mut I, speed: f32)
+    where
+        K: PartialEq + Hash,
+        S: StateSemantics,
+        I: InputStateExt<K, S>,
+    {
+        let mut previous = 0;
+        let events = self.events.iter().map(|event| {
+            let gap = Duration::from_micros(event.offset_micros.saturating_sub(previous));
+            previous = event.offset_micros;
+            (gap, event.key, event.state)
+        });
+        replay_timed(events, target, speed);
+    }
+}
+
+/// # Trait `RecorderExt`
+///
+/// **Grabador** de la entrada para reportes de bugs: un botón estándar de "grabar
+/// reproducción" que funciona con cualquier backend.
+///
+/// El historial de [`WithHistoryExt`](crate::WithHistoryExt) está orientado al
+/// análisis (combos, estadísticas) y se recorta según su política de capacidad. La
+/// grabación, en cambio, es **sin pérdidas**: captura cada evento que llega al
+/// estado entre [`start_recording`](Self::start_recording) y
+/// [`stop_recording`](Self::stop_recording), sin importar
+/// [`trim_history`](crate::HistoryAccessExt::trim_history) ni la capacidad del
+/// historial, junto con la hora de pared y el dispositivo de origen.
+///
+/// ## Parámetros genéricos
+/// - `K`: Tipo de tecla.
+/// - `S`: Tipo de estado de tecla.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// if ui.button("Grabar reproducción") {
+///     input.start_recording();
+/// }
+///
+/// if ui.button("Enviar reporte") && input.is_recording() {
+///     let recording = input.stop_recording();
+///     bug_report.attach("input.json", serde_json::to_vec(&recording)?);
+/// }
+///
+/// // En la máquina de QA:
+/// recording.replay_into(&mut input, 1.0);
+/// ```
+pub trait RecorderExt<K, S> {
+    /// Comienza a grabar. Si ya se está grabando, no hace nada.
+    fn start_recording(&mut self);
+
+    /// Termina la grabación y la retorna.
+    ///
+    /// Si no se estaba grabando, retorna una grabación vacía.
+    fn stop_recording(&mut self) -> Recording<K, S>;
+
+    /// Retorna `true` entre [`start_recording`](Self::start_recording) y
+    /// [`stop_recording`](Self::stop_recording).
+    fn is_recording(&self) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{Btn, Input, Key, delegate_input_state, ms};
+    use crate::{Clock, FrameTimeExt, HistoryAccessExt};
+
+    /// Estado de referencia que graba lo que llega a `set_key`, con el teclado como
+    /// dispositivo `1`.
+    #[derive(Default)]
+    struct Recorder {
+        input: Input,
+        recording: Option<(Duration, Recording<Key, Btn>)>,
+    }
+
+    impl Recorder {
+        fn capture(&mut self, key: Key, state: Btn) {
+            self.input.set_key(key, state);
+            let now = self.input.clock().now();
+            if let Some((start, recording)) = &mut self.recording {
+                let offset_micros = (now - *start).as_micros() as u64;
+                recording.events.push(RecordedEvent { key, state, offset_micros, device: Some(DeviceId(1)) });
+            }
+        }
+    }
+
+    delegate_input_state!(
+        Recorder,
+        input,
+        set_key: Recorder::capture,
+        advance_frame: |this: &mut Recorder| this.input.advance_frame(),
+    );
+
+    impl RecorderExt<Key, Btn> for Recorder {
+        fn start_recording(&mut self) {
+            if self.recording.is_none() {
+                self.recording = Some((self.input.clock().now(), Recording::new(None)));
+            }
+        }
+
+        fn stop_recording(&mut self) -> Recording<Key, Btn> {
+            self.recording.take().map_or_else(|| Recording::new(None), |(_, recording)| recording)
+        }
+
+        fn is_recording(&self) -> bool {
+            self.recording.is_some()
+        }
+    }
+
+    fn at(recorder: &mut Recorder, millis: u64, key: Key, state: Btn) {
+        recorder.input.update_time(ms(millis));
+        recorder.set_key(key, state);
+    }
+
+    #[test]
+    fn recording_is_lossless_and_relative_to_its_start() {
+        let mut recorder = Recorder::default();
+        at(&mut recorder, 100, Key::A, Btn::Down);
+
+        recorder.input.update_time(ms(1000));
+        recorder.start_recording();
+        assert!(recorder.is_recording());
+        at(&mut recorder, 1010, Key::B, Btn::Down);
+        at(&mut recorder, 1050, Key::B, Btn::Up);
+        // El historial se recorta, la grabación no.
+        recorder.input.trim_history(1);
+        recorder.start_recording();
+        at(&mut recorder, 1250, Key::A, Btn::Up);
+
+        let recording = recorder.stop_recording();
+        assert!(!recorder.is_recording());
+        assert_eq!(recording.len(), 3);
+        assert_eq!(recording.duration(), ms(250));
+        assert_eq!(recording.events[0].device, Some(DeviceId(1)));
+        assert_eq!(
+            recording.to_history_records(),
+            [
+                HistoryRecord { key: Key::B, state: Btn::Down, offset_micros: 10_000 },
+                HistoryRecord { key: Key::B, state: Btn::Up, offset_micros: 50_000 },
+                HistoryRecord { key: Key::A, state: Btn::Up, offset_micros: 250_000 },
+            ]
+        );
+    }
+
+    #[test]
+    fn stopping_without_recording_returns_an_empty_recording() {
+        let mut recorder = Recorder::default();
+        at(&mut recorder, 0, Key::A, Btn::Down);
+
+        let recording = recorder.stop_recording();
+
+        assert!(recording.is_empty());
+        assert_eq!(recording.duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn replay_reproduces_the_recorded_state() {
+        let mut recorder = Recorder::default();
+        recorder.start_recording();
+        at(&mut recorder, 0, Key::A, Btn::Down);
+        at(&mut recorder, 5, Key::B, Btn::Down);
+        at(&mut recorder, 10, Key::A, Btn::Up);
+        let recording = recorder.stop_recording();
+
+        let mut target = Input::new();
+        recording.replay_into(&mut target, 0.0);
+
+        assert!(!target.is_pressed(Key::A) && target.is_pressed(Key::B));
+        assert_eq!(target.history().len(), 3);
+    }
+}
@@ -1,3 +1,157 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// Métricas de salud del runtime, retornadas por [`RuntimeExt::diagnostics`].
+///
+/// Pensado para overlays de depuración y healthchecks que consultan todo en una
+/// sola llamada.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuntimeDiagnostics {
+    /// Backend que reporta las métricas (ver [`RuntimeExt::backend_name`]).
+    ///
+    /// Como en [`RuntimeSnapshot::backend`], es un [`Cow`] para que un volcado de
+    /// diagnóstico se pueda volver a leer.
+    pub backend: Cow<'static, str>,
+    /// Eventos aplicados al estado compartido (ver [`RuntimeExt::events_processed`]).
+    pub events_processed: usize,
+    /// Eventos leídos del backend que **no** se aplicaron: colas llenas, runtime
+    /// pausado que descarta, etc. Si crece, el runtime no da abasto con el ritmo de
+    /// eventos del sistema operativo.
+    pub events_dropped: usize,
+    /// Momento del último evento procesado, medido desde el arranque del runtime
+    /// (cada backend documenta cuál es); `None` si aún no hubo eventos.
+    pub last_event: Option<Duration>,
+    /// Dispositivos abiertos actualmente.
+    pub device_count: usize,
+    /// Vueltas completadas por el loop de [`run`](RuntimeExt::run). Si deja de
+    /// aumentar, el loop está bloqueado.
+    pub loop_iterations: u64,
+}
+
+impl RuntimeDiagnostics {
+    /// Crea métricas a cero para `backend`.
+    pub fn new(backend: impl Into<Cow<'static, str>>) -> Self {
+        Self { backend: backend.into(), ..Self::default() }
+    }
+}
+
+/// Qué hace el runtime cuando su cola de eventos pendientes se llena, configurado
+/// con [`RuntimeExt::set_overflow_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Descarta los eventos más antiguos de la cola. Prioriza la latencia: lo que
+    /// llega al estado es siempre lo más reciente.
+    #[default]
+    DropOldest,
+    /// Descarta los eventos nuevos mientras la cola esté llena.
+    DropNewest,
+    /// Deja de leer del backend hasta que haya sitio; no se pierde nada en el
+    /// runtime (el sistema operativo puede descartar por su cuenta). Pensado para
+    /// herramientas y grabación.
+    Block,
+    /// Colapsa las repeticiones consecutivas de la misma tecla con el mismo estado
+    /// en un solo evento. Nunca fusiona una pulsación con su liberación, así que los
+    /// flancos se conservan.
+    Coalesce,
+}
+
+/// Decisión de [`RuntimeExt::on_error`] ante un error del loop de
+/// [`run`](RuntimeExt::run).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorAction {
+    /// Ignorar el error y seguir leyendo (por ejemplo, `EAGAIN`).
+    Retry,
+    /// Cerrar y reabrir los dispositivos, como [`restart`](RuntimeExt::restart), y
+    /// seguir en el loop.
+    Restart,
+    /// Terminar el loop y propagar el error desde `run()`.
+    Abort,
+}
+
+/// Identificador de un dispositivo de entrada, asignado por el backend.
+///
+/// Es estable mientras el dispositivo siga conectado; un dispositivo que se
+/// reconecta puede recibir otro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceId(pub u32);
+
+/// Tipo de dispositivo de entrada reportado en [`DeviceInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceKind {
+    /// Teclado.
+    Keyboard,
+    /// Ratón o touchpad.
+    Mouse,
+    /// Gamepad, arcade stick o similar.
+    Gamepad,
+    /// Cualquier otro dispositivo, o uno que el backend no sabe clasificar.
+    Other,
+}
+
+/// Descripción de un dispositivo, retornada por [`RuntimeExt::list_devices`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceInfo {
+    /// Identificador para [`RuntimeExt::enable_device`].
+    pub id: DeviceId,
+    /// Nombre legible del dispositivo (por ejemplo, `"Xbox Wireless Controller"`).
+    pub name: String,
+    /// Tipo de dispositivo.
+    pub kind: DeviceKind,
+}
+
+/// Cambio en la topología de dispositivos, notificado con
+/// [`RuntimeExt::on_device_change`].
+///
+/// Es el tipo de evento recomendado para [`RuntimeExt::DeviceEvent`]; un backend
+/// puede usar uno propio si necesita reportar más información.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceChange {
+    /// Se conectó un dispositivo.
+    Connected {
+        /// Nombre legible del dispositivo (por ejemplo, `"Xbox Wireless Controller"`).
+        name: String,
+        /// Identificador asignado por el backend.
+        id: DeviceId,
+    },
+    /// Se desconectó el dispositivo con este identificador.
+    Disconnected {
+        /// Identificador con el que se notificó su conexión.
+        id: DeviceId,
+    },
+}
+
+/// Instantánea del runtime producida por [`RuntimeExt::suspend`] y consumida por
+/// [`RuntimeExt::resume`].
+///
+/// Contiene únicamente lo que debe sobrevivir a una suspensión del sistema operativo:
+/// la configuración del backend (incluidos los bindings) y los contadores de
+/// monitoreo. El estado transitorio de input (teclas mantenidas, flancos) **no** se
+/// guarda.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuntimeSnapshot {
+    /// Backend que generó la instantánea (ver [`RuntimeExt::backend_name`]).
+    ///
+    /// Es un [`Cow`] para que la instantánea se pueda deserializar desde un archivo o
+    /// un buffer: al cargarla, el nombre queda en un `String` propio.
+    pub backend: Cow<'static, str>,
+    /// Valor de [`RuntimeExt::events_processed`] al suspender.
+    pub events_processed: usize,
+    /// Configuración serializada en el formato que elija el backend.
+    pub config: Vec<u8>,
+}
+
+impl RuntimeSnapshot {
+    /// Crea una instantánea sin configuración adicional.
+    pub fn new(backend: impl Into<Cow<'static, str>>, events_processed: usize) -> Self {
+        Self { backend: backend.into(), events_processed, config: Vec::new() }
+    }
+}
+
 /// # Trait `RuntimeExt`
 ///
 /// Define el **ciclo de vida y gestión** de un runtime de entrada completo.
@@ -60,22 +214,30 @@
 /// - Uso de runtimes async (Tokio, async-std, smol)
 /// - Integración con otros sistemas asíncronos del motor
 ///
+/// Los backends sin executor (embebidos, WASM sin runtime async) implementan en su
+/// lugar [`PollRuntimeExt`], llamado directamente desde el game loop. Un runtime
+/// asíncrono puede construirse como una capa de conveniencia sobre ese núcleo:
+/// `run` no es más que llamar a `poll` en bucle mientras espera nuevos eventos.
+///
 /// ## Ejemplo de implementación (conceptual)
 ///
 /// ```rust,ignore
 /// use orbit_input_core::{RuntimeExt, InputStateExt, KeyExt, KeyStateExt};
 /// use std::sync::{Arc, Mutex};
+/// use tokio::sync::watch;
 ///
 /// pub struct MyRuntime {
 ///     running: bool,
 ///     events_count: usize,
 ///     state: Arc<Mutex<MyInputState>>,
+///     devices_ready: watch::Sender<bool>,
 /// }
 ///
 /// impl RuntimeExt for MyRuntime {
 ///     type Error = std::io::Error;
 ///     type State = MyInputState;
 ///     type SharedState = Arc<Mutex<MyInputState>>;
+///     type DeviceEvent = DeviceChange;
 ///     
 ///     fn new() -> Result<(Self, Self::SharedState), Self::Error> {
 ///         let state = Arc::new(Mutex::new(MyInputState::new()));
@@ -83,6 +245,7 @@
 ///             running: false,
 ///             events_count: 0,
 ///             state: state.clone(),
+///             devices_ready: watch::channel(false).0,
 ///         };
 ///         Ok((runtime, state))
 ///     }
@@ -90,9 +253,16 @@
 ///     async fn initialize(&mut self) -> Result<(), Self::Error> {
 ///         // Abrir dispositivos, configurar recursos...
 ///         self.running = true;
+///         // Despertar a quien espera en `ready` (también si la apertura falló)
+///         self.devices_ready.send_replace(true);
 ///         Ok(())
 ///     }
 ///     
+///     async fn ready(&self) {
+///         // Esperar a que `initialize` abra al menos un dispositivo
+///         let _ = self.devices_ready.subscribe().wait_for(|ready| *ready).await;
+///     }
+///     
 ///     async fn run(&mut self) -> Result<(), Self::Error> {
 ///         while self.running {
 ///             // Leer evento del OS
@@ -174,6 +344,11 @@ pub trait RuntimeExt {
     /// - Canales de mensajes, etc.
     type SharedState: Clone + Send + Sync + 'static;
 
+    /// Tipo de evento entregado a [`on_device_change`](Self::on_device_change).
+    ///
+    /// Normalmente [`DeviceChange`].
+    type DeviceEvent;
+
     // ==================== INICIALIZACIÓN Y CONTROL ====================
 
     /// Crea una nueva instancia del runtime y su estado compartido.
@@ -201,6 +376,64 @@ pub trait RuntimeExt {
     where
         Self: Sized;
 
+    /// Crea el runtime **pre-reservando** sus buffers internos.
+    ///
+    /// Alternativa a [`new`](Self::new) para juegos que conocen su carga de input:
+    /// - `event_buffer`: capacidad de la cola de eventos pendientes del pipeline.
+    /// - `history`: capacidad del buffer de historial del estado (si lo tiene).
+    ///
+    /// Funcionalmente es equivalente a `new()`, que usa capacidades por defecto.
+    /// Las capacidades son **sugerencias**: el runtime puede redondearlas, ignorarlas
+    /// o crecer por encima de ellas si la carga lo requiere. La implementación por
+    /// defecto simplemente delega en `new()`.
+    ///
+    /// # Errores
+    ///
+    /// Los mismos que [`new`](Self::new).
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// // Evita realocaciones en ráfagas de eventos de alta frecuencia
+    /// let (runtime, state) = MyRuntime::with_capacity(1024, 4096)?;
+    /// ```
+    fn with_capacity(event_buffer: usize, history: usize) -> Result<(Self, Self::SharedState), Self::Error>
+    where
+        Self: Sized,
+    {
+        let _ = (event_buffer, history);
+        Self::new()
+    }
+
+    /// Define qué hacer cuando la cola de eventos pendientes se llena porque el game
+    /// loop no los consume a tiempo.
+    ///
+    /// ## Relación con el estado compartido
+    /// La política actúa **antes** de la actualización del estado: decide qué eventos
+    /// leídos del backend llegan a [`set_key`](crate::InputStateExt::set_key). Los
+    /// eventos descartados (o fusionados por [`OverflowPolicy::Coalesce`]) nunca se
+    /// aplican ni entran en el historial, y se cuentan en
+    /// [`RuntimeDiagnostics::events_dropped`]. La capacidad de la cola es la que se
+    /// pidió en [`with_capacity`](Self::with_capacity) (o la de por defecto).
+    ///
+    /// Retorna `true` si el runtime aplicará la política. La implementación por
+    /// defecto no la aplica y retorna `false`, lo que corresponde a backends que
+    /// aplican cada evento en cuanto lo leen, sin cola intermedia: el llamador sabe
+    /// así que la configuración no tiene efecto.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// // Un juego competitivo prefiere perder eventos viejos a acumular latencia
+    /// if !runtime.set_overflow_policy(OverflowPolicy::DropOldest) {
+    ///     log::info!("{}: sin cola de eventos, la política no aplica", runtime.backend_name());
+    /// }
+    /// ```
+    fn set_overflow_policy(&mut self, policy: OverflowPolicy) -> bool {
+        let _ = policy;
+        false
+    }
+
     /// Inicializa los recursos necesarios antes de comenzar la captura de eventos.
     ///
     /// Este método debe ser llamado **antes** de `run()` y puede:
@@ -218,7 +451,32 @@ pub trait RuntimeExt {
     /// ```rust,ignore
     /// runtime.initialize().await?;
     /// ```
-    fn initialize(&mut self) -> impl std::future::Future<Output = Result<(), Self::Error>> + Send;
+    fn initialize(&mut self) -> impl core::future::Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Retorna un future que se resuelve cuando el runtime está **listo** para ser consultado.
+    ///
+    /// Evita la carrera típica del arranque: el game loop puede empezar a consultar el
+    /// estado antes de que [`initialize`](Self::initialize) termine de abrir los
+    /// dispositivos. El future se resuelve cuando `initialize` completó **y** al menos
+    /// un dispositivo está capturando eventos. Si el runtime ya está listo, se resuelve
+    /// inmediatamente.
+    ///
+    /// ## Si la inicialización falla
+    /// El future **también se resuelve**, para que ninguna tarea quede esperando para
+    /// siempre. El error se reporta únicamente a través del `Result` de
+    /// [`initialize`](Self::initialize); tras despertar, quien necesite distinguir ambos
+    /// casos debe consultar [`is_running`](Self::is_running), que será `false`.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// runtime.ready().await;
+    /// if !runtime.is_running() {
+    ///     return Err(GameError::NoInput);
+    /// }
+    /// game_loop(&shared_state);
+    /// ```
+    fn ready(&self) -> impl core::future::Future<Output = ()> + Send;
 
     /// Inicia el **loop principal** de captura y procesamiento de eventos.
     ///
@@ -237,6 +495,9 @@ pub trait RuntimeExt {
     /// - Ocurre un error crítico de I/O
     /// - El sistema deniega acceso
     ///
+    /// Antes de propagar un error, el loop consulta [`on_error`](Self::on_error) y
+    /// solo retorna `Err` si la respuesta es [`ErrorAction::Abort`].
+    ///
     /// # Ejemplo
     ///
     /// ```rust,ignore
@@ -245,7 +506,33 @@ pub trait RuntimeExt {
     ///     runtime.run().await.expect("Runtime falló");
     /// });
     /// ```
-    fn run(&mut self) -> impl std::future::Future<Output = Result<(), Self::Error>> + Send;
+    fn run(&mut self) -> impl core::future::Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Decide cómo reacciona el loop de [`run`](Self::run) ante `err`.
+    ///
+    /// Muchos errores de dispositivo son recuperables (`EAGAIN`, un corte breve del
+    /// USB): un backend robusto puede retornar [`ErrorAction::Retry`] o
+    /// [`ErrorAction::Restart`] para superarlos sin que el motor tenga que recrear el
+    /// runtime.
+    ///
+    /// La implementación por defecto retorna [`ErrorAction::Abort`], es decir,
+    /// `run()` termina con el primer error.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// fn on_error(&mut self, err: &Self::Error) -> ErrorAction {
+    ///     match err.kind() {
+    ///         std::io::ErrorKind::WouldBlock => ErrorAction::Retry,
+    ///         std::io::ErrorKind::NotFound => ErrorAction::Restart,
+    ///         _ => ErrorAction::Abort,
+    ///     }
+    /// }
+    /// ```
+    fn on_error(&mut self, err: &Self::Error) -> ErrorAction {
+        let _ = err;
+        ErrorAction::Abort
+    }
 
     /// Detiene la captura de eventos y libera los recursos del runtime.
     ///
@@ -275,6 +562,109 @@ pub trait RuntimeExt {
     /// Puede fallar si el reinicio no es posible (mismo tipo de errores que `new()`).
     fn restart(&mut self) -> Result<(), Self::Error>;
 
+    /// Pausa la actualización del estado compartido **sin cerrar los dispositivos**.
+    ///
+    /// Pensado para alt-tab o menús de pausa, donde reabrir los dispositivos con
+    /// `stop()`/`restart()` añade latencia y pierde su estado. Mientras está pausado,
+    /// el loop de `run()` sigue girando y leyendo del backend, pero los eventos no
+    /// llegan al estado compartido. Cada implementación decide y documenta si los
+    /// **descarta** o los **acumula** para aplicarlos en
+    /// [`unpause`](Self::unpause).
+    ///
+    /// Pausar un runtime ya pausado no tiene efecto. El runtime sigue contando como
+    /// activo para [`is_running`](Self::is_running).
+    ///
+    /// Se llama `unpause` (y no `resume`) porque [`resume`](Self::resume) es la
+    /// contraparte de [`suspend`](Self::suspend), que sí cierra los dispositivos.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// // El jugador abre el menú de pausa
+    /// runtime.pause()?;
+    /// // ... al cerrarlo ...
+    /// runtime.unpause()?;
+    /// ```
+    fn pause(&mut self) -> Result<(), Self::Error>;
+
+    /// Reanuda la actualización del estado compartido tras [`pause`](Self::pause).
+    ///
+    /// Si la implementación acumula eventos durante la pausa, los aplica aquí. Sobre
+    /// un runtime no pausado no tiene efecto.
+    fn unpause(&mut self) -> Result<(), Self::Error>;
+
+    /// Registra el manejador que recibe los cambios de **conexión de dispositivos**
+    /// (hotplug), reemplazando al anterior.
+    ///
+    /// El runtime lo invoca desde su loop cada vez que detecta que un dispositivo se
+    /// conectó o se desconectó. Los juegos lo usan para mostrar avisos de "mando
+    /// desconectado" o para volver a pedir bindings a un dispositivo nuevo. Los
+    /// backends sin hotplug pueden guardar el manejador y no llamarlo nunca.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// let overlay = overlay.clone();
+    /// runtime.on_device_change(move |change| match change {
+    ///     DeviceChange::Connected { name, .. } => overlay.toast(format!("{name} conectado")),
+    ///     DeviceChange::Disconnected { .. } => overlay.show("Mando desconectado"),
+    /// });
+    /// ```
+    fn on_device_change(&mut self, handler: impl FnMut(Self::DeviceEvent) + Send + 'static);
+
+    /// Suspende el runtime por un evento de ciclo de vida del sistema operativo
+    /// (app enviada a segundo plano en móvil o consola).
+    ///
+    /// Es más pesado que una pausa: cierra los dispositivos y retorna un
+    /// [`RuntimeSnapshot`] con lo necesario para restaurar el runtime con
+    /// [`resume`](Self::resume).
+    ///
+    /// ## Qué se conserva
+    /// - ✅ Configuración del backend y bindings (en [`RuntimeSnapshot::config`])
+    /// - ✅ Contadores de monitoreo
+    /// - ❌ Teclas mantenidas y flancos: se descartan, porque el usuario puede haber
+    ///   soltado cualquier tecla mientras la app estaba suspendida
+    ///
+    /// La implementación por defecto llama a [`stop`](Self::stop) y retorna una
+    /// instantánea sin configuración.
+    ///
+    /// # Errores
+    ///
+    /// Los mismos que [`stop`](Self::stop).
+    fn suspend(&mut self) -> Result<RuntimeSnapshot, Self::Error> {
+        self.stop()?;
+        Ok(RuntimeSnapshot::new(self.backend_name(), self.events_processed()))
+    }
+
+    /// Restaura un runtime suspendido con [`suspend`](Self::suspend).
+    ///
+    /// Reabre los dispositivos y reaplica la configuración de la instantánea. El
+    /// estado de input se limpia con [`reset_state`](Self::reset_state), de modo que
+    /// ninguna tecla queda "pegada" tras volver de segundo plano.
+    ///
+    /// Una instantánea generada por otro backend (ver [`RuntimeSnapshot::backend`])
+    /// debe ignorar su configuración.
+    ///
+    /// La implementación por defecto llama a `reset_state()` y luego a
+    /// [`restart`](Self::restart).
+    ///
+    /// # Errores
+    ///
+    /// Los mismos que [`restart`](Self::restart).
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// let snapshot = runtime.suspend()?;
+    /// // ... la app vuelve a primer plano ...
+    /// runtime.resume(snapshot)?;
+    /// ```
+    fn resume(&mut self, snapshot: RuntimeSnapshot) -> Result<(), Self::Error> {
+        let _ = snapshot;
+        self.reset_state();
+        self.restart()
+    }
+
     // ==================== MONITOREO Y ESTADO ====================
 
     /// Retorna `true` si el runtime se encuentra activo (capturando eventos).
@@ -288,6 +678,21 @@ pub trait RuntimeExt {
     /// ```
     fn is_running(&self) -> bool;
 
+    /// Retorna las métricas de salud del runtime en una sola llamada.
+    ///
+    /// [`events_processed`](Self::events_processed) y
+    /// [`backend_name`](Self::backend_name) son accesores sobre este valor.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// let diag = runtime.diagnostics();
+    /// if diag.events_dropped > 0 {
+    ///     log::warn!("{}: {} eventos perdidos", diag.backend, diag.events_dropped);
+    /// }
+    /// ```
+    fn diagnostics(&self) -> RuntimeDiagnostics;
+
     /// Retorna la cantidad de eventos procesados desde que se inició el runtime.
     ///
     /// Útil para:
@@ -295,12 +700,16 @@ pub trait RuntimeExt {
     /// - Estadísticas de rendimiento
     /// - Detección de problemas (ej: contador no aumenta = runtime congelado)
     ///
+    /// Por defecto lee [`RuntimeDiagnostics::events_processed`].
+    ///
     /// # Ejemplo
     ///
     /// ```rust,ignore
     /// println!("Eventos procesados: {}", runtime.events_processed());
     /// ```
-    fn events_processed(&self) -> usize;
+    fn events_processed(&self) -> usize {
+        self.diagnostics().events_processed
+    }
 
     /// Retorna una descripción textual del backend o estado actual.
     ///
@@ -315,7 +724,51 @@ pub trait RuntimeExt {
     /// println!("Backend: {}", runtime.backend_name());
     /// // Output: "Linux (evdev)" o "Windows (Raw Input)"
     /// ```
-    fn backend_name(&self) -> &'static str;
+    ///
+    /// Por defecto lee [`RuntimeDiagnostics::backend`].
+    fn backend_name(&self) -> Cow<'static, str> {
+        self.diagnostics().backend
+    }
+
+    /// Enumera los dispositivos que el runtime conoce, habilitados o no.
+    ///
+    /// Permite a una pantalla de ajustes elegir, por ejemplo, "solo el arcade stick,
+    /// ignorar el teclado" con [`enable_device`](Self::enable_device).
+    ///
+    /// La implementación por defecto es la de un backend de **un solo dispositivo**:
+    /// retorna una entrada con `DeviceId(0)`, el [`backend_name`](Self::backend_name)
+    /// como nombre y [`DeviceKind::Other`].
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// for device in runtime.list_devices() {
+    ///     settings.add_toggle(device.id, &device.name);
+    /// }
+    /// ```
+    fn list_devices(&self) -> Vec<DeviceInfo> {
+        alloc::vec![DeviceInfo {
+            id: DeviceId(0),
+            name: self.backend_name().into_owned(),
+            kind: DeviceKind::Other,
+        }]
+    }
+
+    /// Habilita o deshabilita un dispositivo: los eventos de un dispositivo
+    /// deshabilitado no llegan al estado compartido, pero el dispositivo sigue
+    /// abierto.
+    ///
+    /// La implementación por defecto (un solo dispositivo, que no se puede
+    /// deshabilitar) no hace nada y retorna `Ok(())`.
+    ///
+    /// # Errores
+    ///
+    /// Específicos del backend, por ejemplo si `id` no corresponde a ningún
+    /// dispositivo conocido.
+    fn enable_device(&mut self, id: DeviceId, enabled: bool) -> Result<(), Self::Error> {
+        let _ = (id, enabled);
+        Ok(())
+    }
 
     /// Reinicia el estado de entrada **sin detener el runtime**.
     ///
@@ -342,4 +795,509 @@ pub trait RuntimeExt {
     /// Este método debe ser invocado por el loop principal del motor al inicio o final
     /// de cada frame para mantener el estado sincronizado.
     fn update(&mut self);
-}
\ No newline at end of file
+
+    /// Marca el **límite de frame** del estado compartido: lo bloquea y llama a su
+    /// [`advance_frame`](crate::InputStateExt::advance_frame).
+    ///
+    /// El game loop lo llama una vez por frame de render, después de consultar el
+    /// input. Es necesario porque [`SharedState`](Self::SharedState) es opaco: quien
+    /// llama no conoce el tipo de lock y no puede avanzar el frame por su cuenta, así
+    /// que sin este método [`is_just_press`](crate::InputStateExt::is_just_press)
+    /// nunca se limpiaría.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust,ignore
+    /// loop {
+    ///     game.update(&input_state);
+    ///     runtime.frame_tick();
+    /// }
+    /// ```
+    fn frame_tick(&self);
+}
+
+/// # Trait `InjectableRuntime`
+///
+/// Runtime que acepta **eventos sintéticos**, para tests de integración, demos
+/// guionizadas y reproducción de replays.
+///
+/// `K` y `S` son los tipos de tecla y estado **normalizados** del estado
+/// compartido. El evento inyectado sigue el mismo camino que uno real a partir de la
+/// traducción: aplica la [`OverflowPolicy`], respeta [`pause`](RuntimeExt::pause),
+/// actualiza el estado compartido con [`set_key`](crate::InputStateExt::set_key) e
+/// incrementa [`events_processed`](RuntimeExt::events_processed).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// // Reproducir un replay exportado con `HistoryAccessExt::export_history`
+/// for record in &records {
+///     runtime.inject(record.key, record.state)?;
+/// }
+/// ```
+pub trait InjectableRuntime<K, S>: RuntimeExt {
+    /// Inyecta un evento sintético como si lo hubiera leído el backend.
+    ///
+    /// # Errores
+    ///
+    /// Específicos del backend, por ejemplo si el runtime está detenido y no puede
+    /// aceptar eventos.
+    fn inject(&mut self, key: K, state: S) -> Result<(), Self::Error>;
+}
+
+/// # Trait `PollRuntimeExt`
+///
+/// Variante **síncrona** de [`RuntimeExt`] basada en sondeo.
+///
+/// [`RuntimeExt::run`] es `async` y su future debe ser `Send`, lo que deja fuera a
+/// los backends de un solo hilo o bare-metal que no tienen executor. Con este trait
+/// el game loop procesa la entrada directamente, sin arrastrar Tokio ni ningún otro
+/// runtime async.
+///
+/// El trait no expone el estado: `poll` lo actualiza y el game loop lo consulta
+/// por el acceso que ofrezca el backend (por ejemplo, el estado compartido que
+/// retorna su constructor).
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let (mut runtime, shared) = MyPollRuntime::new()?;
+///
+/// loop {
+///     let handled = runtime.poll()?;
+///     stats.events += handled;
+///
+///     let mut state = shared.lock();
+///     if state.is_just_press(KeyCode::Escape) {
+///         break;
+///     }
+///     state.advance_frame();
+/// }
+/// ```
+pub trait PollRuntimeExt {
+    /// Tipo de error retornado por el backend.
+    type Error;
+
+    /// Procesa **todos** los eventos disponibles en este momento, sin bloquear, y
+    /// retorna cuántos se manejaron (`0` si no había ninguno pendiente).
+    ///
+    /// Se llama desde el game loop, normalmente una vez por frame antes de consultar
+    /// el estado.
+    fn poll(&mut self) -> Result<usize, Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputStateExt;
+    use crate::testing::fixtures::{block_on, Btn, Input, Key};
+    use crate::testing::{lock, Lock, NullRuntime};
+    use alloc::sync::Arc;
+
+    #[test]
+    fn with_capacity_behaves_like_new() {
+        let (mut sized, sized_state) = NullRuntime::<Input, Key, Btn>::with_capacity(64, 1024).unwrap();
+        let (mut plain, plain_state) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        for runtime in [&mut sized, &mut plain] {
+            runtime.inject(Key::A, Btn::Down).unwrap();
+            runtime.inject(Key::B, Btn::Down).unwrap();
+        }
+
+        assert_eq!(sized.events_processed(), plain.events_processed());
+        assert_eq!(sized.backend_name(), plain.backend_name());
+        assert_eq!(lock(&sized_state).keys_pressed().len(), 2);
+        assert_eq!(
+            lock(&sized_state).is_pressed(Key::A),
+            lock(&plain_state).is_pressed(Key::A)
+        );
+    }
+
+    #[test]
+    fn default_suspend_and_resume_clear_held_keys() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        block_on(runtime.initialize()).unwrap();
+        runtime.inject(Key::A, Btn::Down).unwrap();
+
+        let snapshot = runtime.suspend().unwrap();
+        assert!(!runtime.is_running());
+        assert_eq!(snapshot, RuntimeSnapshot::new("Null", 1));
+
+        runtime.resume(snapshot).unwrap();
+        assert!(runtime.is_running());
+        assert!(!lock(&shared).is_pressed(Key::A));
+    }
+
+    type DeviceHandler = Box<dyn FnMut(DeviceChange) + Send>;
+
+    /// [`NullRuntime`] con una sensibilidad configurable que sobrevive a la suspensión
+    /// y un manejador de hotplug que los tests disparan con [`plug`](Self::plug).
+    struct ConfiguredRuntime {
+        inner: NullRuntime<Input, Key, Btn>,
+        sensitivity: u8,
+        on_change: Option<Lock<DeviceHandler>>,
+    }
+
+    impl ConfiguredRuntime {
+        /// Simula un cambio de topología detectado por el loop.
+        fn plug(&mut self, change: DeviceChange) {
+            if let Some(handler) = &self.on_change {
+                (*lock(handler))(change);
+            }
+        }
+    }
+
+    impl RuntimeExt for ConfiguredRuntime {
+        type Error = core::convert::Infallible;
+        type State = Input;
+        type SharedState = Arc<Lock<Input>>;
+        type DeviceEvent = DeviceChange;
+
+        fn new() -> Result<(Self, Self::SharedState), Self::Error> {
+            let (inner, shared) = NullRuntime::new()?;
+            Ok((Self { inner, sensitivity: 5, on_change: None }, shared))
+        }
+
+        async fn initialize(&mut self) -> Result<(), Self::Error> {
+            self.inner.initialize().await
+        }
+
+        async fn ready(&self) {
+            self.inner.ready().await;
+        }
+
+        async fn run(&mut self) -> Result<(), Self::Error> {
+            self.inner.run().await
+        }
+
+        fn stop(&mut self) -> Result<(), Self::Error> {
+            self.inner.stop()
+        }
+
+        fn restart(&mut self) -> Result<(), Self::Error> {
+            self.inner.restart()
+        }
+
+        fn pause(&mut self) -> Result<(), Self::Error> {
+            self.inner.pause()
+        }
+
+        fn unpause(&mut self) -> Result<(), Self::Error> {
+            self.inner.unpause()
+        }
+
+        fn on_device_change(&mut self, handler: impl FnMut(Self::DeviceEvent) + Send + 'static) {
+            self.on_change = Some(Lock::new(Box::new(handler)));
+        }
+
+        fn suspend(&mut self) -> Result<RuntimeSnapshot, Self::Error> {
+            let mut snapshot = self.inner.suspend()?;
+            snapshot.config = alloc::vec![self.sensitivity];
+            Ok(snapshot)
+        }
+
+        fn resume(&mut self, snapshot: RuntimeSnapshot) -> Result<(), Self::Error> {
+            if snapshot.backend == self.backend_name() {
+                if let [sensitivity] = snapshot.config[..] {
+                    self.sensitivity = sensitivity;
+                }
+            }
+            self.inner.resume(snapshot)
+        }
+
+        fn is_running(&self) -> bool {
+            self.inner.is_running()
+        }
+
+        fn diagnostics(&self) -> RuntimeDiagnostics {
+            self.inner.diagnostics()
+        }
+
+        fn reset_state(&mut self) {
+            self.inner.reset_state();
+        }
+
+        fn update(&mut self) {}
+
+        fn frame_tick(&self) {
+            self.inner.frame_tick();
+        }
+    }
+
+    #[test]
+    fn suspend_round_trip_keeps_config_and_drops_held_keys() {
+        let (mut runtime, shared) = ConfiguredRuntime::new().unwrap();
+        runtime.sensitivity = 9;
+        runtime.inner.inject(Key::A, Btn::Down).unwrap();
+
+        let snapshot = runtime.suspend().unwrap();
+        runtime.sensitivity = 0;
+        runtime.resume(snapshot).unwrap();
+
+        assert_eq!(runtime.sensitivity, 9);
+        assert!(!lock(&shared).any_pressed());
+        assert!(runtime.is_running());
+    }
+
+    #[test]
+    fn resume_ignores_config_from_another_backend() {
+        let (mut runtime, _) = ConfiguredRuntime::new().unwrap();
+        let mut foreign = RuntimeSnapshot::new("Evdev", 0);
+        foreign.config = alloc::vec![9];
+
+        runtime.resume(foreign).unwrap();
+
+        assert_eq!(runtime.sensitivity, 5);
+    }
+
+    #[test]
+    fn device_changes_reach_the_latest_handler() {
+        let (mut runtime, _) = ConfiguredRuntime::new().unwrap();
+        let seen = Arc::new(Lock::new(Vec::new()));
+        let replaced = Arc::new(Lock::new(Vec::new()));
+
+        let log = replaced.clone();
+        runtime.on_device_change(move |change| lock(&log).push(change));
+        let log = seen.clone();
+        runtime.on_device_change(move |change| lock(&log).push(change));
+
+        let pad = DeviceChange::Connected { name: "Arcade Stick".into(), id: DeviceId(3) };
+        runtime.plug(pad.clone());
+        runtime.plug(DeviceChange::Disconnected { id: DeviceId(3) });
+
+        assert_eq!(*lock(&seen), [pad, DeviceChange::Disconnected { id: DeviceId(3) }]);
+        assert!(lock(&replaced).is_empty());
+    }
+
+    #[test]
+    fn backends_without_hotplug_accept_a_handler() {
+        let (mut runtime, _) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        runtime.on_device_change(|change| panic!("cambio inesperado: {change:?}"));
+        block_on(runtime.run()).unwrap();
+    }
+
+    #[test]
+    fn single_device_backends_list_one_pseudo_device() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        let devices = runtime.list_devices();
+        assert_eq!(devices, [DeviceInfo { id: DeviceId(0), name: "Null".into(), kind: DeviceKind::Other }]);
+
+        assert_eq!(runtime.enable_device(DeviceId(0), false), Ok(()));
+        runtime.inject(Key::A, Btn::Down).unwrap();
+        assert!(lock(&shared).is_pressed(Key::A));
+    }
+
+    #[test]
+    fn diagnostics_start_at_zero() {
+        let diagnostics = RuntimeDiagnostics::new("evdev");
+
+        assert_eq!(diagnostics.backend, "evdev");
+        assert_eq!(diagnostics, RuntimeDiagnostics { backend: "evdev".into(), ..RuntimeDiagnostics::default() });
+        assert_eq!(diagnostics.last_event, None);
+    }
+
+    #[test]
+    fn accessors_read_the_diagnostics() {
+        let (mut runtime, _) = ConfiguredRuntime::new().unwrap();
+        assert_eq!(runtime.diagnostics().last_event, None);
+
+        runtime.inner.inject(Key::A, Btn::Down).unwrap();
+        runtime.inner.inject(Key::A, Btn::Up).unwrap();
+
+        let diagnostics = runtime.diagnostics();
+        assert_eq!(runtime.events_processed(), diagnostics.events_processed);
+        assert_eq!(runtime.events_processed(), 2);
+        assert_eq!(runtime.backend_name(), diagnostics.backend);
+        assert!(diagnostics.last_event.is_some());
+        assert_eq!(diagnostics.events_dropped, 0);
+    }
+
+    #[test]
+    fn backends_without_a_queue_reject_every_overflow_policy() {
+        let (mut runtime, _) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+
+        let policies = [OverflowPolicy::DropOldest, OverflowPolicy::DropNewest, OverflowPolicy::Block, OverflowPolicy::Coalesce];
+        for policy in policies {
+            assert!(!runtime.set_overflow_policy(policy));
+        }
+        assert_eq!(OverflowPolicy::default(), OverflowPolicy::DropOldest);
+    }
+
+    /// Fallo de lectura de [`FlakyRuntime`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum DeviceError {
+        /// `EAGAIN`: no había datos, bastaría con volver a leer.
+        WouldBlock,
+    }
+
+    /// Runtime cuyo dispositivo entrega una lista fija de lecturas, algunas fallidas.
+    /// No sobrescribe [`on_error`](RuntimeExt::on_error).
+    struct FlakyRuntime {
+        inner: NullRuntime<Input, Key, Btn>,
+        reads: alloc::collections::VecDeque<Result<(Key, Btn), DeviceError>>,
+        restarts: usize,
+    }
+
+    impl RuntimeExt for FlakyRuntime {
+        type Error = DeviceError;
+        type State = Input;
+        type SharedState = Arc<Lock<Input>>;
+        type DeviceEvent = DeviceChange;
+
+        fn new() -> Result<(Self, Self::SharedState), Self::Error> {
+            let (inner, shared) = NullRuntime::new().unwrap();
+            Ok((Self { inner, reads: Default::default(), restarts: 0 }, shared))
+        }
+
+        async fn initialize(&mut self) -> Result<(), Self::Error> {
+            self.inner.initialize().await.unwrap();
+            Ok(())
+        }
+
+        async fn ready(&self) {
+            self.inner.ready().await;
+        }
+
+        async fn run(&mut self) -> Result<(), Self::Error> {
+            while let Some(read) = self.reads.pop_front() {
+                match read {
+                    Ok((key, state)) => self.inner.inject(key, state).unwrap(),
+                    Err(err) => match self.on_error(&err) {
+                        ErrorAction::Retry => {}
+                        ErrorAction::Restart => self.restarts += 1,
+                        ErrorAction::Abort => return Err(err),
+                    },
+                }
+            }
+            Ok(())
+        }
+
+        fn stop(&mut self) -> Result<(), Self::Error> {
+            self.inner.stop().unwrap();
+            Ok(())
+        }
+
+        fn restart(&mut self) -> Result<(), Self::Error> {
+            self.inner.restart().unwrap();
+            Ok(())
+        }
+
+        fn pause(&mut self) -> Result<(), Self::Error> {
+            self.inner.pause().unwrap();
+            Ok(())
+        }
+
+        fn unpause(&mut self) -> Result<(), Self::Error> {
+            self.inner.unpause().unwrap();
+            Ok(())
+        }
+
+        fn on_device_change(&mut self, handler: impl FnMut(Self::DeviceEvent) + Send + 'static) {
+            self.inner.on_device_change(handler);
+        }
+
+        fn is_running(&self) -> bool {
+            self.inner.is_running()
+        }
+
+        fn diagnostics(&self) -> RuntimeDiagnostics {
+            self.inner.diagnostics()
+        }
+
+        fn reset_state(&mut self) {
+            self.inner.reset_state();
+        }
+
+        fn update(&mut self) {}
+
+        fn frame_tick(&self) {
+            self.inner.frame_tick();
+        }
+    }
+
+    #[test]
+    fn default_error_policy_aborts_on_the_first_error() {
+        let (mut runtime, shared) = FlakyRuntime::new().unwrap();
+        runtime.reads.extend([Ok((Key::A, Btn::Down)), Err(DeviceError::WouldBlock), Ok((Key::B, Btn::Down))]);
+
+        assert_eq!(runtime.on_error(&DeviceError::WouldBlock), ErrorAction::Abort);
+        assert_eq!(block_on(runtime.run()), Err(DeviceError::WouldBlock));
+        assert!(lock(&shared).is_pressed(Key::A));
+        assert!(!lock(&shared).is_pressed(Key::B));
+        assert_eq!(runtime.reads.len(), 1);
+        assert_eq!(runtime.restarts, 0);
+    }
+
+    /// Game loop que solo conoce [`RuntimeExt`], sin el tipo de lock del estado.
+    fn end_frame(runtime: &impl RuntimeExt) {
+        runtime.frame_tick();
+    }
+
+    #[test]
+    fn frame_tick_advances_the_shared_state() {
+        let (mut runtime, shared) = ConfiguredRuntime::new().unwrap();
+        runtime.inner.inject(Key::A, Btn::Down).unwrap();
+        runtime.inner.inject(Key::B, Btn::Down).unwrap();
+        runtime.inner.inject(Key::B, Btn::Up).unwrap();
+
+        end_frame(&runtime);
+        assert!(lock(&shared).is_pressed(Key::A));
+        assert!(!lock(&shared).is_just_press(Key::A));
+        assert!(!lock(&shared).is_just_released(Key::B));
+
+        for _ in 0..3 {
+            end_frame(&runtime);
+        }
+        assert!(lock(&shared).held_stable(Key::A, 4));
+    }
+
+    /// Backend de sondeo con una cola de eventos pendientes, sin executor.
+    #[derive(Default)]
+    struct QueuedPoll {
+        pending: alloc::collections::VecDeque<(Key, Btn)>,
+        state: Input,
+    }
+
+    impl PollRuntimeExt for QueuedPoll {
+        type Error = core::convert::Infallible;
+
+        fn poll(&mut self) -> Result<usize, Self::Error> {
+            let handled = self.pending.len();
+            for (key, state) in self.pending.drain(..) {
+                self.state.set_key(key, state);
+            }
+            Ok(handled)
+        }
+    }
+
+    #[test]
+    fn poll_drains_the_available_events() {
+        let mut runtime = QueuedPoll::default();
+        runtime.pending.extend([(Key::A, Btn::Down), (Key::B, Btn::Down), (Key::A, Btn::Up)]);
+
+        assert_eq!(runtime.poll(), Ok(3));
+        assert_eq!(runtime.state.keys_pressed(), [Key::B]);
+        assert_eq!(runtime.poll(), Ok(0));
+    }
+
+    #[test]
+    fn null_runtime_has_nothing_to_poll() {
+        let (mut runtime, shared) = NullRuntime::<Input, Key, Btn>::new().unwrap();
+        runtime.inject(Key::A, Btn::Down).unwrap();
+
+        assert_eq!(runtime.poll(), Ok(0));
+        assert!(lock(&shared).is_pressed(Key::A));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_roundtrips_through_json() {
+        let mut snapshot = RuntimeSnapshot::new("null", 42);
+        snapshot.config = vec![7];
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: RuntimeSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, snapshot);
+        assert!(matches!(loaded.backend, Cow::Owned(_)));
+    }
+}
@@ -1,5 +1,9 @@
-use std::time::{Duration, Instant};
-use std::hash::Hash;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use crate::Clock;
 
 /// # Trait `InputEvent`
 ///
@@ -14,6 +18,25 @@ use std::hash::Hash;
 /// - Permitir que los backends definan sus propios tipos de evento.
 /// - Facilitar el análisis temporal y detección de patrones.
 ///
+/// ## Tiempo
+/// Cada evento declara con [`Time`](Self::Time) el tipo de su marca de tiempo: los
+/// backends de escritorio usan `Instant` y los embebidos, por ejemplo, un contador de
+/// ticks `u32`. El historial sabe medir intervalos entre marcas a través de su
+/// [`Clock`](HistoryAccessExt::Clock), cuyo `Timestamp` debe ser este mismo tipo.
+///
+/// ## Migración desde 0.1.3
+/// `timestamp` retornaba un `Instant` fijo. Ahora:
+/// - Añadir `type Time = Instant;` a cada implementación de `InputEvent`. El tipo
+///   debe implementar `Ord` (un `f32` no sirve, un `Instant` o un entero sí).
+/// - Añadir `type Clock = StdClock;` a cada implementación de [`WithHistoryExt`].
+/// - Las consultas que recibían un `Instant` (por ejemplo,
+///   [`state_at_time`](HistoryAccessExt::state_at_time)) reciben ahora `T::Time`; con
+///   `Instant` el código que las llama no cambia.
+///
+/// Las duraciones siguen siendo [`core::time::Duration`], que ya está disponible sin
+/// `std`: los relojes de ticks convierten sus intervalos a `Duration` en
+/// [`Clock::duration_between`], por lo que no hace falta un tipo de duración propio.
+///
 /// ## Ejemplo de implementación
 /// ```rust,ignore
 /// use std::time::Instant;
@@ -30,6 +53,7 @@ use std::hash::Hash;
 /// impl InputEvent for KeyEvent {
 ///     type Key = KeyCode;
 ///     type State = KeyState;
+///     type Time = Instant;
 ///     
 ///     fn key(&self) -> Self::Key {
 ///         self.keycode
@@ -48,8 +72,16 @@ pub trait InputEvent: Hash + PartialEq + Clone {
     /// Tipo de tecla usado por este evento.
     type Key: Copy + PartialEq + Hash;
     
-    /// Tipo de estado usado por este evento.
-    type State: Copy + PartialEq;
+    /// Tipo de estado usado por este evento. [`StateSemantics`] permite clasificar el
+    /// evento con [`is_press`](Self::is_press) e [`is_release`](Self::is_release).
+    type State: StateSemantics;
+
+    /// Tipo de la marca de tiempo del evento (por ejemplo, `Instant` o un contador
+    /// de ticks).
+    ///
+    /// Tiene los mismos requisitos que [`Clock::Timestamp`] (`Copy + Ord`), de modo
+    /// que cualquier marca de un reloj sirve como tiempo de evento y viceversa.
+    type Time: Copy + Ord;
     
     /// Retorna la tecla asociada a este evento.
     fn key(&self) -> Self::Key;
@@ -58,7 +90,805 @@ pub trait InputEvent: Hash + PartialEq + Clone {
     fn state(&self) -> Self::State;
     
     /// Retorna el instante temporal en que ocurrió este evento.
-    fn timestamp(&self) -> Instant;
+    fn timestamp(&self) -> Self::Time;
+
+    /// Retorna `true` si el evento es una **pulsación**: el flanco en que la tecla
+    /// baja.
+    ///
+    /// Es el criterio con el que los algoritmos del historial (secuencias, combos,
+    /// estadísticas) distinguen pulsaciones. Por defecto delega en
+    /// [`StateSemantics::is_press`] del estado.
+    ///
+    /// ## Autorepeat
+    /// Los eventos de autorepeat del sistema operativo no deben contar como
+    /// pulsaciones, o inflarían [`press_frequency`](HistoryStatsExt::press_frequency)
+    /// y romperían los doble taps; tampoco son liberaciones. Si el estado distingue el
+    /// autorepeat (por ejemplo un `KeyState::Repeat`), basta con excluirlo en
+    /// [`StateSemantics::is_press`]. Si el evento lo marca aparte, el evento puede
+    /// sobrescribir este método; y un juego que sí quiera contar el autorepeat como
+    /// pulsación lo decide aquí mismo:
+    ///
+    /// ```rust,ignore
+    /// fn is_press(&self) -> bool {
+    ///     self.state.is_press() && (COUNT_REPEATS || !self.repeat)
+    /// }
+    /// ```
+    fn is_press(&self) -> bool {
+        self.state().is_press()
+    }
+
+    /// Retorna `true` si el evento es una **liberación**: la tecla queda arriba.
+    ///
+    /// Por defecto delega en [`StateSemantics::is_release`]. Un evento de autorepeat
+    /// no es una liberación.
+    fn is_release(&self) -> bool {
+        self.state().is_release()
+    }
+
+    /// Convierte el evento a su forma [`SerializableEvent`], con la marca de tiempo
+    /// expresada como microsegundos desde `origin` medidos con el reloj `C`.
+    ///
+    /// Los eventos anteriores a `origin` quedan con desplazamiento `0`, y los
+    /// desplazamientos que no caben en `u64` se saturan.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let origin = history.history()[0].timestamp();
+    /// let wire: Vec<_> = history
+    ///     .replay()
+    ///     .map(|event| event.to_serializable::<StdClock>(origin))
+    ///     .collect();
+    /// ```
+    fn to_serializable<C>(&self, origin: Self::Time) -> SerializableEvent<Self::Key, Self::State>
+    where
+        C: Clock<Timestamp = Self::Time>,
+    {
+        SerializableEvent {
+            key: self.key(),
+            state: self.state(),
+            offset_micros: u64::try_from(C::duration_between(origin, self.timestamp()).as_micros())
+                .unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// # Trait `ChordEvent`
+///
+/// Extiende [`InputEvent`] para eventos que pueden representar un **acorde**: varias
+/// teclas que cambiaron de estado "a la vez" (por ejemplo `Ctrl+Shift+S`).
+///
+/// Los backends suelen emitir un evento por tecla con microsegundos de diferencia;
+/// [`collapse`](Self::collapse) los agrupa en una sola entrada lógica, lo que reduce
+/// el historial y evita confundir a los detectores de secuencias.
+///
+/// ## Cómo ven un acorde las consultas del historial
+/// - [`key`](InputEvent::key) retorna la **primera** tecla del acorde (la más temprana).
+/// - [`chord_keys`](Self::chord_keys) retorna todas, en el orden en que llegaron.
+/// - [`timestamp`](InputEvent::timestamp) es el del evento más temprano del grupo.
+///
+/// Por tanto, un matcher de secuencias que solo mira `key()` ve el acorde como un
+/// único paso.
+pub trait ChordEvent: InputEvent {
+    /// Teclas que componen el evento. Un evento simple retorna solo su [`key`](InputEvent::key).
+    fn chord_keys(&self) -> &[Self::Key];
+
+    /// Construye un evento de acorde a partir de un grupo de eventos consecutivos
+    /// con el mismo estado (`events.len() >= 2`, ordenados por tiempo).
+    fn merge(events: &[Self]) -> Self;
+
+    /// Retorna `true` si el evento agrupa más de una tecla.
+    fn is_chord(&self) -> bool {
+        self.chord_keys().len() > 1
+    }
+
+    /// Agrupa en acordes los eventos **consecutivos** con el mismo estado cuyo
+    /// timestamp esté a `tolerance` o menos del primer evento del grupo.
+    ///
+    /// El reloj `C` mide la distancia entre marcas de tiempo; normalmente es el
+    /// [`Clock`](HistoryAccessExt::Clock) del historial que contiene los eventos.
+    ///
+    /// Los eventos que no forman grupo se conservan tal cual. El orden relativo del
+    /// resultado es el mismo que el de `events`.
+    fn collapse<C>(events: &[Self], tolerance: Duration) -> Vec<Self>
+    where
+        C: Clock<Timestamp = Self::Time>,
+    {
+        let mut collapsed = Vec::with_capacity(events.len());
+        let mut start = 0;
+
+        while start < events.len() {
+            let first = &events[start];
+            let end = events[start..]
+                .iter()
+                .position(|event| {
+                    event.state() != first.state()
+                        || C::duration_between(first.timestamp(), event.timestamp()) > tolerance
+                })
+                .map_or(events.len(), |offset| start + offset);
+
+            if end - start > 1 {
+                collapsed.push(Self::merge(&events[start..end]));
+            } else {
+                collapsed.push(first.clone());
+            }
+            start = end;
+        }
+
+        collapsed
+    }
+}
+
+/// # Trait `StateSemantics`
+///
+/// Clasifica un tipo de estado `S` como **tecla abajo** o **tecla arriba**.
+///
+/// Los traits de estado son genéricos sobre `S`, por lo que no pueden saber
+/// por sí mismos qué variante significa "presionada". Este trait aporta esa
+/// interpretación mínima para que las utilidades genéricas del crate
+/// (validaciones, implementaciones por defecto) puedan razonar sobre el estado.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::StateSemantics;
+///
+/// impl StateSemantics for KeyState {
+///     fn is_down(&self) -> bool {
+///         matches!(self, KeyState::Pressed | KeyState::Active)
+///     }
+///
+///     fn released() -> Self {
+///         KeyState::Release
+///     }
+/// }
+/// ```
+pub trait StateSemantics: Copy + PartialEq {
+    /// Retorna `true` si el estado representa una tecla **abajo** (recién presionada o mantenida).
+    fn is_down(&self) -> bool;
+
+    /// Retorna `true` si un evento con este estado es una **pulsación**: el flanco en
+    /// que la tecla baja, no un reporte de tecla mantenida.
+    ///
+    /// Es el criterio por defecto de [`InputEvent::is_press`], y por tanto de
+    /// [`HistoryAccessExt::press_events`] y de la detección de secuencias. Por
+    /// defecto coincide con [`is_down`](Self::is_down), lo correcto
+    /// para modelos de dos estados. Los modelos con un estado "mantenida" que también
+    /// se registra en el historial (por ejemplo `Active`, o el autorepeat) deben
+    /// sobrescribirlo para excluirlo:
+    ///
+    /// ```rust,ignore
+    /// fn is_press(&self) -> bool {
+    ///     matches!(self, KeyState::Pressed)
+    /// }
+    /// ```
+    fn is_press(&self) -> bool {
+        self.is_down()
+    }
+
+    /// Retorna `true` si un evento con este estado es una **liberación**.
+    ///
+    /// Por defecto es `!is_down()`. Es el criterio por defecto de
+    /// [`InputEvent::is_release`].
+    fn is_release(&self) -> bool {
+        !self.is_down()
+    }
+
+    /// Retorna el estado canónico de **tecla liberada**.
+    ///
+    /// Usado por las implementaciones por defecto que necesitan sintetizar una
+    /// liberación (por ejemplo, [`InputStateExt::release_all`]).
+    fn released() -> Self;
+}
+
+/// Discrepancia entre el historial y el estado vivo detectada por
+/// [`HistoryAccessExt::assert_coherent`].
+///
+/// Identifica la primera tecla cuyo estado derivado del historial no coincide
+/// con lo que reporta [`InputStateExt::is_pressed`].
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoherenceError<K> {
+    /// Tecla en la que se detectó la discrepancia.
+    pub key: K,
+    /// Valor reportado por el estado vivo (`is_pressed`).
+    pub live_pressed: bool,
+    /// Valor derivado al reproducir el historial.
+    pub history_pressed: bool,
+}
+
+#[cfg(feature = "testing")]
+impl<K: core::fmt::Debug> core::fmt::Display for CoherenceError<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "estado incoherente para {:?}: is_pressed = {}, historial = {}",
+            self.key, self.live_pressed, self.history_pressed
+        )
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<K: core::fmt::Debug> core::error::Error for CoherenceError<K> {}
+
+/// Estado de input **reconstruido** a partir del historial en un punto del pasado.
+///
+/// Producido por [`HistoryAccessExt::state_at`] y [`HistoryAccessExt::state_at_time`].
+/// Es un valor independiente (no toma prestado el historial), por lo que puede
+/// guardarse y consultarse después de que el historial cambie.
+///
+/// Solo conserva el **último estado** registrado de cada tecla hasta ese punto;
+/// no reconstruye flancos ni temporizadores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconstructedState<K, S> {
+    states: Vec<(K, S)>,
+}
+
+impl<K, S> ReconstructedState<K, S>
+where
+    K: Copy + PartialEq + Hash,
+    S: Copy + PartialEq,
+{
+    /// Reconstruye el estado aplicando en orden los eventos dados.
+    pub fn from_events<'a, T>(events: impl IntoIterator<Item = &'a T>) -> Self
+    where
+        T: InputEvent<Key = K, State = S> + 'a,
+    {
+        let mut states: Vec<(K, S)> = Vec::new();
+        for event in events {
+            match states.iter_mut().find(|(key, _)| *key == event.key()) {
+                Some(entry) => entry.1 = event.state(),
+                None => states.push((event.key(), event.state())),
+            }
+        }
+        Self { states }
+    }
+
+    /// Retorna el último estado registrado de la tecla, o `None` si no tuvo eventos.
+    pub fn state_of(&self, key: K) -> Option<S> {
+        self.states
+            .iter()
+            .find(|(tracked, _)| *tracked == key)
+            .map(|(_, state)| *state)
+    }
+
+    /// Retorna `true` si la tecla estaba abajo en ese punto.
+    pub fn is_pressed(&self, key: K) -> bool
+    where
+        S: StateSemantics,
+    {
+        self.state_of(key).is_some_and(|state| state.is_down())
+    }
+
+    /// Retorna las teclas que estaban abajo en ese punto, en orden de primera aparición.
+    pub fn keys_pressed(&self) -> Vec<K>
+    where
+        S: StateSemantics,
+    {
+        self.states
+            .iter()
+            .filter(|(_, state)| state.is_down())
+            .map(|(key, _)| *key)
+            .collect()
+    }
+}
+
+/// Vista **de solo lectura** de un [`InputStateExt`] bajo otro espacio de teclas.
+///
+/// Producida por [`InputStateExt::map_keys`]. Traduce cada tecla `K` del estado
+/// original a `K2` mediante la función de mapeo:
+///
+/// - Es una vista **prestada** (no copia el estado): refleja siempre el estado actual
+///   del original.
+/// - Las teclas que el mapeo convierte en `None` son **invisibles** en la vista.
+/// - Si varias teclas originales se mapean a la misma `K2`, la vista la considera
+///   presionada si **cualquiera** de ellas lo está, y reporta un flanco si
+///   **cualquiera** de ellas lo tiene.
+///
+/// No implementa [`InputStateExt`]: la vista no puede modificar el estado prestado
+/// y el mapeo no es invertible, así que solo expone **consultas**, con los mismos
+/// nombres y semántica que en el trait. Se resuelven recorriendo las teclas
+/// rastreadas por [`InputStateExt::state_iter`] del original.
+pub struct MappedState<'a, I, K, S, F, K2>
+where
+    I: ?Sized,
+{
+    inner: &'a I,
+    map: F,
+    _marker: PhantomData<fn(K, S) -> K2>,
+}
+
+impl<I, K, S, F, K2> MappedState<'_, I, K, S, F, K2>
+where
+    I: InputStateExt<K, S> + ?Sized,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    F: Fn(K) -> Option<K2>,
+    K2: Copy + PartialEq,
+{
+    /// Teclas originales **rastreadas** que se mapean a `key`, presionadas o no.
+    fn sources(&self, key: K2) -> impl Iterator<Item = K> + '_ {
+        self.inner
+            .state_iter()
+            .map(|(source, _)| source)
+            .filter(move |source| (self.map)(*source) == Some(key))
+    }
+
+    /// Equivalente a [`InputStateExt::state_of`] en el espacio `K2`.
+    pub fn state_of(&self, key: K2) -> Option<S> {
+        self.state_iter()
+            .find(|(tracked, _)| *tracked == key)
+            .map(|(_, state)| state)
+    }
+
+    /// Equivalente a [`InputStateExt::is_pressed`] en el espacio `K2`.
+    pub fn is_pressed(&self, key: K2) -> bool {
+        self.sources(key).any(|source| self.inner.is_pressed(source))
+    }
+
+    /// Equivalente a [`InputStateExt::is_released`] en el espacio `K2`.
+    pub fn is_released(&self, key: K2) -> bool {
+        !self.is_pressed(key)
+    }
+
+    /// Equivalente a [`InputStateExt::is_just_press`] en el espacio `K2`.
+    pub fn is_just_press(&self, key: K2) -> bool {
+        self.sources(key).any(|source| self.inner.is_just_press(source))
+    }
+
+    /// Equivalente a [`InputStateExt::is_just_released`] en el espacio `K2`.
+    pub fn is_just_released(&self, key: K2) -> bool {
+        self.sources(key).any(|source| self.inner.is_just_released(source))
+    }
+
+    /// Equivalente a [`InputStateExt::changed_since`] en el espacio `K2`.
+    pub fn changed_since(&self, key: K2, tick: u64) -> bool {
+        self.sources(key).any(|source| self.inner.changed_since(source, tick))
+    }
+
+    /// Equivalente a [`InputStateExt::time_pressed`]; con varias teclas de origen,
+    /// retorna la pulsación más larga.
+    pub fn time_pressed(&self, key: K2) -> Option<Duration> {
+        self.sources(key)
+            .filter_map(|source| self.inner.time_pressed(source))
+            .max()
+    }
+
+    /// Equivalente a [`InputStateExt::held_stable`] en el espacio `K2`.
+    pub fn held_stable(&self, key: K2, frames: usize) -> bool {
+        self.sources(key).any(|source| self.inner.held_stable(source, frames))
+    }
+
+    /// Equivalente a [`InputStateExt::active_combo`] en el espacio `K2`.
+    pub fn active_combo(&self, combo: &[K2]) -> bool {
+        combo.iter().all(|key| self.is_pressed(*key))
+    }
+
+    /// Retorna `true` si alguna tecla **visible** en la vista está presionada.
+    pub fn any_pressed(&self) -> bool {
+        self.inner
+            .pressed_iter()
+            .any(|source| (self.map)(source).is_some())
+    }
+
+    /// Última tecla presionada del original, si es visible en la vista.
+    pub fn last_pressed(&self) -> Option<K2> {
+        self.inner.last_pressed().and_then(&self.map)
+    }
+
+    /// Teclas presionadas visibles en la vista, sin duplicados.
+    pub fn keys_pressed(&self) -> Vec<K2> {
+        self.state_iter()
+            .filter(|(_, state)| state.is_down())
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Una entrada por tecla visible, sin duplicados. Si varias teclas de origen se
+    /// mapean a la misma `K2`, prevalece el estado de una presionada.
+    pub fn state_iter(&self) -> impl Iterator<Item = (K2, S)> + use<'_, I, K, S, F, K2> {
+        let mut states: Vec<(K2, S)> = Vec::new();
+        for (source, state) in self.inner.state_iter() {
+            let Some(key) = (self.map)(source) else { continue };
+            match states.iter_mut().find(|(tracked, _)| *tracked == key) {
+                Some(entry) if !entry.1.is_down() => entry.1 = state,
+                Some(_) => {}
+                None => states.push((key, state)),
+            }
+        }
+        states.into_iter()
+    }
+}
+
+/// Hasher FNV-1a de 64 bits usado por [`HistoryAccessExt::timeline_hash`].
+///
+/// A diferencia de `DefaultHasher`, su algoritmo es fijo.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+/// Paso de un patrón para [`ComboDetectionExt::match_pattern`].
+///
+/// Cada token consume una cantidad fija de pulsaciones consecutivas.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use SeqToken::*;
+///
+/// // Hadouken: abajo, abajo-adelante (cualquier diagonal), adelante, puño
+/// let hadouken = [
+///     Exact(Pad::Down),
+///     AnyOf(&[Pad::DownRight, Pad::DownLeft]),
+///     Exact(Pad::Right),
+///     Exact(Pad::Punch),
+/// ];
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqToken<'a, K> {
+    /// Exactamente esta tecla (una pulsación).
+    Exact(K),
+    /// Cualquiera de estas teclas (una pulsación).
+    AnyOf(&'a [K]),
+    /// Cualquier tecla (una pulsación).
+    Wildcard,
+    /// La tecla repetida `n` veces seguidas (`n` pulsaciones; `n == 0` no consume nada).
+    Repeat(K, usize),
+}
+
+impl<K: PartialEq> SeqToken<'_, K> {
+    /// Número de pulsaciones que consume el token.
+    pub fn len(&self) -> usize {
+        match self {
+            SeqToken::Repeat(_, n) => *n,
+            _ => 1,
+        }
+    }
+
+    /// Retorna `true` si el token no consume ninguna pulsación (`Repeat(_, 0)`).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retorna `true` si `key` es aceptada por el token.
+    pub fn accepts(&self, key: &K) -> bool {
+        match self {
+            SeqToken::Exact(expected) | SeqToken::Repeat(expected, _) => expected == key,
+            SeqToken::AnyOf(options) => options.contains(key),
+            SeqToken::Wildcard => true,
+        }
+    }
+}
+
+/// Especificación **completa** de una secuencia de pulsaciones, evaluada con
+/// [`ComboDetectionExt::matches`].
+///
+/// Reúne en un solo valor lo que hoy se reparte entre
+/// [`match_sequence`](ComboDetectionExt::match_sequence) (el orden) y
+/// [`match_sequence_in_time`](ComboDetectionExt::match_sequence_in_time) (el tiempo):
+/// cada paso indica qué teclas acepta y, opcionalmente, el tiempo máximo desde el
+/// paso anterior. Se construye una vez y se reutiliza cada frame.
+///
+/// ## Semántica
+/// - Los pasos coinciden con pulsaciones **consecutivas**
+///   ([`press_events`](HistoryAccessExt::press_events)), igual que en
+///   [`match_pattern`](ComboDetectionExt::match_pattern).
+/// - [`within`](Self::within) limita el tiempo entre el último paso añadido y el
+///   anterior; sobre el primer paso no tiene efecto.
+/// - Un matcher sin pasos nunca coincide.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// let hadouken = SequenceMatcher::new()
+///     .exact(Pad::Down)
+///     .any_of(&[Pad::DownRight, Pad::DownLeft])
+///     .within(Duration::from_millis(150))
+///     .exact(Pad::Right)
+///     .within(Duration::from_millis(150))
+///     .exact(Pad::Punch)
+///     .within(Duration::from_millis(200));
+///
+/// if history.matches(&hadouken) {
+///     player.hadouken();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceMatcher<K> {
+    steps: Vec<SequenceStep<K>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SequenceStep<K> {
+    keys: Vec<K>,
+    within: Option<Duration>,
+}
+
+impl<K: Copy + PartialEq> SequenceMatcher<K> {
+    /// Crea un matcher sin pasos.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Añade un paso que acepta exactamente `key`.
+    pub fn exact(mut self, key: K) -> Self {
+        self.steps.push(SequenceStep { keys: alloc::vec![key], within: None });
+        self
+    }
+
+    /// Añade un paso que acepta cualquiera de `keys`.
+    pub fn any_of(mut self, keys: &[K]) -> Self {
+        self.steps.push(SequenceStep { keys: keys.to_vec(), within: None });
+        self
+    }
+
+    /// Exige que el último paso añadido ocurra como máximo `window` después del
+    /// anterior. Sin pasos no tiene efecto.
+    pub fn within(mut self, window: Duration) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.within = Some(window);
+        }
+        self
+    }
+
+    /// Número de pasos del matcher.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Retorna `true` si el matcher no tiene pasos.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<K: Copy + PartialEq> Default for SequenceMatcher<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Qué eventos cuentan como **hueco** entre pasos en
+/// [`ComboDetectionExt::match_sequence_loose_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GapCounting {
+    /// Todo evento entre dos pasos cuenta, incluidas las liberaciones.
+    #[default]
+    AllEvents,
+    /// Solo las pulsaciones ([`StateSemantics::is_press`]) cuentan; el resto de
+    /// eventos se ignora por completo.
+    PressesOnly,
+}
+
+/// Retorna `true` si `pattern` se puede emparejar en `stream` empezando en `from`,
+/// con como máximo `max_gap` elementos sin emparejar antes de cada paso.
+fn loose_match_from<K: PartialEq>(stream: &[(K, bool)], pattern: &[K], from: usize, max_gap: usize) -> bool {
+    let Some((step, rest)) = pattern.split_first() else {
+        return true;
+    };
+    let end = from.saturating_add(max_gap).saturating_add(1).min(stream.len());
+
+    (from..end).any(|index| {
+        let (key, down) = &stream[index];
+        *down && key == step && loose_match_from(stream, rest, index + 1, max_gap)
+    })
+}
+
+/// Retorna `true` si `pattern` aparece como elementos **consecutivos** de `stream` y
+/// el primero y el último están separados como máximo por `window`.
+fn timed_match<K: PartialEq, Tm: Copy>(
+    stream: &[(K, Tm)],
+    pattern: &[K],
+    window: Duration,
+    elapsed: impl Fn(Tm, Tm) -> Duration,
+) -> bool {
+    if pattern.is_empty() {
+        return false;
+    }
+
+    stream.windows(pattern.len()).any(|candidate| {
+        let keys_match = candidate.iter().zip(pattern).all(|((key, _), expected)| key == expected);
+        let first = candidate[0].1;
+        let last = candidate[candidate.len() - 1].1;
+        keys_match && elapsed(first, last) <= window
+    })
+}
+
+/// Evento aplanado para [`chord_sequence_from`]: tecla, si es pulsación, si es
+/// liberación y momento relativo al primer evento.
+type ChordStep<K> = (K, bool, bool, Duration);
+
+/// Retorna `true` si `steps` se puede completar a partir de `stream[from..]`, con el
+/// paso anterior presionado en `previous_at` y las teclas de `held` aún mantenidas.
+fn chord_sequence_from<K: PartialEq + Copy>(
+    stream: &[ChordStep<K>],
+    steps: &[(K, Duration)],
+    from: usize,
+    previous_at: Duration,
+    held: &mut Vec<K>,
+) -> bool {
+    let Some(((step, window), rest)) = steps.split_first() else {
+        return true;
+    };
+
+    for (index, (key, press, release, at)) in stream.iter().enumerate().skip(from) {
+        if at.saturating_sub(previous_at) > *window {
+            break;
+        }
+        if *release && held.contains(key) {
+            return false;
+        }
+        if *press && key == step {
+            held.push(*key);
+            let matched = chord_sequence_from(stream, rest, index + 1, *at, held);
+            held.pop();
+            if matched {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Intervalos entre marcas de tiempo consecutivas de `times`.
+fn intervals_between<Tm: Copy>(times: impl Iterator<Item = Tm>, elapsed: impl Fn(Tm, Tm) -> Duration) -> Vec<Duration> {
+    let mut previous = None;
+    times
+        .filter_map(|at| {
+            let interval = previous.map(|before| elapsed(before, at));
+            previous = Some(at);
+            interval
+        })
+        .collect()
+}
+
+/// Sufijo de `history` con los eventos cuya antigüedad no supera `window`, siendo el
+/// momento actual `idle` después del último evento.
+fn recent_events<Tm: Copy, E: InputEvent<Time = Tm>>(
+    history: &[E],
+    window: Duration,
+    idle: Duration,
+    elapsed: impl Fn(Tm, Tm) -> Duration,
+) -> &[E] {
+    let Some(last_at) = history.last().map(|event| event.timestamp()) else {
+        return history;
+    };
+    let recent = history
+        .iter()
+        .rev()
+        .take_while(|event| elapsed(event.timestamp(), last_at).saturating_add(idle) <= window)
+        .count();
+    &history[history.len() - recent..]
+}
+
+/// Cuenta las pulsaciones de cada tecla de `presses` (en orden cronológico), de
+/// mayor a menor; los empates los gana la tecla presionada más tarde.
+fn press_counts<K: PartialEq + Copy>(presses: impl Iterator<Item = K>) -> Vec<(K, usize)> {
+    // (tecla, pulsaciones, índice de la última pulsación)
+    let mut counts: Vec<(K, usize, usize)> = Vec::new();
+    for (index, key) in presses.enumerate() {
+        match counts.iter_mut().find(|(counted, _, _)| *counted == key) {
+            Some(entry) => {
+                entry.1 += 1;
+                entry.2 = index;
+            }
+            None => counts.push((key, 1, index)),
+        }
+    }
+
+    counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+    counts.into_iter().map(|(key, presses, _)| (key, presses)).collect()
+}
+
+/// Tiempo transcurrido desde el primer evento de `history` hasta el momento actual,
+/// que está `idle` después del último. Cero si el historial está vacío.
+fn history_span<Tm: Copy, E: InputEvent<Time = Tm>>(
+    history: &[E],
+    idle: Duration,
+    elapsed: impl Fn(Tm, Tm) -> Duration,
+) -> Duration {
+    match (history.first(), history.last()) {
+        (Some(first), Some(last)) => elapsed(first.timestamp(), last.timestamp()).saturating_add(idle),
+        _ => Duration::ZERO,
+    }
+}
+
+/// Aplica `events` sobre `target` en orden, esperando antes de cada uno su intervalo
+/// desde el anterior dividido por `speed`.
+///
+/// Con `speed` no positivo o no finito, o si el intervalo escalado no cabe en un
+/// [`Duration`], el evento se aplica de inmediato: nunca se bloquea indefinidamente.
+#[cfg(feature = "std")]
+pub(crate) fn replay_timed<K, S, I>(events: impl IntoIterator<Item = (Duration, K, S)>, target: &mut I, speed: f32)
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    I: InputStateExt<K, S> + ?Sized,
+{
+    let realtime = speed.is_finite() && speed > 0.0;
+
+    for (gap, key, state) in events {
+        if realtime && !gap.is_zero() {
+            if let Ok(wait) = Duration::try_from_secs_f64(gap.as_secs_f64() / f64::from(speed)) {
+                std::thread::sleep(wait);
+            }
+        }
+        target.set_key(key, state);
+    }
+}
+
+/// Evento del historial en formato **portable**, producido por
+/// [`HistoryAccessExt::export_history`] y consumido por
+/// [`HistoryAccessExt::import_history`].
+///
+/// Es una estructura plana, pensada para escribirse a disco con el formato que elija
+/// el sistema de replays. La marca de tiempo es **relativa** al primer evento
+/// exportado, por lo que no depende de la época de `Instant` de cada máquina.
+///
+/// Con la feature `serde` implementa `Serialize`/`Deserialize` (si `K` y `S` lo
+/// implementan). `Instant` no se puede serializar: por eso el tiempo viaja como un
+/// desplazamiento en microsegundos y no como la marca original del evento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryRecord<K, S> {
+    /// Tecla del evento.
+    pub key: K,
+    /// Estado de la tecla en el evento.
+    pub state: S,
+    /// Microsegundos transcurridos desde el primer evento exportado.
+    pub offset_micros: u64,
+}
+
+/// Forma **serializable** de cualquier [`InputEvent`], obtenida con
+/// [`InputEvent::to_serializable`].
+///
+/// Es el mismo formato plano que [`HistoryRecord`]: tecla, estado y desplazamiento
+/// en microsegundos respecto a un origen.
+pub type SerializableEvent<K, S> = HistoryRecord<K, S>;
+
+/// Combo con **prioridad** para resolver coincidencias simultáneas con
+/// [`ComboDetectionExt::best_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrioritizedCombo<K> {
+    /// Secuencia de teclas del combo.
+    pub keys: Vec<K>,
+    /// Prioridad del combo; mayor valor gana.
+    pub priority: u32,
+}
+
+impl<K> PrioritizedCombo<K> {
+    /// Crea un combo con la secuencia y prioridad dadas.
+    pub fn new(keys: Vec<K>, priority: u32) -> Self {
+        Self { keys, priority }
+    }
+}
+
+/// Estimación del consumo de memoria del historial, retornada por
+/// [`HistoryAccessExt::memory_footprint`].
+///
+/// Ambos valores están en bytes y solo cuentan el almacenamiento de los eventos en sí
+/// (`size_of::<T>()` por evento), no memoria en el heap a la que apunten los eventos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HistoryFootprint {
+    /// Bytes ocupados por los eventos almacenados (`len * size_of::<T>()`).
+    pub logical: usize,
+    /// Bytes reservados por el almacenamiento subyacente (`capacity * size_of::<T>()`).
+    ///
+    /// Siempre mayor o igual que [`logical`](Self::logical).
+    pub allocated: usize,
 }
 
 /// # Trait `InputStateExt`
@@ -79,7 +909,43 @@ pub trait InputEvent: Hash + PartialEq + Clone {
 ///
 /// ## Parámetros genéricos
 /// - `K`: Tipo de tecla (por ejemplo, [`KeyCode`](crate::keyboard::KeyCode)).
-/// - `S`: Tipo de estado (por ejemplo, [`KeyState`](crate::keyboard::KeyState)).
+/// - `S`: Tipo de estado (por ejemplo, [`KeyState`](crate::keyboard::KeyState)). Debe
+///   implementar [`StateSemantics`], que las implementaciones por defecto usan para
+///   clasificar cada estado como presionado o liberado.
+///
+/// ## Métodos requeridos
+/// Todas las consultas derivadas ([`state_of`](Self::state_of),
+/// [`pressed_iter`](Self::pressed_iter), [`any_pressed`](Self::any_pressed),
+/// [`last_pressed`](Self::last_pressed), [`active_combo`](Self::active_combo)...)
+/// tienen una implementación por defecto, que cada backend puede sobrescribir si
+/// dispone de una estructura más eficiente. Solo son obligatorios los elementos que
+/// leen o escriben **almacenamiento propio del backend**, que el trait no puede ver:
+///
+/// - [`set_key`](Self::set_key) y [`state_iter`](Self::state_iter): escribir y
+///   enumerar el estado de cada tecla. Son las primitivas sobre las que se
+///   construye el resto.
+/// - [`advance_frame`](Self::advance_frame), [`is_just_press`](Self::is_just_press)
+///   e [`is_just_released`](Self::is_just_released): los flancos pendientes no se
+///   pueden deducir del estado actual (una tecla presionada y soltada en el mismo
+///   frame termina liberada, pero con ambos flancos).
+/// - [`set_tick`](Self::set_tick) y [`changed_since`](Self::changed_since):
+///   necesitan el tick del último cambio de cada tecla, que el estado actual no
+///   conserva.
+/// - [`suppress_repeat`](Self::suppress_repeat): la ventana se guarda por tecla; un
+///   valor por defecto que la ignorara aceptaría la configuración sin aplicarla.
+/// - [`time_pressed`](Self::time_pressed): necesita el instante de cada pulsación
+///   y una fuente de tiempo (ver [`FrameTimeExt`](crate::FrameTimeExt)).
+/// - [`held_stable`](Self::held_stable): necesita el contador de límites de frame
+///   por tecla descrito en el método. El historial no sirve de sustituto: este
+///   trait no tiene acceso a él, y sus marcas de tiempo no dicen dónde cayó cada
+///   `advance_frame`.
+/// - [`reset`](Self::reset) y [`reset_key`](Self::reset_key): liberan **sin**
+///   flancos y **sin** registrar en el historial. Un valor por defecto solo podría
+///   pasar por [`set_key`](Self::set_key), que genera ambos.
+/// - [`Snapshot`](Self::Snapshot), [`snapshot`](Self::snapshot) y
+///   [`restore`](Self::restore): el snapshot debe incluir flancos, ticks y
+///   temporizadores, que viven en el backend; además, los tipos asociados no
+///   admiten valor por defecto en Rust estable.
 ///
 /// ## Ejemplo de uso
 /// ```rust,ignore
@@ -99,33 +965,184 @@ pub trait InputEvent: Hash + PartialEq + Clone {
 pub trait InputStateExt<K, S>
 where
     K: Copy + PartialEq + Hash,
-    S: Copy + PartialEq,
+    S: StateSemantics,
 {
+    /// Copia **completa** del estado vivo, producida por [`snapshot`](Self::snapshot)
+    /// y aplicada con [`restore`](Self::restore).
+    ///
+    /// Cada implementación elige su representación; normalmente un clon de sus
+    /// estructuras internas.
+    type Snapshot: Clone;
+
     /// Establece o actualiza el estado de una tecla.
     ///
     /// Normalmente llamado por el runtime cuando detecta un evento de entrada.
+    /// Además del estado, **registra la transición**: si la tecla pasa de liberada a
+    /// presionada (o al revés), queda un flanco pendiente que reportan
+    /// [`is_just_press`](Self::is_just_press) / [`is_just_released`](Self::is_just_released)
+    /// hasta el siguiente [`advance_frame`](Self::advance_frame).
     fn set_key(&mut self, key: K, state: S);
 
+    /// Aplica **varias** actualizaciones de una vez, por ejemplo todas las de un
+    /// mismo reporte del sistema operativo.
+    ///
+    /// Equivale a llamar a [`set_key`](Self::set_key) con cada par, **en el orden
+    /// del slice**: el historial registra los eventos en ese orden y una misma tecla
+    /// puede aparecer varias veces (presionar y soltar en el mismo reporte deja ambos
+    /// flancos, como con llamadas sueltas).
+    ///
+    /// Por defecto hace exactamente eso. Las implementaciones con estado compartido
+    /// pueden sobrescribirlo para adquirir el lock una sola vez y añadir todos los
+    /// eventos al historial de forma atómica.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let updates: Vec<_> = report.keys().map(|(code, down)| (code, KeyState::from(down))).collect();
+    /// input.set_keys(&updates);
+    /// ```
+    fn set_keys(&mut self, updates: &[(K, S)]) {
+        for &(key, state) in updates {
+            self.set_key(key, state);
+        }
+    }
+
+    /// Marca el **fin de un frame**: consume los flancos registrados por
+    /// [`set_key`](Self::set_key).
+    ///
+    /// El game loop debe llamarlo exactamente una vez por frame, después de que todos
+    /// los sistemas hayan consultado el input. Tras la llamada:
+    /// - Las teclas recién presionadas pasan a **mantenidas**: siguen en
+    ///   [`is_pressed`](Self::is_pressed), pero [`is_just_press`](Self::is_just_press)
+    ///   retorna `false`.
+    /// - Las teclas recién liberadas pasan a **liberadas**:
+    ///   [`is_just_released`](Self::is_just_released) retorna `false`.
+    ///
+    /// No modifica qué teclas están presionadas. Si una tecla se presiona y se suelta
+    /// dentro del mismo frame, ambos flancos son visibles hasta la llamada.
+    ///
+    /// Con el modelo de ticks (ver [`set_tick`](Self::set_tick)), equivale a avanzar
+    /// el tick en uno.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// loop {
+    ///     runtime.update();
+    ///     game.update(&input);   // consulta is_just_press, is_pressed...
+    ///     input.advance_frame();  // los flancos de este frame se consumen
+    /// }
+    /// ```
+    fn advance_frame(&mut self);
+
     /// Retorna `true` si la tecla fue presionada **por primera vez** en este frame.
     ///
     /// Se diferencia de [`is_pressed`](Self::is_pressed) en que solo retorna `true`
     /// en el frame exacto donde ocurrió la pulsación, no mientras se mantiene presionada.
+    ///
+    /// "Este frame" significa **desde el último [`advance_frame`](Self::advance_frame)**.
+    /// Con el modelo de ticks (ver [`set_tick`](Self::set_tick)), significa **en o
+    /// después del último tick observado**.
     fn is_just_press(&self, key: K) -> bool;
 
+    /// Establece el **tick de cambio** actual del estado.
+    ///
+    /// Modelo alternativo a los flags por frame, inspirado en la detección de cambios
+    /// de motores ECS (como Bevy): el estado registra el tick en el que cada tecla
+    /// cambió por última vez, y cada observador compara contra el último tick que vio
+    /// mediante [`changed_since`](Self::changed_since). Así, sistemas que se ejecutan a
+    /// distinta frecuencia obtienen cada uno su flanco exactamente una vez.
+    ///
+    /// ## Modelo de ticks
+    /// - El tick es un contador **monótono**; valores menores al actual se ignoran.
+    /// - Un `set_key` que pasa la tecla de liberada a presionada (o al revés) la marca
+    ///   como cambiada en el tick actual; uno que no altera
+    ///   [`is_pressed`](Self::is_pressed) no cuenta como cambio.
+    /// - [`is_just_press`](Self::is_just_press) equivale a "presionada en o después del
+    ///   último tick establecido".
+    ///
+    /// ## Compatibilidad con el modelo por frame
+    /// Incrementar el tick una vez por frame reproduce exactamente la semántica
+    /// clásica de flancos por frame de [`advance_frame`](Self::advance_frame); las implementaciones que no llamen a este método
+    /// siguen funcionando como hasta ahora.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// input.set_tick(world.change_tick());
+    /// ```
+    fn set_tick(&mut self, tick: u64);
+
+    /// Retorna `true` si la tecla cambió de estado **en o después** de `tick`.
+    ///
+    /// Cada observador guarda el primer tick que aún no procesó y consulta con él, de
+    /// modo que ve cada cambio una sola vez independientemente de su frecuencia.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if input.changed_since(KeyCode::Space, self.next_unseen_tick) && input.is_pressed(KeyCode::Space) {
+    ///     jump();
+    /// }
+    /// // Los cambios de este tick ya se procesaron
+    /// self.next_unseen_tick = current_tick + 1;
+    /// ```
+    fn changed_since(&self, key: K, tick: u64) -> bool;
+
+    /// Limita la frecuencia de flancos de pulsación de una tecla.
+    ///
+    /// Tras cada flanco aceptado, los flancos siguientes de `key` que ocurran dentro
+    /// de `window` hacen que [`is_just_press`](Self::is_just_press) retorne `false`.
+    /// Resuelve los "dobles disparos" en los que una misma pulsación activa lógica
+    /// por varias vías.
+    ///
+    /// Es un filtro sobre el **resultado de la consulta**, no sobre la entrada:
+    /// - A diferencia de un *debounce* físico, el evento sí se registra: `is_pressed`,
+    ///   los temporizadores y el historial lo ven normalmente.
+    /// - No consume ni altera combos del historial; solo afecta a `is_just_press`.
+    ///
+    /// La configuración es persistente por tecla; `window == Duration::ZERO` la desactiva.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Como mucho un disparo cada 250ms, aunque el jugador pulse más rápido
+    /// input.suppress_repeat(KeyCode::Space, Duration::from_millis(250));
+    /// ```
+    fn suppress_repeat(&mut self, key: K, window: Duration);
+
+    /// Retorna el **estado crudo** `S` almacenado para la tecla con
+    /// [`set_key`](Self::set_key), o `None` si nunca se estableció.
+    ///
+    /// Permite leer estados más ricos que presionada/liberada (por ejemplo,
+    /// distinguir `Pressed` de `Active` en un modelo de tres estados).
+    ///
+    /// Por defecto busca la tecla en [`state_iter`](Self::state_iter), por lo que
+    /// también retorna `None` para teclas que la implementación ya dejó de rastrear.
+    fn state_of(&self, key: K) -> Option<S> {
+        self.state_iter()
+            .find(|(tracked, _)| *tracked == key)
+            .map(|(_, state)| state)
+    }
+
     /// Retorna `true` si la tecla está actualmente **presionada o mantenida**.
     ///
     /// Retorna `true` tanto en el frame inicial como en todos los frames subsiguientes
     /// mientras la tecla siga presionada.
-    fn is_pressed(&self, key: K) -> bool;
+    ///
+    /// Por defecto clasifica [`state_of`](Self::state_of) con
+    /// [`StateSemantics::is_down`]; una tecla sin estado cuenta como liberada.
+    fn is_pressed(&self, key: K) -> bool {
+        self.state_of(key).is_some_and(|state| state.is_down())
+    }
 
     /// Retorna `true` si la tecla está **completamente liberada**.
     ///
-    /// Es el estado opuesto a [`is_pressed`](Self::is_pressed).
-    fn is_released(&self, key: K) -> bool;
+    /// Es el estado opuesto a [`is_pressed`](Self::is_pressed), y así se implementa
+    /// por defecto.
+    fn is_released(&self, key: K) -> bool {
+        !self.is_pressed(key)
+    }
 
     /// Retorna `true` si la tecla fue **liberada en este frame específico**.
     ///
-    /// Similar a [`is_just_press`](Self::is_just_press) pero para el evento de liberación.
+    /// Similar a [`is_just_press`](Self::is_just_press) pero para el evento de liberación;
+    /// el flanco también se consume con [`advance_frame`](Self::advance_frame).
     fn is_just_released(&self, key: K) -> bool;
 
     /// Retorna el tiempo total que una tecla ha estado presionada.
@@ -133,94 +1150,532 @@ where
     /// Útil para detectar pulsaciones largas (hold) o cargar acciones.
     fn time_pressed(&self, key: K) -> Option<Duration>;
 
-    /// Verifica si una combinación de teclas se encuentra activa (todas presionadas).
+    /// Retorna `true` si la tecla lleva presionada de forma continua **al menos**
+    /// `threshold`.
     ///
-    /// Útil para detectar combinaciones como `CTRL + S` o `SHIFT + A`.
+    /// Atajo sobre [`time_pressed`](Self::time_pressed) para pulsaciones largas.
     ///
     /// # Ejemplo
     /// ```rust,ignore
-    /// if input.active_combo(&[KeyCode::ControlLeft, KeyCode::C]) {
-    ///     copy_to_clipboard();
+    /// if input.held_for(KeyCode::E, Duration::from_millis(800)) {
+    ///     door.force_open();
     /// }
     /// ```
-    fn active_combo(&self, combo: &[K]) -> bool;
+    fn held_for(&self, key: K, threshold: Duration) -> bool {
+        self.time_pressed(key).is_some_and(|held| held >= threshold)
+    }
 
-    /// Retorna `true` si **cualquier tecla** se encuentra actualmente presionada.
+    /// Retorna el **nivel de carga** de la tecla en `0.0..=1.0`: el tiempo presionada
+    /// dividido por `full`, limitado a `1.0`.
     ///
-    /// Útil para detectar actividad general del usuario.
-    fn any_pressed(&self) -> bool;
-
-    /// Devuelve la última tecla presionada (si existe).
+    /// Pensado para ataques cargados o tensar un arco. Retorna `0.0` si la tecla no
+    /// está presionada, y `1.0` si `full` es cero y la tecla está presionada.
     ///
-    /// Útil para sistemas de rebinding de teclas o debug.
-    fn last_pressed(&self) -> Option<K>;
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let charge = input.held_ratio(KeyCode::MouseLeft, Duration::from_secs(2));
+    /// bow.set_draw(charge);
+    /// ```
+    fn held_ratio(&self, key: K, full: Duration) -> f32 {
+        match self.time_pressed(key) {
+            None => 0.0,
+            Some(_) if full.is_zero() => 1.0,
+            Some(held) => (held.as_secs_f32() / full.as_secs_f32()).min(1.0),
+        }
+    }
 
-    /// Retorna todas las teclas actualmente presionadas.
+    /// Retorna `true` si la tecla ha permanecido **presionada de forma continua**
+    /// durante al menos los últimos `frames` límites de frame.
     ///
-    /// Útil para visualizar el estado completo o debug.
-    fn keys_pressed(&self) -> Vec<K>;
-
-    /// Resetea el estado actual (por ejemplo, al cambiar de escena o al pausar el juego).
+    /// Es un *debounce* a nivel de frame, distinto de uno basado en tiempo: un
+    /// parpadeo de un solo frame (presionar y soltar entre dos límites) no cuenta
+    /// como pulsación estable. Útil para sistemas que se ejecutan a la mitad de la
+    /// frecuencia del input y necesitan ignorar glitches de un frame.
     ///
-    /// Limpia todos los estados internos sin afectar el historial (si existe).
-    fn reset(&mut self);
-
+    /// Con `frames == 0` equivale a [`is_pressed`](Self::is_pressed).
+    ///
+    /// # Requisitos de implementación
+    /// Cada implementación mantiene, por tecla, un **contador** de límites de frame
+    /// (llamadas a [`advance_frame`](Self::advance_frame)) cruzados con la tecla
+    /// presionada. Cada liberación lo pone a cero, aunque la tecla se vuelva a
+    /// presionar antes del siguiente límite; así un parpadeo dentro de un frame
+    /// también rompe la racha. `held_stable` compara el contador con `frames`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Solo considerar el sprint si Shift lleva 3 frames abajo
+    /// if input.held_stable(KeyCode::ShiftLeft, 3) {
+    ///     player.sprint();
+    /// }
+    /// ```
+    fn held_stable(&self, key: K, frames: usize) -> bool;
 
+    /// Verifica si una combinación de teclas se encuentra activa (todas presionadas).
+    ///
+    /// Útil para detectar combinaciones como `CTRL + S` o `SHIFT + A`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if input.active_combo(&[KeyCode::ControlLeft, KeyCode::C]) {
+    ///     copy_to_clipboard();
+    /// }
+    /// ```
+    fn active_combo(&self, combo: &[K]) -> bool {
+        combo.iter().all(|key| self.is_pressed(*key))
+    }
+
+    /// Verifica un **atajo** estilo `Ctrl+S`: todos los `modifiers` mantenidos y `key`
+    /// presionada **en este frame**.
+    ///
+    /// A diferencia de [`active_combo`](Self::active_combo), que es `true` en cada
+    /// frame mientras la combinación se mantiene (y repetiría el guardado frame tras
+    /// frame), este método solo dispara una vez por pulsación de `key`. Los
+    /// modificadores pueden llevar presionados cualquier tiempo; el orden solo importa
+    /// en que `key` debe ser la última en bajar.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if input.combo_with_modifiers(&[KeyCode::ControlLeft], KeyCode::S) {
+    ///     save_game();
+    /// }
+    /// ```
+    fn combo_with_modifiers(&self, modifiers: &[K], key: K) -> bool {
+        self.is_just_press(key) && self.is_all_pressed(modifiers)
+    }
+
+    /// Retorna `true` si **alguna** de las teclas de `keys` está presionada.
+    ///
+    /// Con un slice vacío retorna `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// const MOVEMENT: [KeyCode; 4] = [KeyCode::W, KeyCode::A, KeyCode::S, KeyCode::D];
+    ///
+    /// if input.is_any_pressed(&MOVEMENT) {
+    ///     player.set_animation(Animation::Walk);
+    /// }
+    /// ```
+    fn is_any_pressed(&self, keys: &[K]) -> bool {
+        keys.iter().any(|key| self.is_pressed(*key))
+    }
+
+    /// Retorna `true` si **todas** las teclas de `keys` están presionadas.
+    ///
+    /// Semánticamente idéntico a [`active_combo`](Self::active_combo); existe para
+    /// formar pareja con [`is_any_pressed`](Self::is_any_pressed) y expresar la
+    /// intención cuando no se trata de un atajo. Con un slice vacío retorna `true`.
+    fn is_all_pressed(&self, keys: &[K]) -> bool {
+        keys.iter().all(|key| self.is_pressed(*key))
+    }
+
+    /// Retorna `true` si `key` está presionada y **ningún** combo de `blocked_by` está
+    /// activo.
+    ///
+    /// Resuelve el clásico conflicto entre modificadores y teclas sueltas: si `Ctrl+A`
+    /// es un combo y `A` sola es otra acción, la acción de `A` no debe dispararse
+    /// mientras `Ctrl` está presionada.
+    ///
+    /// ## Lógica de supresión
+    /// - Un combo bloquea cuando **todas** sus teclas están presionadas
+    ///   ([`is_all_pressed`](Self::is_all_pressed)); basta con uno.
+    /// - Los combos tienen precedencia sobre la tecla suelta: no importa cuál se
+    ///   presionó primero.
+    /// - Los combos vacíos se ignoran (no bloquean nunca).
+    /// - Al soltar el modificador, la tecla vuelve a reportarse presionada si sigue abajo.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let ctrl_a: &[KeyCode] = &[KeyCode::ControlLeft, KeyCode::A];
+    ///
+    /// if input.active_combo(ctrl_a) {
+    ///     select_all();
+    /// } else if input.is_pressed_exclusive(KeyCode::A, &[ctrl_a]) {
+    ///     player.strafe_left();
+    /// }
+    /// ```
+    fn is_pressed_exclusive(&self, key: K, blocked_by: &[&[K]]) -> bool {
+        self.is_pressed(key)
+            && !blocked_by
+                .iter()
+                .any(|combo| !combo.is_empty() && self.is_all_pressed(combo))
+    }
+
+    /// Retorna `true` si **cualquier tecla** se encuentra actualmente presionada.
+    ///
+    /// Útil para detectar actividad general del usuario. Equivale a
+    /// `self.pressed_iter().next().is_some()`, que es la implementación por defecto.
+    fn any_pressed(&self) -> bool {
+        self.pressed_iter().next().is_some()
+    }
+
+    /// Retorna el **número** de teclas presionadas simultáneamente.
+    ///
+    /// Cuenta solo las teclas con [`is_pressed`](Self::is_pressed) en `true`: una
+    /// tecla soltada en este frame (con [`is_just_released`](Self::is_just_released)
+    /// aún visible) no cuenta. Por defecto cuenta
+    /// [`pressed_iter`](Self::pressed_iter), sin asignar memoria, a diferencia de
+    /// `keys_pressed().len()`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // "Machaca los dos botones"
+    /// if input.pressed_count() >= 2 {
+    ///     struggle.progress += 1;
+    /// }
+    /// ```
+    fn pressed_count(&self) -> usize {
+        self.pressed_iter().count()
+    }
+
+    /// Devuelve la última tecla presionada (si existe).
+    ///
+    /// Útil para sistemas de rebinding de teclas o debug.
+    ///
+    /// Por defecto retorna, entre las teclas de [`pressed_iter`](Self::pressed_iter),
+    /// la de menor [`time_pressed`](Self::time_pressed): la que se presionó más
+    /// tarde. Solo considera teclas **aún presionadas**; las implementaciones que
+    /// recuerdan la última pulsación después de soltarla deben sobrescribirlo.
+    fn last_pressed(&self) -> Option<K> {
+        self.pressed_iter()
+            .filter_map(|key| Some((key, self.time_pressed(key)?)))
+            .min_by_key(|(_, held)| *held)
+            .map(|(key, _)| key)
+    }
+
+    /// Retorna todas las teclas actualmente presionadas.
+    ///
+    /// Útil para visualizar el estado completo o debug. Asigna un `Vec` en cada
+    /// llamada; en bucles por frame o en targets embebidos conviene
+    /// [`pressed_iter`](Self::pressed_iter).
+    ///
+    /// Por defecto recolecta [`pressed_iter`](Self::pressed_iter).
+    fn keys_pressed(&self) -> Vec<K> {
+        self.pressed_iter().collect()
+    }
+
+    /// Itera las teclas actualmente presionadas **sin asignar memoria**.
+    ///
+    /// Por defecto filtra [`state_iter`](Self::state_iter) con
+    /// [`is_pressed`](Self::is_pressed). Las implementaciones con un conjunto propio de
+    /// teclas presionadas pueden sobrescribirlo para iterarlo directamente.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for key in input.pressed_iter() {
+    ///     overlay.highlight(key);
+    /// }
+    /// ```
+    fn pressed_iter(&self) -> impl Iterator<Item = K> + '_ {
+        self.pressed_state_iter().map(|(key, _)| key)
+    }
+
+    /// Retorna las teclas cuyo [`is_just_press`](Self::is_just_press) es `true` en
+    /// este frame.
+    ///
+    /// Útil para pantallas de rebinding: el primer elemento es la tecla a capturar.
+    /// Por defecto filtra [`pressed_iter`](Self::pressed_iter), sin orden garantizado.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if let Some(&key) = input.just_pressed_keys().first() {
+    ///     bindings.bind(pending_action, key);
+    /// }
+    /// ```
+    fn just_pressed_keys(&self) -> Vec<K> {
+        self.pressed_iter()
+            .filter(|key| self.is_just_press(*key))
+            .collect()
+    }
+
+    /// Retorna las teclas cuyo [`is_just_released`](Self::is_just_released) es `true`
+    /// en este frame.
+    ///
+    /// Por defecto filtra [`state_iter`](Self::state_iter), que debe seguir rastreando
+    /// las teclas liberadas al menos hasta el siguiente
+    /// [`advance_frame`](Self::advance_frame).
+    fn just_released_keys(&self) -> Vec<K> {
+        self.state_iter()
+            .map(|(key, _)| key)
+            .filter(|key| self.is_just_released(*key))
+            .collect()
+    }
+
+    /// Itera **todas las teclas rastreadas** junto a su estado crudo actual `S`.
+    ///
+    /// Incluye las teclas liberadas que el estado siga rastreando (por ejemplo, las
+    /// que se soltaron en este frame); que una tecla liberada aparezca o no depende de
+    /// cuánto tiempo la conserve cada implementación. Las teclas que nunca recibieron
+    /// un [`set_key`](Self::set_key) no aparecen.
+    ///
+    /// No asigna memoria: es la primitiva sobre la que se construyen
+    /// [`pressed_state_iter`](Self::pressed_state_iter) y [`debug_dump`](Self::debug_dump).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for (key, state) in input.state_iter() {
+    ///     overlay.draw_key(key, state);
+    /// }
+    /// ```
+    fn state_iter(&self) -> impl Iterator<Item = (K, S)> + '_;
+
+    /// Igual que [`state_iter`](Self::state_iter) pero solo para las teclas
+    /// actualmente presionadas ([`is_pressed`](Self::is_pressed)).
+    ///
+    /// Útil con modelos de tres estados, donde el llamador necesita distinguir una
+    /// tecla recién presionada de una mantenida.
+    fn pressed_state_iter(&self) -> impl Iterator<Item = (K, S)> + '_ {
+        self.state_iter().filter(|(key, _)| self.is_pressed(*key))
+    }
+
+    /// Genera un **resumen de una línea** del estado, listo para pegar en un reporte de bug.
+    ///
+    /// Incluye las teclas presionadas con su estado (y su tiempo de pulsación si se
+    /// conoce), [`any_pressed`](Self::any_pressed) y [`last_pressed`](Self::last_pressed),
+    /// usando la representación `Debug` de `K` y `S`:
+    ///
+    /// ```text
+    /// pressed=[W:Active(1.250s), ShiftLeft:Pressed(0.016s)] any_pressed=true last_pressed=Some(ShiftLeft)
+    /// ```
+    ///
+    /// Disponible con el feature `std`.
+    ///
+    /// **Nota:** el formato está pensado para lectura humana y **no es estable**;
+    /// puede cambiar entre versiones. No lo parsees.
+    #[cfg(feature = "std")]
+    fn debug_dump(&self) -> String
+    where
+        K: core::fmt::Debug,
+        S: core::fmt::Debug,
+    {
+        let pressed: Vec<String> = self
+            .pressed_state_iter()
+            .map(|(key, state)| match self.time_pressed(key) {
+                Some(held) => format!("{key:?}:{state:?}({:.3}s)", held.as_secs_f32()),
+                None => format!("{key:?}:{state:?}"),
+            })
+            .collect();
+
+        format!(
+            "pressed=[{}] any_pressed={} last_pressed={:?}",
+            pressed.join(", "),
+            self.any_pressed(),
+            self.last_pressed()
+        )
+    }
+
+    /// Crea una vista de este estado bajo **otro espacio de teclas** `K2`.
+    ///
+    /// `f` traduce cada tecla `K` a `Some(K2)`, o a `None` si la tecla no debe ser
+    /// visible en la vista. Es el bloque funcional detrás de adaptadores de input
+    /// unificado o híbrido (por ejemplo, presentar un estado de `KeyCode` como teclas
+    /// de acción del juego).
+    ///
+    /// La vista es **prestada y de solo lectura**: no copia el estado y refleja sus
+    /// cambios. Ver [`MappedState`] para la semántica exacta.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let actions = input.map_keys(|key| match key {
+    ///     KeyCode::Space | KeyCode::W => Some(Action::Jump),
+    ///     KeyCode::J => Some(Action::Attack),
+    ///     _ => None,
+    /// });
+    ///
+    /// if actions.is_just_press(Action::Jump) {
+    ///     player.jump();
+    /// }
+    /// ```
+    fn map_keys<K2, F>(&self, f: F) -> MappedState<'_, Self, K, S, F, K2>
+    where
+        Self: Sized,
+        F: Fn(K) -> Option<K2>,
+    {
+        MappedState { inner: self, map: f, _marker: PhantomData }
+    }
+
+    /// Resetea el estado actual (por ejemplo, al cambiar de escena o al pausar el juego).
+    ///
+    /// Limpia todos los estados internos sin afectar el historial (si existe).
+    fn reset(&mut self);
+
+    /// Fuerza **una sola tecla** al estado liberado, sin tocar las demás.
+    ///
+    /// Pensado para teclas "atascadas": tras un alt-tab o una reconexión, el sistema
+    /// operativo puede no entregar nunca el evento de liberación y una tecla de
+    /// movimiento queda lógicamente mantenida.
+    ///
+    /// Debe comportarse como si hubiera llegado una **liberación sintética** ya
+    /// consumida: la tecla deja de estar en [`is_pressed`](Self::is_pressed), su
+    /// temporizador de [`time_pressed`](Self::time_pressed) se descarta y se limpian
+    /// sus flancos, de modo que ni [`is_just_press`](Self::is_just_press) ni
+    /// [`is_just_released`](Self::is_just_released) la reportan. Al igual que
+    /// [`reset`](Self::reset), no afecta al historial.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// fn on_focus_lost(input: &mut impl InputStateExt<KeyCode, KeyState>) {
+    ///     for key in [KeyCode::W, KeyCode::A, KeyCode::S, KeyCode::D] {
+    ///         input.reset_key(key);
+    ///     }
+    /// }
+    /// ```
+    fn reset_key(&mut self, key: K);
+
+    /// Captura el estado vivo exacto en este punto, para **rollback netcode**
+    /// (estilo GGPO) o re-simulación determinista.
+    ///
+    /// El snapshot debe incluir todo lo que afecta a las consultas del trait: teclas
+    /// presionadas y sus estados, flancos pendientes de
+    /// [`is_just_press`](Self::is_just_press) / [`is_just_released`](Self::is_just_released),
+    /// temporizadores de [`time_pressed`](Self::time_pressed) y contadores de frame o
+    /// de tick. No incluye el historial (si existe).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// saved.insert(frame, input.snapshot());
+    ///
+    /// // Al recibir input remoto tardío:
+    /// input.restore(&saved[&confirmed_frame]);
+    /// resimulate_from(confirmed_frame);
+    /// ```
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restaura un estado capturado con [`snapshot`](Self::snapshot).
+    ///
+    /// Tras la llamada, todas las consultas deben responder exactamente igual que en
+    /// el momento de la captura, de modo que re-simular los mismos eventos produzca
+    /// los mismos resultados.
+    fn restore(&mut self, snapshot: &Self::Snapshot);
+
+    /// **Libera** todas las teclas mantenidas como si el usuario las hubiera soltado.
+    ///
+    /// A diferencia de [`reset`](Self::reset), que limpia el estado **en silencio**,
+    /// este método transiciona cada tecla presionada al estado liberado generando los
+    /// flancos correspondientes: tras la llamada, [`is_just_released`](Self::is_just_released)
+    /// retorna `true` para cada una de ellas en este frame, de modo que la lógica de juego
+    /// reacciona igual que ante una liberación real.
+    ///
+    /// Útil al iniciar una cinemática o abrir un menú modal.
+    ///
+    /// La implementación por defecto llama a [`set_key`](Self::set_key) con
+    /// [`StateSemantics::released`] para cada tecla de [`keys_pressed`](Self::keys_pressed).
+    /// Las implementaciones que soportan [`remap`](Self::remap) deben sobrescribirlo
+    /// para liberar las teclas almacenadas **sin** volver a remapearlas.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// fn start_cutscene(input: &mut impl InputStateExt<KeyCode, KeyState>) {
+    ///     input.release_all();
+    ///     // Los sistemas que escuchan is_just_released detienen sus acciones
+    /// }
+    /// ```
+    fn release_all(&mut self) {
+        for key in self.keys_pressed() {
+            self.set_key(key, S::released());
+        }
+    }
+
+    /// **Remapea** globalmente la tecla `from` a `to`: a partir de la llamada, cada
+    /// `set_key(from, state)` se almacena como `set_key(to, state)`.
+    ///
+    /// Pensado para accesibilidad (por ejemplo, juego con una sola mano). Actúa por
+    /// debajo de [`ActionMapExt`](crate::ActionMapExt): todas las consultas, los
+    /// flancos y el historial ven solo `to`, igual que si el backend hubiera emitido
+    /// esa tecla.
+    ///
+    /// ## Semántica
+    /// - Los remapeos **no se encadenan**: cada tecla entrante se resuelve una sola
+    ///   vez. Con `A → B` y `B → C`, `A` se almacena como `B` y `B` como `C`; así,
+    ///   `A → B` junto con `B → A` intercambia las dos teclas.
+    /// - Remapear de nuevo `from` reemplaza su destino; `remap(from, from)` lo anula.
+    /// - `to` sigue recibiendo su propio input: con `A → B`, tanto `A` como `B`
+    ///   llegan como `B`.
+    /// - No modifica el estado actual. Una tecla mantenida al remapear se soltará
+    ///   con el destino nuevo y quedará atascada, así que conviene remapear sin teclas
+    ///   presionadas (por ejemplo, tras [`release_all`](Self::release_all)).
+    ///
+    /// Retorna `true` si el remapeo quedó registrado. La implementación por defecto
+    /// no lo aplica y retorna `false`, para que el llamador sepa que el backend no
+    /// soporta remapeos (por ejemplo, para ocultar la opción en el menú) en lugar de
+    /// asumir que funcionan.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Modo zurdo: las flechas hacen de WASD
+    /// input.release_all();
+    /// let supported = input.remap(KeyCode::ArrowUp, KeyCode::W)
+    ///     && input.remap(KeyCode::ArrowLeft, KeyCode::A)
+    ///     && input.remap(KeyCode::ArrowDown, KeyCode::S)
+    ///     && input.remap(KeyCode::ArrowRight, KeyCode::D);
+    /// settings.left_handed.set_available(supported);
+    /// ```
+    fn remap(&mut self, from: K, to: K) -> bool {
+        let _ = (from, to);
+        false
+    }
+
+    /// Elimina todos los remapeos registrados con [`remap`](Self::remap).
+    ///
+    /// Como `remap`, no modifica el estado actual. Retorna `true` si el backend
+    /// soporta remapeos; la implementación por defecto retorna `false`.
+    fn clear_remaps(&mut self) -> bool {
+        false
+    }
 }
 
-/// # Trait `WithHistoryExt`
-///
-/// Extiende [`InputStateExt`] añadiendo un **sistema de historial de eventos**.
+/// # Trait `HistoryAccessExt`
 ///
-/// Este trait permite registrar, consultar y analizar los eventos de entrada
-/// a lo largo del tiempo — ideal para detectar *combos*, *doble taps*, *secuencias*
-/// o simplemente para fines de depuración o replays.
+/// **Acceso base** al historial de eventos: el único trait de la familia de
+/// [`WithHistoryExt`] que un backend implementa a mano.
 ///
-/// ## Concepto
-/// - Mientras [`InputStateExt`] maneja el estado **presente**,
-///   `WithHistoryExt` maneja el **pasado reciente**.
-/// - Cada evento registrado (`T`) representa una acción individual del usuario
-///   con su respectivo timestamp, permitiendo análisis temporal sofisticado.
+/// Agrupa el almacenamiento (lectura, recorte, importación y deshacer), el momento
+/// actual ([`since_last_event`](Self::since_last_event)) y las utilidades que operan
+/// directamente sobre los eventos (exportación, replay y reconstrucción del estado).
+/// [`TemporalQueryExt`], [`ComboDetectionExt`] y [`HistoryStatsExt`] se implementan
+/// automáticamente para todo tipo que implemente este trait.
 ///
-/// ## Parámetros genéricos
-/// - `K`: Tipo de tecla o código de entrada (por ejemplo, `KeyCode`).
-/// - `S`: Estado asociado a la tecla (`KeyState`).
-/// - `T`: Tipo de evento histórico que implementa [`InputEvent`].
+/// ## Métodos requeridos
+/// Además del [`Clock`](Self::Clock), solo son obligatorias las primitivas que
+/// acceden al almacenamiento: [`history`](Self::history), el momento actual
+/// ([`since_last_event`](Self::since_last_event)) y las operaciones que modifican el
+/// historial ([`clear_history`](Self::clear_history),
+/// [`trim_history`](Self::trim_history), [`import_history`](Self::import_history),
+/// [`dedup_consecutive`](Self::dedup_consecutive), [`undo_last`](Self::undo_last) y
+/// [`undo_last_and_revert`](Self::undo_last_and_revert)). `dedup_consecutive` no
+/// puede construirse sobre las demás: quitar eventos intermedios con ellas obliga a
+/// reimportar el historial, y [`import_history`](Self::import_history) reubica las
+/// marcas de tiempo.
 ///
-/// ## Ejemplo
+/// ## Ejemplo de implementación
 /// ```rust,ignore
-/// use orbit_input_core::keyboard::{KeyCode, KeyState};
-/// use orbit_input_core::traits::WithHistoryExt;
-/// use std::time::Duration;
+/// impl HistoryAccessExt<KeyCode, KeyState, KeyEvent> for MyInput {
+///     type Clock = StdClock;
 ///
-/// fn check_combo<H>(history: &H)
-/// where
-///     H: WithHistoryExt<KeyCode, KeyState, KeyEvent>
-/// {
-///     // Detectar combo clásico de Konami Code
-///     let konami = [
-///         KeyCode::ArrowUp, KeyCode::ArrowUp,
-///         KeyCode::ArrowDown, KeyCode::ArrowDown,
-///         KeyCode::ArrowLeft, KeyCode::ArrowRight,
-///     ];
-///     
-///     if history.match_sequence_in_time(&konami, Duration::from_secs(5)) {
-///         println!("¡Konami Code activado!");
-///         unlock_secret_level();
+///     fn history(&self) -> &[KeyEvent] {
+///         &self.events
 ///     }
-///     
-///     // Detectar doble tap para dash
-///     if history.is_double_tap(KeyCode::ShiftLeft, Duration::from_millis(300)) {
-///         println!("¡Dash activado!");
+///
+///     fn since_last_event(&self) -> Option<Duration> {
+///         self.events.last().map(|event| event.timestamp().elapsed())
 ///     }
+///
+///     // clear_history, trim_history, import_history, dedup_consecutive, undo_last...
 /// }
+///
+/// // Las consultas temporales, los combos y las estadísticas ya están disponibles:
+/// let dash = input.is_double_tap(KeyCode::ShiftLeft, Duration::from_millis(300));
 /// ```
-pub trait WithHistoryExt<K, S, T>: InputStateExt<K, S>
+pub trait HistoryAccessExt<K, S, T>: InputStateExt<K, S>
 where
     K: Copy + PartialEq + Hash,
-    S: Copy + PartialEq,
+    S: StateSemantics,
     T: InputEvent<Key = K, State = S>,
 {
+    /// Reloj que mide los intervalos entre las marcas de tiempo de los eventos.
+    ///
+    /// Con `std`, [`StdClock`](crate::StdClock) para eventos con `Time = Instant`.
+    type Clock: Clock<Timestamp = T::Time>;
+
     // === ACCESO BASE ===
 
     /// Devuelve todos los eventos registrados en el historial.
@@ -229,7 +1684,28 @@ where
     fn history(&self) -> &[T];
 
     /// Devuelve el último evento registrado (más reciente).
-    fn last_event(&self) -> Option<&T>;
+    fn last_event(&self) -> Option<&T> {
+        self.history().last()
+    }
+
+    /// Itera solo los eventos de **pulsación** del historial, del más antiguo al más
+    /// reciente, según [`StateSemantics::is_press`].
+    ///
+    /// Descarta liberaciones y reportes de tecla mantenida, que son la causa habitual
+    /// de combos que "se rompen" por un evento de soltar intercalado. Es el flujo sobre
+    /// el que operan [`match_sequence`](ComboDetectionExt::match_sequence) y
+    /// [`match_pattern`](ComboDetectionExt::match_pattern).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let recent: Vec<KeyCode> = history.press_events().map(|e| e.key()).collect();
+    /// ```
+    fn press_events<'a>(&'a self) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+    {
+        self.history().iter().filter(|event| event.is_press())
+    }
 
     /// Limpia por completo el historial de eventos.
     ///
@@ -246,135 +1722,158 @@ where
     /// ```
     fn trim_history(&mut self, max: usize);
 
-    // === CONSULTAS TEMPORALES ===
-
-    /// Devuelve el tiempo transcurrido desde el último evento registrado.
-    ///
-    /// Útil para detectar inactividad del usuario.
-    fn since_last_event(&self) -> Duration;
-
-    /// Devuelve el tiempo desde la última vez que se presionó una tecla específica.
+    /// Elimina todos los eventos con **más de `age` de antigüedad**, conservando el
+    /// resto.
     ///
-    /// Retorna `None` si la tecla nunca fue presionada.
-    fn since_key_pressed(&self, key: K) -> Option<std::time::Duration>;
-
-    /// Devuelve la diferencia temporal entre los dos últimos eventos consecutivos de la misma tecla.
+    /// Complementa a [`trim_history`](Self::trim_history), que recorta por cantidad, y
+    /// combina con [`keys_in_last`](TemporalQueryExt::keys_in_last) para análisis sobre los
+    /// últimos segundos (por ejemplo, en juegos de ritmo). Un evento con antigüedad
+    /// exactamente igual a `age` se conserva.
     ///
-    /// Útil para medir velocidad de tapping o intervalos de pulsación.
-    fn delta_between(&self, key: K) -> Option<std::time::Duration>;
-
-    /// Verifica si una tecla fue presionada dos veces dentro de un intervalo determinado (doble tap).
+    /// ## Cálculo de "ahora"
+    /// No requiere leer un reloj: la antigüedad de cada evento se calcula como su
+    /// distancia al último evento ([`Clock::duration_between`]) más
+    /// [`since_last_event`](Self::since_last_event). Así funciona igual con relojes de
+    /// ticks sin `std`, y como `duration_between` satura, nunca se resta por debajo
+    /// de cero: marcas de tiempo desordenadas o un contador reiniciado hacen que los
+    /// eventos afectados cuenten como recientes y se conserven, en lugar de fallar.
     ///
     /// # Ejemplo
     /// ```rust,ignore
-    /// // Detectar doble clic en menos de 300ms
-    /// if history.is_double_tap(KeyCode::Space, std::time::Duration::from_millis(300)) {
-    ///     perform_double_jump();
-    /// }
+    /// // Analizar solo los últimos 4 segundos de la canción
+    /// history.trim_older_than(Duration::from_secs(4));
     /// ```
-    fn is_double_tap(&self, key: K, threshold: std::time::Duration) -> bool;
+    fn trim_older_than(&mut self, age: Duration) {
+        let history = self.history();
+        let Some(last_at) = history.last().map(|event| event.timestamp()) else {
+            return;
+        };
+        let idle = self.since_last_event().unwrap_or_default();
 
-    /// Calcula el promedio de tiempo entre pulsaciones consecutivas de una tecla.
-    ///
-    /// Útil para análisis de ritmo o detección de patrones de entrada.
-    fn average_press_interval(&self, key: K) -> Option<std::time::Duration>;
+        let expired = history
+            .iter()
+            .take_while(|event| {
+                Self::Clock::duration_between(event.timestamp(), last_at).saturating_add(idle) > age
+            })
+            .count();
 
-    // === DETECCIÓN DE COMBOS Y SECUENCIAS ===
+        if expired > 0 {
+            let keep = history.len() - expired;
+            self.trim_history(keep);
+        }
+    }
 
-    /// Verifica si una secuencia específica de teclas ocurrió en el orden indicado.
+    /// Colapsa cada racha de eventos **consecutivos idénticos** (misma tecla y mismo
+    /// estado) en un solo evento, conservando el primero, es decir, el de marca de
+    /// tiempo más temprana.
     ///
-    /// No considera el tiempo entre eventos, solo el orden.
+    /// Algunos backends emiten eventos duplicados (dos `Pressed` seguidos de la misma
+    /// tecla), y un interruptor con rebote produce lo mismo. Sin limpiarlos,
+    /// [`total_presses`](HistoryStatsExt::total_presses) cuenta de más y los combos
+    /// ven pasos repetidos. Ver [`has_duplicates`](Self::has_duplicates) para
+    /// detectarlos sin modificar nada.
+    ///
+    /// Los modelos que registran reportes repetidos de tecla mantenida (por ejemplo,
+    /// varios `Active` seguidos) también los verán colapsados.
+    ///
+    /// Los eventos que quedan conservan sus marcas de tiempo originales. Con los
+    /// eventos en un `Vec`:
     ///
-    /// # Ejemplo
     /// ```rust,ignore
-    /// // Detectar secuencia clásica: arriba, arriba, abajo, abajo
-    /// let pattern = [KeyCode::ArrowUp, KeyCode::ArrowUp, 
-    ///                KeyCode::ArrowDown, KeyCode::ArrowDown];
-    /// if history.match_sequence(&pattern) {
-    ///     println!("Secuencia detectada!");
+    /// fn dedup_consecutive(&mut self) {
+    ///     self.events.dedup_by(|later, earlier| {
+    ///         later.key() == earlier.key() && later.state() == earlier.state()
+    ///     });
     /// }
     /// ```
-    fn match_sequence(&self, pattern: &[K]) -> bool;
+    fn dedup_consecutive(&mut self);
 
-    /// Verifica si una secuencia de teclas ocurrió dentro de un margen temporal determinado.
+    /// Retorna una **estimación** de la memoria usada por los eventos del historial.
     ///
-    /// Útil para combos rápidos o inputs en cadena que requieren timing preciso.
+    /// Pensado para monitorear sesiones largas, alimentar políticas de retención por
+    /// tamaño y overlays de depuración. Ver [`HistoryFootprint`] para qué incluye cada
+    /// valor.
+    ///
+    /// La implementación por defecto solo conoce [`history`](Self::history), así que
+    /// reporta `allocated == logical`. Las implementaciones respaldadas por un `Vec`
+    /// o `VecDeque` deberían sobrescribirla para reportar su capacidad real.
     ///
     /// # Ejemplo
     /// ```rust,ignore
-    /// // La secuencia debe completarse en menos de 2 segundos
-    /// if history.match_sequence_in_time(&combo, Duration::from_secs(2)) {
-    ///     activate_special_move();
-    /// }
+    /// let footprint = history.memory_footprint();
+    /// overlay.text(format!("historial: {} / {} bytes", footprint.logical, footprint.allocated));
     /// ```
-    fn match_sequence_in_time(&self, pattern: &[K], window: std::time::Duration) -> bool;
+    fn memory_footprint(&self) -> HistoryFootprint {
+        let logical = core::mem::size_of_val(self.history());
+        HistoryFootprint { logical, allocated: logical }
+    }
 
-    /// Verifica si un conjunto de teclas fue presionado de forma simultánea dentro de una tolerancia de tiempo.
+    /// Retorna un **hash estable** de la línea de tiempo del historial, para comparar
+    /// de forma barata dos grabaciones (replays, verificación entre máquinas en juego
+    /// competitivo).
     ///
-    /// Ideal para detectar combinaciones como `CTRL + C` o `SHIFT + ALT + S` donde
-    /// las teclas deben presionarse "al mismo tiempo" (dentro de la tolerancia).
+    /// Cubre, para cada evento en orden, su tecla, su estado y su **instante relativo**
+    /// al primer evento del historial. El hash es **sensible al orden**: reordenar o
+    /// alterar cualquier evento lo cambia.
+    ///
+    /// ## Agrupación temporal
+    /// El instante relativo se redondea al milisegundo más cercano antes de hashearse,
+    /// de modo que el *jitter* de captura por debajo del milisegundo no altera el
+    /// resultado. Dos eventos que quedan a ambos lados del límite de redondeo sí
+    /// producen hashes distintos. Los instantes absolutos no intervienen, así que dos
+    /// máquinas que arrancaron en momentos distintos obtienen el mismo hash.
+    ///
+    /// ## Estabilidad
+    /// Usa FNV-1a de 64 bits en lugar del hasher de `std` (cuyo algoritmo no está
+    /// garantizado), por lo que el resultado es estable entre ejecuciones y máquinas
+    /// con el mismo build. Depende de las implementaciones de `Hash` de `K` y `S`.
     ///
     /// # Ejemplo
     /// ```rust,ignore
-    /// // Detectar CTRL+SHIFT+S con tolerancia de 100ms
-    /// let combo = [KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::S];
-    /// if history.simultaneous_combo(&combo, Duration::from_millis(100)) {
-    ///     save_as();
+    /// if local.timeline_hash() != remote_hash {
+    ///     log::warn!("desincronización de input detectada");
     /// }
     /// ```
-    fn simultaneous_combo(&self, combo: &[K], tolerance: std::time::Duration) -> bool;
+    fn timeline_hash(&self) -> u64
+    where
+        S: Hash,
+    {
+        let mut hasher = Fnv1a::new();
+        let history = self.history();
+        if let Some(origin) = history.first().map(|event| event.timestamp()) {
+            for event in history {
+                let offset = Self::Clock::duration_between(origin, event.timestamp());
+                event.key().hash(&mut hasher);
+                event.state().hash(&mut hasher);
+                hasher.write_u64(((offset.as_micros() + 500) / 1000) as u64);
+            }
+        }
+        hasher.finish()
+    }
 
-    // === FILTRADO Y BÚSQUEDA ===
+    // === MOMENTO ACTUAL ===
 
-    /// Devuelve los últimos `n` eventos registrados para una tecla específica.
+    /// Devuelve el tiempo transcurrido desde el último evento registrado, o `None` si
+    /// el historial está vacío.
     ///
-    /// Los eventos están ordenados del más antiguo al más reciente.
-    fn find_last_n(&self, key: K, n: usize) -> Vec<&T>;
-
-    /// Devuelve todas las teclas presionadas dentro de un rango temporal dado.
+    /// Útil para detectar inactividad del usuario: `None` significa "nunca hubo
+    /// entrada", no "hubo entrada hace un instante".
     ///
-    /// # Ejemplo
-    /// ```rust,ignore
-    /// // Obtener todas las teclas presionadas en el último segundo
-    /// let recent_keys = history.keys_in_last(std::time::Duration::from_secs(1));
-    /// ```
-    fn keys_in_last(&self, duration: std::time::Duration) -> Vec<K>;
-
-    /// Verifica si una tecla fue presionada recientemente (dentro de los últimos `n` eventos).
+    /// ## Cambio incompatible
+    /// Antes retornaba `Duration` y el historial vacío obligaba a inventar un valor
+    /// (`Duration::ZERO` o `Duration::MAX`). Las implementaciones deben retornar
+    /// `None` en ese caso y `Some(elapsed)` en el resto; el código que la llama puede
+    /// recuperar el comportamiento anterior con `unwrap_or_default()`.
     ///
     /// # Ejemplo
     /// ```rust,ignore
-    /// // ¿La tecla Escape fue presionada en los últimos 5 eventos?
-    /// if history.occurred_recently(KeyCode::Escape, 5) {
-    ///     show_menu();
+    /// match history.since_last_event() {
+    ///     None => ui.show_hint("Presiona cualquier tecla"),
+    ///     Some(idle) if idle > Duration::from_secs(60) => game.pause(),
+    ///     Some(_) => {}
     /// }
     /// ```
-    fn occurred_recently(&self, key: K, within: usize) -> bool;
-
-    /// Devuelve cuántas veces una tecla fue presionada en los últimos `n` eventos.
-    ///
-    /// Útil para detectar spam de teclas o medir frecuencia de uso.
-    fn count_recent(&self, key: K, within: usize) -> usize;
-
-    // === ESTADÍSTICAS ===
-
-    /// Devuelve el total de veces que una tecla fue presionada desde el inicio del historial.
-    fn total_presses(&self, key: K) -> usize;
-
-    /// Calcula la frecuencia promedio de pulsaciones por segundo de una tecla.
-    ///
-    /// Basado en el historial completo disponible.
-    fn press_frequency(&self, key: K) -> f32;
-
-    /// Devuelve la tecla más utilizada dentro del historial.
-    ///
-    /// Útil para análisis de gameplay o sistemas de tutoriales adaptativos.
-    fn most_frequent_key(&self) -> Option<K>;
-
-    /// Calcula la velocidad promedio de entrada (teclas por segundo globales).
-    ///
-    /// Considera todas las teclas en el historial.
-    fn average_input_speed(&self) -> f32;
+    fn since_last_event(&self) -> Option<Duration>;
 
     // === UTILIDADES AVANZADAS ===
 
@@ -389,10 +1888,2489 @@ where
     ///              event.key(), event.state(), event.timestamp());
     /// }
     /// ```
-    fn replay<'a>(&'a self) -> impl Iterator<Item = &'a T> where T: 'a;
+    fn replay<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.history().iter()
+    }
+
+    /// Itera de forma **perezosa** los eventos que cumplen `pred`, del más antiguo al
+    /// más reciente.
+    ///
+    /// Permite a las herramientas responder preguntas propias sin un método dedicado
+    /// para cada consulta. Al ser un iterador, quien llama puede cortar en cuanto
+    /// encuentra lo que busca.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Liberaciones de Punch en el historial
+    /// let first = history
+    ///     .find_events(|event| event.key() == Pad::Punch && event.is_release())
+    ///     .next();
+    /// ```
+    fn find_events<'a, F>(&'a self, pred: F) -> impl Iterator<Item = &'a T> + 'a
+    where
+        T: 'a,
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.history().iter().filter(move |event| pred(event))
+    }
+
+    /// Exporta el historial completo como una lista de [`HistoryRecord`] portable,
+    /// del evento más antiguo al más reciente.
+    ///
+    /// Cada `offset_micros` se mide desde el primer evento con el
+    /// [`Clock`](Self::Clock) del historial, así que el primer registro siempre tiene
+    /// offset `0`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let records = history.export_history();
+    /// replay_file.write(&encode(&records))?;
+    /// ```
+    fn export_history(&self) -> Vec<HistoryRecord<K, S>> {
+        let history = self.history();
+        let Some(origin) = history.first().map(|event| event.timestamp()) else {
+            return Vec::new();
+        };
+
+        history
+            .iter()
+            .map(|event| event.to_serializable::<Self::Clock>(origin))
+            .collect()
+    }
+
+    /// **Reemplaza** el historial por los eventos de `records`, en el mismo orden.
+    ///
+    /// La implementación reconstruye cada evento sumando `offset_micros` a un instante
+    /// base de su elección (normalmente, el momento de la importación); solo se
+    /// preservan los intervalos entre eventos, no los instantes absolutos. No modifica
+    /// el estado vivo: para reproducir el replay, aplicar los eventos con
+    /// [`set_key`](InputStateExt::set_key) a su ritmo.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let records = decode(&std::fs::read("match_042.replay")?)?;
+    /// history.import_history(&records);
+    /// assert!(history.match_sequence(&[KeyCode::Down, KeyCode::Right, KeyCode::P]));
+    /// ```
+    fn import_history(&mut self, records: &[HistoryRecord<K, S>]);
+
+    /// Reproduce el historial sobre otro estado, aplicando cada evento con
+    /// [`set_key`](InputStateExt::set_key) en orden y respetando los intervalos
+    /// grabados.
+    ///
+    /// `speed` es la velocidad de reproducción: `1.0` es tiempo real, `2.0` el doble
+    /// de rápido y `0.5` la mitad. Con `speed == 0.0` (o cualquier valor no positivo
+    /// o no finito) todos los eventos se aplican **de inmediato**, útil para avanzar
+    /// un replay hasta un frame concreto. Un intervalo que, escalado, no cabe en un
+    /// [`Duration`] (velocidades ínfimas) también se aplica de inmediato en lugar de
+    /// bloquear el hilo indefinidamente.
+    ///
+    /// **Bloquea el hilo actual** durante la reproducción (usa `std::thread::sleep`),
+    /// por lo que solo está disponible con el feature `std`. No llama a
+    /// [`advance_frame`](InputStateExt::advance_frame): los flancos de `target` se
+    /// acumulan hasta que quien lo consuma avance el frame.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let mut debug_state = MyInputState::default();
+    /// recorded.replay_into(&mut debug_state, 0.0); // estado final, sin esperar
+    /// assert!(debug_state.is_pressed(KeyCode::ShiftLeft));
+    /// ```
+    #[cfg(feature = "std")]
+    fn replay_into<I>(&self, target: &mut I, speed: f32)
+    where
+        I: InputStateExt<K, S>,
+    {
+        let mut previous: Option<T::Time> = None;
+        let events = self.history().iter().map(|event| {
+            let gap = previous.map_or(Duration::ZERO, |before| Self::Clock::duration_between(before, event.timestamp()));
+            previous = Some(event.timestamp());
+            (gap, event.key(), event.state())
+        });
+        replay_timed(events, target, speed);
+    }
 
     /// Elimina y retorna el último evento del historial.
     ///
-    /// Útil para sistemas de undo o rollback.
+    /// Útil para sistemas de undo o rollback. Solo recorta el historial: el estado
+    /// vivo no cambia, por lo que ambos pueden quedar inconsistentes. Para deshacer
+    /// también su efecto, usar [`undo_last_and_revert`](Self::undo_last_and_revert).
     fn undo_last(&mut self) -> Option<T>;
-}
\ No newline at end of file
+
+    /// Elimina y retorna el último evento del historial **y revierte su efecto** en el
+    /// estado vivo.
+    ///
+    /// Tras la llamada, el estado de la tecla del evento eliminado es el de su último
+    /// evento restante en el historial. Si el evento eliminado era el **único** de esa
+    /// tecla, la tecla vuelve a estar completamente liberada, como tras
+    /// [`reset_key`](InputStateExt::reset_key). Las demás teclas no cambian.
+    ///
+    /// Lo que el evento cambió en las consultas por frame también se revierte:
+    /// [`last_pressed`](InputStateExt::last_pressed),
+    /// [`changed_since`](InputStateExt::changed_since) y
+    /// [`held_stable`](InputStateExt::held_stable) responden como si el evento no se
+    /// hubiera registrado nunca.
+    ///
+    /// La reversión no registra eventos nuevos en el historial ni genera flancos de
+    /// [`is_just_press`](InputStateExt::is_just_press) /
+    /// [`is_just_released`](InputStateExt::is_just_released). Retorna `None` (sin
+    /// cambios) si el historial está vacío.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Scrubbing en el editor: retroceder un evento
+    /// if let Some(event) = input.undo_last_and_revert() {
+    ///     timeline.unmark(event.timestamp());
+    /// }
+    /// ```
+    fn undo_last_and_revert(&mut self) -> Option<T>;
+
+    /// Reconstruye el estado de input tal como era **antes** del evento `index`.
+    ///
+    /// Reproduce los eventos `history()[..index]`; con `index >= history().len()`
+    /// reproduce el historial completo. Convierte el historial en una herramienta de
+    /// "viaje en el tiempo" para scrubbing de replays o depuración de rollback.
+    ///
+    /// Complejidad **O(index)** sobre el historial (más una búsqueda lineal por tecla
+    /// distinta). El resultado es un [`ReconstructedState`] independiente.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let past = history.state_at(42);
+    /// if past.is_pressed(KeyCode::ShiftLeft) {
+    ///     println!("Shift estaba abajo en el evento 42");
+    /// }
+    /// ```
+    fn state_at(&self, index: usize) -> ReconstructedState<K, S> {
+        let history = self.history();
+        ReconstructedState::from_events(&history[..index.min(history.len())])
+    }
+
+    /// Reconstruye el estado de input en el instante `time`.
+    ///
+    /// Incluye todos los eventos con timestamp **menor o igual** a `time`.
+    /// Misma complejidad que [`state_at`](Self::state_at).
+    fn state_at_time(&self, time: T::Time) -> ReconstructedState<K, S> {
+        ReconstructedState::from_events(
+            self.history()
+                .iter()
+                .take_while(|event| event.timestamp() <= time),
+        )
+    }
+
+    // === DIAGNÓSTICO ===
+
+    /// Retorna `true` si el historial contiene dos eventos **consecutivos idénticos**
+    /// (misma tecla y mismo estado).
+    ///
+    /// Señala un backend que duplica eventos o un interruptor con rebote; se
+    /// corrigen con [`dedup_consecutive`](Self::dedup_consecutive).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if history.has_duplicates() {
+    ///     log::warn!("el backend emite eventos duplicados");
+    ///     history.dedup_consecutive();
+    /// }
+    /// ```
+    fn has_duplicates(&self) -> bool {
+        self.history()
+            .windows(2)
+            .any(|pair| pair[0].key() == pair[1].key() && pair[0].state() == pair[1].state())
+    }
+
+    /// Verifica que el historial y el estado vivo sean **coherentes** entre sí.
+    ///
+    /// Disponible solo con el feature `testing`; pensado para las suites de test
+    /// de quienes implementan un backend.
+    ///
+    /// ## Definición de coherencia
+    /// El estado es coherente cuando, para toda tecla `k`:
+    /// - Si `k` aparece en el historial, [`is_pressed(k)`](InputStateExt::is_pressed)
+    ///   coincide con [`is_down`](StateSemantics::is_down) del **último** evento de `k`.
+    /// - Si `k` está en [`keys_pressed`](InputStateExt::keys_pressed), existe al menos
+    ///   un evento de `k` en el historial.
+    ///
+    /// La comprobación asume que el historial está completo desde el último
+    /// [`reset`](InputStateExt::reset): tras un [`trim_history`](Self::trim_history)
+    /// o un `reset` las discrepancias reportadas pueden ser legítimas.
+    ///
+    /// # Errores
+    /// Retorna la **primera** discrepancia encontrada, recorriendo el historial del
+    /// evento más antiguo al más reciente y luego las teclas presionadas.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// input.set_key(KeyCode::A, KeyState::Pressed);
+    /// assert_eq!(input.assert_coherent(), Ok(()));
+    /// ```
+    #[cfg(feature = "testing")]
+    fn assert_coherent(&self) -> Result<(), CoherenceError<K>> {
+        let history = self.history();
+
+        for (i, event) in history.iter().enumerate() {
+            let key = event.key();
+            if history[i + 1..].iter().any(|later| later.key() == key) {
+                continue;
+            }
+
+            let history_pressed = event.state().is_down();
+            let live_pressed = self.is_pressed(key);
+            if history_pressed != live_pressed {
+                return Err(CoherenceError { key, live_pressed, history_pressed });
+            }
+        }
+
+        for key in self.pressed_iter() {
+            if !history.iter().any(|event| event.key() == key) {
+                return Err(CoherenceError { key, live_pressed: true, history_pressed: false });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// # Trait `TemporalQueryExt`
+///
+/// **Consultas temporales** sobre el historial: tiempo desde una pulsación, doble
+/// taps, ritmo entre pulsaciones y tiempos de reacción, además del filtrado de
+/// eventos por tecla o por rango de tiempo.
+///
+/// Se implementa automáticamente para todo [`HistoryAccessExt`].
+///
+/// Al ser una implementación genérica, el comportamiento de sus métodos es **fijo**:
+/// ningún backend puede sobrescribirlos, y la semántica documentada en cada uno es
+/// la que se obtiene siempre. Un backend con una forma más eficiente de responder
+/// alguna consulta la expone como método propio.
+pub trait TemporalQueryExt<K, S, T>: HistoryAccessExt<K, S, T>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    // === CONSULTAS TEMPORALES ===
+
+    /// Devuelve el tiempo desde la última vez que se presionó una tecla específica.
+    ///
+    /// Retorna `None` si la tecla nunca fue presionada.
+    fn since_key_pressed(&self, key: K) -> Option<Duration> {
+        let last_at = self.last_event()?.timestamp();
+        let pressed_at = self
+            .history()
+            .iter()
+            .rev()
+            .find(|event| event.key() == key && event.is_press())?
+            .timestamp();
+        let idle = self.since_last_event().unwrap_or_default();
+        Some(Self::Clock::duration_between(pressed_at, last_at).saturating_add(idle))
+    }
+
+    /// Devuelve la diferencia temporal entre los dos últimos eventos consecutivos de la misma tecla.
+    ///
+    /// Útil para medir velocidad de tapping o intervalos de pulsación. Cuenta eventos
+    /// de cualquier estado, de modo que tras pulsar y soltar mide la duración de la
+    /// pulsación.
+    fn delta_between(&self, key: K) -> Option<Duration> {
+        let mut events = self.history().iter().rev().filter(|event| event.key() == key);
+        let last_at = events.next()?.timestamp();
+        let previous_at = events.next()?.timestamp();
+        Some(Self::Clock::duration_between(previous_at, last_at))
+    }
+
+    /// Verifica si una tecla fue presionada dos veces dentro de un intervalo determinado (doble tap).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Detectar doble clic en menos de 300ms
+    /// if history.is_double_tap(KeyCode::Space, Duration::from_millis(300)) {
+    ///     perform_double_jump();
+    /// }
+    /// ```
+    ///
+    /// Compara las **dos últimas pulsaciones** de la tecla.
+    fn is_double_tap(&self, key: K, threshold: Duration) -> bool {
+        let mut presses = self
+            .history()
+            .iter()
+            .rev()
+            .filter(|event| event.key() == key && event.is_press());
+        match (presses.next(), presses.next()) {
+            (Some(last), Some(previous)) => {
+                Self::Clock::duration_between(previous.timestamp(), last.timestamp()) <= threshold
+            }
+            _ => false,
+        }
+    }
+
+    /// Calcula el promedio de tiempo entre pulsaciones consecutivas de una tecla.
+    ///
+    /// Útil para análisis de ritmo o detección de patrones de entrada. Retorna `None`
+    /// si la tecla tiene menos de dos pulsaciones.
+    fn average_press_interval(&self, key: K) -> Option<Duration> {
+        let intervals = self.press_intervals(key);
+        let count = u32::try_from(intervals.len()).ok().filter(|count| *count > 0)?;
+        Some(intervals.iter().sum::<Duration>() / count)
+    }
+
+    /// Devuelve los intervalos entre pulsaciones consecutivas de `key`, en orden
+    /// cronológico.
+    ///
+    /// Es la colección sobre la que se calculan
+    /// [`average_press_interval`](Self::average_press_interval) y
+    /// [`tap_variance`](Self::tap_variance).
+    fn press_intervals(&self, key: K) -> Vec<Duration> {
+        let times = self
+            .press_events()
+            .filter(|event| event.key() == key)
+            .map(|event| event.timestamp());
+        intervals_between(times, Self::Clock::duration_between)
+    }
+
+    /// Calcula la **varianza** (en segundos²) de los intervalos entre pulsaciones
+    /// consecutivas de `key`.
+    ///
+    /// Una varianza baja indica pulsaciones rítmicas, casi de metrónomo; una alta,
+    /// machaqueo errático. Pensado para dificultad adaptativa y opciones de
+    /// accesibilidad. Retorna `None` si la tecla tiene menos de dos pulsaciones.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if history.tap_variance(Pad::Punch).is_some_and(|variance| variance > 0.01) {
+    ///     assist.enable_auto_mash();
+    /// }
+    /// ```
+    fn tap_variance(&self, key: K) -> Option<f32> {
+        let intervals = self.press_intervals(key);
+        if intervals.is_empty() {
+            return None;
+        }
+
+        let count = intervals.len() as f32;
+        let mean = intervals.iter().map(Duration::as_secs_f32).sum::<f32>() / count;
+        let variance = intervals
+            .iter()
+            .map(|interval| {
+                let delta = interval.as_secs_f32() - mean;
+                delta * delta
+            })
+            .sum::<f32>()
+            / count;
+        Some(variance)
+    }
+
+    /// Mide el tiempo de reacción entre una tecla **señal** y una tecla de
+    /// **respuesta**: desde la pulsación más reciente de `cue` hasta la primera
+    /// pulsación de `response` posterior a ella.
+    ///
+    /// Retorna `None` si `cue` nunca fue presionada o si no hay ninguna pulsación de
+    /// `response` después de la última pulsación de `cue`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if let Some(latency) = history.reaction_time(Cue::Flash, Pad::Punch) {
+    ///     trainer.record(latency);
+    /// }
+    /// ```
+    fn reaction_time(&self, cue: K, response: K) -> Option<Duration> {
+        let history = self.history();
+        let cue_index = history
+            .iter()
+            .rposition(|event| event.key() == cue && event.is_press())?;
+        let cue_at = history[cue_index].timestamp();
+
+        history[cue_index + 1..]
+            .iter()
+            .find(|event| event.key() == response && event.is_press())
+            .map(|event| Self::Clock::duration_between(cue_at, event.timestamp()))
+    }
+
+    /// Devuelve el retardo entre un instante de referencia y el **primer evento**
+    /// registrado después de él.
+    ///
+    /// Solo cuentan los eventos **estrictamente posteriores** a `since`; un evento con
+    /// timestamp igual a `since` se ignora. Retorna `None` si no hubo input después de
+    /// la referencia.
+    ///
+    /// Útil como métrica de tiempo de reacción o telemetría de UX (cuánto tarda el
+    /// usuario en responder a un prompt).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let prompt_shown = Instant::now();
+    /// // ... frames después ...
+    /// if let Some(delay) = history.time_to_first_input(prompt_shown) {
+    ///     telemetry.record("reaction", delay);
+    /// }
+    /// ```
+    fn time_to_first_input(&self, since: T::Time) -> Option<Duration> {
+        self.history()
+            .iter()
+            .find(|event| event.timestamp() > since)
+            .map(|event| Self::Clock::duration_between(since, event.timestamp()))
+    }
+
+    // === FILTRADO Y BÚSQUEDA ===
+
+    /// Devuelve los últimos `n` eventos registrados para una tecla específica.
+    ///
+    /// Los eventos están ordenados del más antiguo al más reciente.
+    fn find_last_n(&self, key: K, n: usize) -> Vec<&T> {
+        let mut events: Vec<&T> = self.history().iter().rev().filter(|event| event.key() == key).take(n).collect();
+        events.reverse();
+        events
+    }
+
+    /// Devuelve todas las teclas presionadas dentro de un rango temporal dado.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Obtener todas las teclas presionadas en el último segundo
+    /// let recent_keys = history.keys_in_last(Duration::from_secs(1));
+    /// ```
+    ///
+    /// Cada tecla aparece una sola vez, en orden de primera pulsación dentro del
+    /// rango. La antigüedad se mide respecto al momento actual (ver
+    /// [`since_last_event`](HistoryAccessExt::since_last_event)).
+    fn keys_in_last(&self, duration: Duration) -> Vec<K> {
+        let idle = self.since_last_event().unwrap_or_default();
+        let recent = recent_events(self.history(), duration, idle, Self::Clock::duration_between);
+
+        let mut keys: Vec<K> = Vec::new();
+        for event in recent {
+            if event.is_press() && !keys.contains(&event.key()) {
+                keys.push(event.key());
+            }
+        }
+        keys
+    }
+
+    /// Devuelve los eventos cuya marca de tiempo cae en el rango **inclusivo**
+    /// `[start, end]`, ordenados del más antiguo al más reciente.
+    ///
+    /// A diferencia de [`keys_in_last`](Self::keys_in_last), el rango es arbitrario:
+    /// una línea de tiempo de replay puede pedir exactamente los eventos de la
+    /// porción visible. Si `start > end` el resultado está vacío.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for event in history.events_in_range(view.start, view.end) {
+    ///     timeline.draw_marker(event.key(), event.timestamp());
+    /// }
+    /// ```
+    fn events_in_range(&self, start: T::Time, end: T::Time) -> Vec<&T> {
+        self.history()
+            .iter()
+            .filter(|event| {
+                let at = event.timestamp();
+                start <= at && at <= end
+            })
+            .collect()
+    }
+
+    /// Verifica si una tecla fue presionada recientemente (dentro de los últimos `n` eventos).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // ¿La tecla Escape fue presionada en los últimos 5 eventos?
+    /// if history.occurred_recently(KeyCode::Escape, 5) {
+    ///     show_menu();
+    /// }
+    /// ```
+    fn occurred_recently(&self, key: K, within: usize) -> bool {
+        self.count_recent(key, within) > 0
+    }
+
+    /// Devuelve cuántas veces una tecla fue presionada en los últimos `n` eventos.
+    ///
+    /// Útil para detectar spam de teclas o medir frecuencia de uso. Los `n` eventos
+    /// incluyen los de cualquier estado; solo se cuentan las pulsaciones.
+    fn count_recent(&self, key: K, within: usize) -> usize {
+        self.history()
+            .iter()
+            .rev()
+            .take(within)
+            .filter(|event| event.key() == key && event.is_press())
+            .count()
+    }
+}
+
+impl<K, S, T, H> TemporalQueryExt<K, S, T> for H
+where
+    H: HistoryAccessExt<K, S, T> + ?Sized,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+}
+
+/// # Trait `ComboDetectionExt`
+///
+/// **Detección de combos y secuencias** sobre el historial: secuencias exactas,
+/// con huecos o con límite de tiempo, combos simultáneos, acordes y resolución de
+/// combos por prioridad.
+///
+/// Se implementa automáticamente para todo [`TemporalQueryExt`] (y por tanto para
+/// todo [`HistoryAccessExt`]); como en [`TemporalQueryExt`], su comportamiento es
+/// fijo y no se puede sobrescribir.
+pub trait ComboDetectionExt<K, S, T>: TemporalQueryExt<K, S, T>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    /// Verifica si una secuencia específica de teclas ocurrió en el orden indicado.
+    ///
+    /// No considera el tiempo entre eventos, solo el orden. Opera sobre el flujo de
+    /// **pulsaciones** ([`press_events`](HistoryAccessExt::press_events)): las liberaciones
+    /// intercaladas no rompen la secuencia.
+    ///
+    /// Equivale a [`match_pattern`](Self::match_pattern) con un
+    /// [`SeqToken::Exact`] por tecla.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Detectar secuencia clásica: arriba, arriba, abajo, abajo
+    /// let pattern = [KeyCode::ArrowUp, KeyCode::ArrowUp, 
+    ///                KeyCode::ArrowDown, KeyCode::ArrowDown];
+    /// if history.match_sequence(&pattern) {
+    ///     println!("Secuencia detectada!");
+    /// }
+    /// ```
+    fn match_sequence(&self, pattern: &[K]) -> bool {
+        let tokens: Vec<SeqToken<'_, K>> = pattern.iter().map(|key| SeqToken::Exact(*key)).collect();
+        self.match_pattern(&tokens)
+    }
+
+    /// Verifica si el patrón de [`SeqToken`] aparece en el historial.
+    ///
+    /// Permite expresar entradas de juegos de pelea sin enumerar cada permutación:
+    /// ranuras con alternativas ([`AnyOf`](SeqToken::AnyOf)), ranuras libres
+    /// ([`Wildcard`](SeqToken::Wildcard)) y repeticiones ([`Repeat`](SeqToken::Repeat)).
+    ///
+    /// ## Semántica
+    /// - Solo se consideran las **pulsaciones** ([`press_events`](HistoryAccessExt::press_events));
+    ///   las liberaciones y los reportes de tecla mantenida no rompen el patrón.
+    /// - Los tokens deben coincidir con pulsaciones **consecutivas**, en cualquier
+    ///   punto del historial.
+    /// - Un patrón que no consume ninguna pulsación (vacío o solo `Repeat(_, 0)`)
+    ///   retorna `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// use SeqToken::*;
+    ///
+    /// let shoryuken = [Exact(Pad::Right), Exact(Pad::Down), AnyOf(&[Pad::DownRight, Pad::Right]), Exact(Pad::Punch)];
+    /// if history.match_pattern(&shoryuken) {
+    ///     player.shoryuken();
+    /// }
+    /// ```
+    fn match_pattern(&self, pattern: &[SeqToken<'_, K>]) -> bool {
+        let expanded: Vec<&SeqToken<'_, K>> = pattern
+            .iter()
+            .flat_map(|token| core::iter::repeat_n(token, token.len()))
+            .collect();
+        if expanded.is_empty() {
+            return false;
+        }
+
+        let presses: Vec<K> = self.press_events().map(|event| event.key()).collect();
+
+        presses.windows(expanded.len()).any(|window| {
+            window
+                .iter()
+                .zip(&expanded)
+                .all(|(key, token)| token.accepts(key))
+        })
+    }
+
+    /// Verifica si la secuencia descrita por `matcher` aparece en el historial,
+    /// respetando el orden y las ventanas de tiempo de cada paso.
+    ///
+    /// Ver [`SequenceMatcher`] para la semántica completa.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let dash = SequenceMatcher::new()
+    ///     .exact(Pad::Right)
+    ///     .exact(Pad::Right)
+    ///     .within(Duration::from_millis(200));
+    /// if history.matches(&dash) {
+    ///     player.dash();
+    /// }
+    /// ```
+    fn matches(&self, matcher: &SequenceMatcher<K>) -> bool {
+        let steps = &matcher.steps;
+        if steps.is_empty() {
+            return false;
+        }
+
+        let presses: Vec<(K, T::Time)> = self
+            .press_events()
+            .map(|event| (event.key(), event.timestamp()))
+            .collect();
+
+        presses.windows(steps.len()).any(|window| {
+            window.iter().zip(steps).enumerate().all(|(index, ((key, at), step))| {
+                let in_time = match (index.checked_sub(1), step.within) {
+                    (Some(previous), Some(limit)) => {
+                        Self::Clock::duration_between(window[previous].1, *at) <= limit
+                    }
+                    _ => true,
+                };
+                in_time && step.keys.contains(key)
+            })
+        })
+    }
+
+    /// Como [`match_sequence`](Self::match_sequence), pero tolera hasta `max_gap`
+    /// eventos **no relacionados** entre cada paso del patrón.
+    ///
+    /// Los jugadores machacan botones: un evento perdido en mitad de un movimiento no
+    /// debería romperlo. Cada paso debe ser una pulsación de la tecla indicada, y
+    /// entre dos pasos consecutivos puede haber como máximo `max_gap` eventos de
+    /// cualquier tipo. Con `max_gap == 0` los pasos deben ser eventos consecutivos.
+    ///
+    /// Cuenta **todos** los eventos como hueco ([`GapCounting::AllEvents`]), incluida
+    /// la liberación de la tecla del paso anterior: presionar y soltar `Down` antes de
+    /// presionar `Right` ya es un hueco de 1. Para contar solo pulsaciones, usar
+    /// [`match_sequence_loose_by`](Self::match_sequence_loose_by) con
+    /// [`GapCounting::PressesOnly`].
+    ///
+    /// Un patrón vacío retorna `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Abajo, adelante, puño, tolerando un botón extra entre pasos
+    /// if history.match_sequence_loose(&[Pad::Down, Pad::Right, Pad::Punch], 1) {
+    ///     player.hadouken();
+    /// }
+    /// ```
+    fn match_sequence_loose(&self, pattern: &[K], max_gap: usize) -> bool {
+        self.match_sequence_loose_by(pattern, max_gap, GapCounting::AllEvents)
+    }
+
+    /// Igual que [`match_sequence_loose`](Self::match_sequence_loose), eligiendo qué
+    /// eventos cuentan como hueco.
+    ///
+    /// Con [`GapCounting::PressesOnly`] los eventos que no son pulsaciones se descartan antes de
+    /// emparejar, de modo que `max_gap` cuenta solo pulsaciones extra.
+    fn match_sequence_loose_by(&self, pattern: &[K], max_gap: usize, counting: GapCounting) -> bool {
+        let Some(first) = pattern.first() else {
+            return false;
+        };
+
+        let stream: Vec<(K, bool)> = self
+            .history()
+            .iter()
+            .map(|event| (event.key(), event.is_press()))
+            .filter(|(_, down)| *down || counting == GapCounting::AllEvents)
+            .collect();
+
+        stream.iter().enumerate().any(|(start, (key, down))| {
+            *down && key == first && loose_match_from(&stream, &pattern[1..], start + 1, max_gap)
+        })
+    }
+
+    /// Verifica si una secuencia de teclas ocurrió dentro de un margen temporal determinado.
+    ///
+    /// Útil para combos rápidos o inputs en cadena que requieren timing preciso.
+    ///
+    /// Las teclas deben ser **pulsaciones consecutivas** y entre la
+    /// primera y la última no puede pasar más de `window`. Un patrón vacío retorna
+    /// `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // La secuencia debe completarse en menos de 2 segundos
+    /// if history.match_sequence_in_time(&combo, Duration::from_secs(2)) {
+    ///     activate_special_move();
+    /// }
+    /// ```
+    fn match_sequence_in_time(&self, pattern: &[K], window: Duration) -> bool {
+        let presses: Vec<(K, T::Time)> = self
+            .press_events()
+            .map(|event| (event.key(), event.timestamp()))
+            .collect();
+        timed_match(&presses, pattern, window, Self::Clock::duration_between)
+    }
+
+    /// Como [`match_sequence_in_time`](Self::match_sequence_in_time), pero sobre las
+    /// **liberaciones**: las teclas deben soltarse en el orden indicado, como
+    /// liberaciones consecutivas, dentro de `window`.
+    ///
+    /// Necesario para movimientos que dependen de cuándo se suelta una tecla, como
+    /// los movimientos de carga ("mantener atrás y soltar hacia adelante + puño").
+    /// Un patrón vacío retorna `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Soltar atrás y, enseguida, puño
+    /// if history.match_release_sequence(&[Pad::Left, Pad::Punch], Duration::from_millis(150)) {
+    ///     player.sonic_boom();
+    /// }
+    /// ```
+    fn match_release_sequence(&self, pattern: &[K], window: Duration) -> bool {
+        let releases: Vec<(K, T::Time)> = self
+            .history()
+            .iter()
+            .filter(|event| event.is_release())
+            .map(|event| (event.key(), event.timestamp()))
+            .collect();
+        timed_match(&releases, pattern, window, Self::Clock::duration_between)
+    }
+
+    /// Verifica si un conjunto de teclas fue presionado de forma simultánea dentro de una tolerancia de tiempo.
+    ///
+    /// Ideal para detectar combinaciones como `CTRL + C` o `SHIFT + ALT + S` donde
+    /// las teclas deben presionarse "al mismo tiempo" (dentro de la tolerancia).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Detectar CTRL+SHIFT+S con tolerancia de 100ms
+    /// let combo = [KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::S];
+    /// if history.simultaneous_combo(&combo, Duration::from_millis(100)) {
+    ///     save_as();
+    /// }
+    /// ```
+    ///
+    /// Busca una ventana de duración `tolerance` en la que **cada** tecla
+    /// del combo tenga al menos una pulsación, en cualquier orden. Un combo vacío
+    /// nunca se cumple.
+    fn simultaneous_combo(&self, combo: &[K], tolerance: Duration) -> bool {
+        if combo.is_empty() {
+            return false;
+        }
+
+        let presses: Vec<&T> = self.press_events().filter(|event| combo.contains(&event.key())).collect();
+        presses.iter().enumerate().any(|(index, start)| {
+            let start_at = start.timestamp();
+            combo.iter().all(|key| {
+                presses[index..].iter().any(|event| {
+                    event.key() == *key
+                        && Self::Clock::duration_between(start_at, event.timestamp()) <= tolerance
+                })
+            })
+        })
+    }
+
+    /// Verifica un **acorde secuencial**: cada tecla se presiona, en orden, mientras
+    /// las anteriores siguen mantenidas.
+    ///
+    /// Cubre lo que no expresan [`simultaneous_combo`](Self::simultaneous_combo)
+    /// ("a la vez") ni [`match_sequence`](Self::match_sequence) ("en orden"):
+    /// "presiona A y, manteniéndola, presiona B antes de 200 ms", como los acordes de
+    /// piano o los atajos modificador + tecla con tiempo explícito.
+    ///
+    /// ## Semántica
+    /// - Cada paso `(key, window)` debe ser una pulsación de `key` como máximo
+    ///   `window` después de la pulsación del paso anterior. La ventana del primer
+    ///   paso no se usa.
+    /// - Ninguna tecla de los pasos anteriores puede liberarse antes de la pulsación
+    ///   del paso siguiente; otras teclas intercaladas no rompen el acorde.
+    /// - Busca en todo el historial. Una lista de pasos vacía retorna `false`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let steps = [
+    ///     (KeyCode::LShift, Duration::ZERO),
+    ///     (KeyCode::A, Duration::from_millis(200)),
+    ///     (KeyCode::S, Duration::from_millis(200)),
+    /// ];
+    /// if history.chord_sequence(&steps) {
+    ///     player.super_move();
+    /// }
+    /// ```
+    fn chord_sequence(&self, steps: &[(K, Duration)]) -> bool {
+        let history = self.history();
+        let (Some(((first, _), rest)), Some(origin)) =
+            (steps.split_first(), history.first().map(|event| event.timestamp()))
+        else {
+            return false;
+        };
+
+        let stream: Vec<ChordStep<K>> = history
+            .iter()
+            .map(|event| {
+                (
+                    event.key(),
+                    event.is_press(),
+                    event.is_release(),
+                    Self::Clock::duration_between(origin, event.timestamp()),
+                )
+            })
+            .collect();
+
+        let mut held = Vec::with_capacity(steps.len());
+        stream.iter().enumerate().any(|(index, (key, press, _, at))| {
+            if !*press || key != first {
+                return false;
+            }
+            held.clear();
+            held.push(*key);
+            chord_sequence_from(&stream, rest, index + 1, *at, &mut held)
+        })
+    }
+
+    /// Retorna el **máximo número de teclas mantenidas a la vez** dentro de cualquier
+    /// ventana deslizante de duración `window`.
+    ///
+    /// Una tecla cuenta en una ventana si estuvo abajo en algún momento de ella. Con
+    /// `window == 0` es el pico exacto de teclas mantenidas simultáneamente; una
+    /// ventana mayor tolera las pulsaciones "en rodillo", en las que una tecla se
+    /// suelta justo antes de que baje la siguiente.
+    ///
+    /// Es la versión agregada de [`simultaneous_combo`](Self::simultaneous_combo) para
+    /// todas las teclas. Las herramientas de diagnóstico pueden compararlo con lo que
+    /// exige un combo para avisar de teclados sin *N-key rollover* (teclas fantasma o
+    /// pulsaciones perdidas). Solo considera lo que hay en el historial: las teclas
+    /// presionadas antes del primer evento registrado no cuentan.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if history.simultaneous_peak(Duration::from_millis(50)) < 4 {
+    ///     ui.warn("Tu teclado podría no registrar combos de 4 teclas");
+    /// }
+    /// ```
+    fn simultaneous_peak(&self, window: Duration) -> usize {
+        let history = self.history();
+        let Some(origin) = history.first().map(|event| event.timestamp()) else {
+            return 0;
+        };
+
+        // Intervalos [bajada - window, subida) por tecla, ya fusionados: una tecla
+        // cuenta en la ventana que empieza en `t` si `t` cae dentro de uno de ellos.
+        let mut intervals: Vec<(K, Duration, Option<Duration>)> = Vec::new();
+        for event in history {
+            let at = Self::Clock::duration_between(origin, event.timestamp());
+            let open = intervals
+                .iter_mut()
+                .rev()
+                .find(|(key, _, _)| *key == event.key())
+                .filter(|(_, _, end)| end.is_none());
+
+            match (event.state().is_down(), open) {
+                (true, None) => {
+                    let start = at.saturating_sub(window);
+                    let merged = intervals
+                        .iter_mut()
+                        .rev()
+                        .find(|(key, _, _)| *key == event.key())
+                        .filter(|(_, _, end)| end.is_some_and(|end| end > start));
+                    match merged {
+                        Some(interval) => interval.2 = None,
+                        None => intervals.push((event.key(), start, None)),
+                    }
+                }
+                (false, Some(interval)) => interval.2 = Some(at),
+                _ => {}
+            }
+        }
+
+        // Barrido: a igual instante, los cierres (-1) van antes que las aperturas (+1).
+        let mut points: Vec<(Duration, i32)> = Vec::with_capacity(intervals.len() * 2);
+        for (_, start, end) in &intervals {
+            points.push((*start, 1));
+            if let Some(end) = end {
+                points.push((*end, -1));
+            }
+        }
+        points.sort_unstable();
+
+        let mut held = 0i32;
+        let mut peak = 0i32;
+        for (_, delta) in points {
+            held += delta;
+            peak = peak.max(held);
+        }
+        peak as usize
+    }
+
+    /// Devuelve los nombres de todos los combos conocidos que el jugador ejecutó
+    /// dentro de la ventana reciente `window`.
+    ///
+    /// Contraparte analítica de la detección de combos, pensada para modos de
+    /// entrenamiento y resúmenes post-ronda. Cada entrada de `known` es
+    /// `(nombre, teclas)`.
+    ///
+    /// ## Semántica
+    /// - Solo se consideran eventos de **pulsación** ([`InputEvent::is_press`]; el
+    ///   autorepeat no cuenta salvo que el tipo de evento lo incluya)
+    ///   ocurridos en la ventana `[ahora - window, ahora]`, donde "ahora" es el
+    ///   último evento más [`since_last_event`](HistoryAccessExt::since_last_event).
+    /// - Un combo coincide si sus teclas aparecen **consecutivas** en ese flujo de pulsaciones.
+    /// - **Solapamiento:** los combos se evalúan de forma independiente; si uno está
+    ///   contenido en otro (`[A, B]` dentro de `[A, B, C]`), se reportan ambos.
+    /// - **Orden:** cronológico según el instante en que se **completó** la primera
+    ///   ocurrencia de cada combo. Cada combo aparece como máximo una vez.
+    /// - Los combos con lista de teclas vacía nunca coinciden.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let known: &[(&str, &[KeyCode])] = &[
+    ///     ("Hadouken", &[KeyCode::Down, KeyCode::Right, KeyCode::P]),
+    ///     ("Shoryuken", &[KeyCode::Right, KeyCode::Down, KeyCode::P]),
+    /// ];
+    /// for name in history.combos_executed(known, Duration::from_secs(10)) {
+    ///     summary.push(name);
+    /// }
+    /// ```
+    fn combos_executed<'a>(&self, known: &[(&'a str, &[K])], window: Duration) -> Vec<&'a str> {
+        let Some(last) = self.last_event() else {
+            return Vec::new();
+        };
+        let last_at = last.timestamp();
+        let idle = self.since_last_event().unwrap_or_default();
+
+        let presses: Vec<K> = self
+            .history()
+            .iter()
+            .filter(|event| {
+                let age = Self::Clock::duration_between(event.timestamp(), last_at).saturating_add(idle);
+                event.is_press() && age <= window
+            })
+            .map(|event| event.key())
+            .collect();
+
+        let mut found: Vec<(usize, &'a str)> = known
+            .iter()
+            .filter(|(_, keys)| !keys.is_empty())
+            .filter_map(|(name, keys)| {
+                presses
+                    .windows(keys.len())
+                    .position(|run| run == *keys)
+                    .map(|start| (start + keys.len(), *name))
+            })
+            .collect();
+        found.sort_by_key(|(end, _)| *end);
+
+        found.into_iter().map(|(_, name)| name).collect()
+    }
+
+    /// Devuelve el combo **ganador** entre varios que coinciden a la vez.
+    ///
+    /// Cuando varios combos registrados se completan en el mismo frame (por ejemplo,
+    /// uno de 3 golpes y otro de 5 que comparten prefijo), el juego debe elegir uno
+    /// solo para que el movimiento especial gane al básico.
+    ///
+    /// Un combo coincide si [`match_sequence`](Self::match_sequence) retorna `true`
+    /// para sus teclas. Entre los que coinciden:
+    /// 1. Gana el de mayor [`priority`](PrioritizedCombo::priority).
+    /// 2. A igual prioridad, gana el de **más teclas**.
+    /// 3. Si persiste el empate, gana el que aparece primero en `combos`.
+    ///
+    /// Retorna `None` si ninguno coincide.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// let combos = [
+    ///     PrioritizedCombo::new(vec![KeyCode::J, KeyCode::J, KeyCode::J], 0),
+    ///     PrioritizedCombo::new(vec![KeyCode::J, KeyCode::J, KeyCode::J, KeyCode::K, KeyCode::K], 10),
+    /// ];
+    /// if let Some(combo) = history.best_match(&combos) {
+    ///     perform(combo);
+    /// }
+    /// ```
+    fn best_match<'a>(&self, combos: &'a [PrioritizedCombo<K>]) -> Option<&'a PrioritizedCombo<K>> {
+        combos
+            .iter()
+            .filter(|combo| self.match_sequence(&combo.keys))
+            .fold(None, |best: Option<&'a PrioritizedCombo<K>>, combo| match best {
+                Some(current)
+                    if (current.priority, current.keys.len()) >= (combo.priority, combo.keys.len()) =>
+                {
+                    Some(current)
+                }
+                _ => Some(combo),
+            })
+    }
+}
+
+impl<K, S, T, H> ComboDetectionExt<K, S, T> for H
+where
+    H: TemporalQueryExt<K, S, T> + ?Sized,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+}
+
+/// # Trait `HistoryStatsExt`
+///
+/// **Estadísticas** de uso calculadas sobre el historial: pulsaciones por tecla,
+/// frecuencias, histograma y acciones por minuto.
+///
+/// Se implementa automáticamente para todo [`ComboDetectionExt`] (y por tanto para
+/// todo [`HistoryAccessExt`]); como en [`TemporalQueryExt`], su comportamiento es
+/// fijo y no se puede sobrescribir.
+pub trait HistoryStatsExt<K, S, T>: ComboDetectionExt<K, S, T>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+    /// Devuelve el total de veces que una tecla fue presionada desde el inicio del historial.
+    fn total_presses(&self, key: K) -> usize {
+        self.press_events().filter(|event| event.key() == key).count()
+    }
+
+    /// Calcula la frecuencia promedio de pulsaciones por segundo de una tecla.
+    ///
+    /// Basado en el historial completo disponible: divide las pulsaciones por el
+    /// tiempo transcurrido desde el primer evento. Retorna `0.0` si ese tiempo es nulo.
+    fn press_frequency(&self, key: K) -> f32 {
+        let span = history_span(
+            self.history(),
+            self.since_last_event().unwrap_or_default(),
+            Self::Clock::duration_between,
+        );
+        if span.is_zero() {
+            return 0.0;
+        }
+        self.total_presses(key) as f32 / span.as_secs_f32()
+    }
+
+    /// Devuelve la tecla más utilizada dentro del historial.
+    ///
+    /// Útil para análisis de gameplay o sistemas de tutoriales adaptativos. Es la
+    /// primera entrada de [`press_histogram`](Self::press_histogram).
+    fn most_frequent_key(&self) -> Option<K> {
+        self.press_histogram().first().map(|(key, _)| *key)
+    }
+
+    /// Igual que [`most_frequent_key`](Self::most_frequent_key), pero contando solo
+    /// las pulsaciones cuya antigüedad no supera `window`.
+    ///
+    /// Los sistemas adaptativos y los tutoriales reaccionan a lo que el jugador hace
+    /// **ahora**, no a una tecla machacada hace una hora. La antigüedad se mide como
+    /// en [`keys_in_last`](TemporalQueryExt::keys_in_last) y los empates se resuelven
+    /// como en [`press_histogram`](Self::press_histogram). Retorna `None` si no hay
+    /// pulsaciones en la ventana.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if history.most_frequent_key_recent(Duration::from_secs(30)) == Some(KeyCode::Space) {
+    ///     tips.show("Prueba a mantener Espacio para planear");
+    /// }
+    /// ```
+    fn most_frequent_key_recent(&self, window: Duration) -> Option<K> {
+        let idle = self.since_last_event().unwrap_or_default();
+        let recent = recent_events(self.history(), window, idle, Self::Clock::duration_between);
+        press_counts(recent.iter().filter(|event| event.is_press()).map(|event| event.key()))
+            .first()
+            .map(|(key, _)| *key)
+    }
+
+    /// Devuelve la **distribución completa** de pulsaciones: cada tecla que aparece
+    /// en el historial con su número de pulsaciones, de mayor a menor.
+    ///
+    /// Los empates se resuelven por la pulsación **más reciente** (la tecla presionada
+    /// más tarde va primero), de modo que el resultado es estable entre llamadas. Las
+    /// teclas que solo aparecen en eventos que no son pulsaciones no se incluyen.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// for (key, presses) in history.press_histogram() {
+    ///     heatmap.set(key, presses);
+    /// }
+    /// ```
+    fn press_histogram(&self) -> Vec<(K, usize)> {
+        press_counts(self.press_events().map(|event| event.key()))
+    }
+
+    /// Devuelve las teclas **distintas** que fueron presionadas alguna vez en el
+    /// historial, en orden de primera aparición.
+    ///
+    /// Pensado para tutoriales que sugieren acciones que el jugador aún no probó.
+    /// Igual que [`press_histogram`](Self::press_histogram), solo cuenta las
+    /// pulsaciones ([`StateSemantics::is_press`]).
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// if !history.keys_ever_pressed().contains(&KeyCode::LControl) {
+    ///     tips.show("Mantén Ctrl para agacharte");
+    /// }
+    /// ```
+    fn keys_ever_pressed(&self) -> Vec<K> {
+        let mut keys: Vec<K> = Vec::new();
+        for event in self.press_events() {
+            if !keys.contains(&event.key()) {
+                keys.push(event.key());
+            }
+        }
+        keys
+    }
+
+    /// Calcula la velocidad promedio de entrada (teclas por segundo globales).
+    ///
+    /// Considera todas las teclas en el historial, con el mismo criterio que
+    /// [`press_frequency`](Self::press_frequency).
+    fn average_input_speed(&self) -> f32 {
+        let span = history_span(
+            self.history(),
+            self.since_last_event().unwrap_or_default(),
+            Self::Clock::duration_between,
+        );
+        if span.is_zero() {
+            return 0.0;
+        }
+        self.press_events().count() as f32 / span.as_secs_f32()
+    }
+
+    /// Calcula las **acciones por minuto** (APM) en la ventana final de duración
+    /// `window`, que es lo que muestran los overlays competitivos.
+    ///
+    /// Cuenta las pulsaciones cuya antigüedad (respecto al momento actual, ver
+    /// [`since_last_event`](HistoryAccessExt::since_last_event)) no supera `window` y extrapola a
+    /// un ritmo por minuto. Si el historial abarca menos que `window`, se divide por
+    /// el tiempo realmente transcurrido desde el primer evento en lugar de por la
+    /// ventana nominal, para no inflar las cifras del inicio de la partida.
+    ///
+    /// Retorna `0.0` si no hay pulsaciones en la ventana o el intervalo medido es nulo.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// overlay.set_apm(history.actions_per_minute(Duration::from_secs(10)));
+    /// ```
+    fn actions_per_minute(&self, window: Duration) -> f32 {
+        let history = self.history();
+        let idle = self.since_last_event().unwrap_or_default();
+
+        let span = history_span(history, idle, Self::Clock::duration_between).min(window);
+        if span.is_zero() {
+            return 0.0;
+        }
+
+        let actions = recent_events(history, window, idle, Self::Clock::duration_between)
+            .iter()
+            .filter(|event| event.is_press())
+            .count();
+
+        actions as f32 * 60.0 / span.as_secs_f32()
+    }
+}
+
+impl<K, S, T, H> HistoryStatsExt<K, S, T> for H
+where
+    H: ComboDetectionExt<K, S, T> + ?Sized,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+}
+
+/// # Trait `WithHistoryExt`
+///
+/// Extiende [`InputStateExt`] añadiendo un **sistema de historial de eventos**.
+///
+/// Este trait permite registrar, consultar y analizar los eventos de entrada
+/// a lo largo del tiempo — ideal para detectar *combos*, *doble taps*, *secuencias*
+/// o simplemente para fines de depuración o replays.
+///
+/// ## Concepto
+/// - Mientras [`InputStateExt`] maneja el estado **presente**,
+///   `WithHistoryExt` maneja el **pasado reciente**.
+/// - Cada evento registrado (`T`) representa una acción individual del usuario
+///   con su respectivo timestamp, permitiendo análisis temporal sofisticado.
+///
+/// ## Parámetros genéricos
+/// - `K`: Tipo de tecla o código de entrada (por ejemplo, `KeyCode`).
+/// - `S`: Estado asociado a la tecla (`KeyState`).
+/// - `T`: Tipo de evento histórico que implementa [`InputEvent`].
+///
+/// ## Estructura
+/// `WithHistoryExt` es un **alias** que reúne cuatro traits, cada uno supertrait
+/// del siguiente:
+/// - [`HistoryAccessExt`], con el acceso base, el recorte y las utilidades. Es el
+///   único que se implementa a mano.
+/// - [`TemporalQueryExt`], con las consultas temporales y el filtrado.
+/// - [`ComboDetectionExt`], con los combos y las secuencias.
+/// - [`HistoryStatsExt`], con las estadísticas.
+///
+/// Los tres últimos y el propio `WithHistoryExt` se implementan automáticamente para
+/// todo [`HistoryAccessExt`]. Quien consume el historial puede pedir
+/// `H: WithHistoryExt<K, S, T>` o solo el nivel que necesite; para llamar a los
+/// métodos sobre un tipo concreto, el trait que los define debe estar en ámbito.
+///
+/// ## Cambio incompatible
+/// Antes los backends implementaban `WithHistoryExt` directamente. Ahora
+/// implementan [`HistoryAccessExt`] con el mismo `type Clock` y los mismos métodos
+/// requeridos: basta con renombrar el bloque `impl`. Las consultas que antes se
+/// sobrescribían ya no se pueden sobrescribir; si un backend tenía una versión más
+/// eficiente, debe exponerla como método propio.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// use orbit_input_core::keyboard::{KeyCode, KeyState};
+/// use orbit_input_core::traits::WithHistoryExt;
+/// use core::time::Duration;
+///
+/// fn check_combo<H>(history: &H)
+/// where
+///     H: WithHistoryExt<KeyCode, KeyState, KeyEvent>
+/// {
+///     // Detectar combo clásico de Konami Code
+///     let konami = [
+///         KeyCode::ArrowUp, KeyCode::ArrowUp,
+///         KeyCode::ArrowDown, KeyCode::ArrowDown,
+///         KeyCode::ArrowLeft, KeyCode::ArrowRight,
+///     ];
+///     
+///     if history.match_sequence_in_time(&konami, Duration::from_secs(5)) {
+///         println!("¡Konami Code activado!");
+///         unlock_secret_level();
+///     }
+///     
+///     // Detectar doble tap para dash
+///     if history.is_double_tap(KeyCode::ShiftLeft, Duration::from_millis(300)) {
+///         println!("¡Dash activado!");
+///     }
+/// }
+/// ```
+pub trait WithHistoryExt<K, S, T>: HistoryStatsExt<K, S, T>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+}
+
+impl<K, S, T, H> WithHistoryExt<K, S, T> for H
+where
+    H: HistoryStatsExt<K, S, T> + ?Sized,
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: InputEvent<Key = K, State = S>,
+{
+}
+
+
+/// # Trait `ChordHistoryExt`
+///
+/// Extiende [`WithHistoryExt`] para historiales cuyo tipo de evento implementa
+/// [`ChordEvent`], permitiendo **colapsar** pulsaciones casi simultáneas en acordes.
+///
+/// Es a la vez una mejora de almacenamiento (menos entradas) y de corrección: los
+/// detectores de secuencias dejan de ver tres pasos donde el jugador hizo un solo
+/// gesto.
+///
+/// ## Ejemplo de implementación
+/// ```rust,ignore
+/// impl ChordHistoryExt<KeyCode, KeyState, KeyEvent> for MyInput {
+///     fn collapse_simultaneous(&mut self, tolerance: Duration) {
+///         self.events = KeyEvent::collapse::<Self::Clock>(&self.events, tolerance);
+///     }
+/// }
+/// ```
+pub trait ChordHistoryExt<K, S, T>: WithHistoryExt<K, S, T>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+    T: ChordEvent<Key = K, State = S>,
+{
+    /// Reemplaza en el historial cada grupo de eventos consecutivos con el mismo
+    /// estado y separados como máximo `tolerance` por un único evento de acorde.
+    ///
+    /// Debe producir el mismo resultado que [`ChordEvent::collapse`] aplicado al
+    /// historial completo.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// // Ctrl, Shift y S llegan con 2ms de diferencia
+    /// history.collapse_simultaneous(Duration::from_millis(10));
+    /// assert_eq!(history.last_event().unwrap().chord_keys().len(), 3);
+    /// ```
+    fn collapse_simultaneous(&mut self, tolerance: Duration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FrameTimeExt;
+    use crate::testing::MockClock;
+    use crate::testing::fixtures::{Btn, DropsReleases, Event, Faulty, Healthy, Input, Key, ms, play};
+
+    #[test]
+    fn coherent_backend_passes() {
+        let input = play(&[(0, Key::A, Btn::Down), (10, Key::B, Btn::Down), (20, Key::A, Btn::Up)]);
+
+        assert_eq!(input.assert_coherent(), Ok(()));
+    }
+
+    #[test]
+    fn lost_release_reports_the_key() {
+        let mut input = Faulty::<DropsReleases>::default();
+        input.set_key(Key::A, Btn::Down);
+        input.set_key(Key::B, Btn::Down);
+        input.set_key(Key::B, Btn::Up);
+
+        assert_eq!(
+            input.assert_coherent(),
+            Err(CoherenceError { key: Key::B, live_pressed: false, history_pressed: true })
+        );
+    }
+
+    #[test]
+    fn pressed_key_without_events_is_incoherent() {
+        let mut input = play(&[(0, Key::A, Btn::Down)]);
+        input.clear_history();
+
+        assert_eq!(
+            input.assert_coherent(),
+            Err(CoherenceError { key: Key::A, live_pressed: true, history_pressed: false })
+        );
+    }
+
+    #[test]
+    fn first_input_counts_only_events_after_the_reference() {
+        let input = play(&[(0, Key::A, Btn::Down), (10, Key::A, Btn::Up), (30, Key::B, Btn::Down)]);
+
+        assert_eq!(input.time_to_first_input(ms(5)), Some(ms(5)));
+        assert_eq!(input.time_to_first_input(ms(10)), Some(ms(20)));
+        assert_eq!(input.time_to_first_input(ms(30)), None);
+    }
+
+    fn release_all_emits_edges(input: &mut impl HistoryAccessExt<Key, Btn, Event>) {
+        input.set_key(Key::A, Btn::Down);
+        input.set_key(Key::B, Btn::Down);
+        input.advance_frame();
+
+        input.release_all();
+
+        assert!(!input.any_pressed());
+        assert!(input.is_just_released(Key::A));
+        assert!(input.is_just_released(Key::B));
+        assert_eq!(input.history().len(), 4);
+        assert!(input.history()[2..].iter().all(|event| event.state == Btn::Up));
+    }
+
+    #[test]
+    fn release_all_default_emits_edges() {
+        release_all_emits_edges(&mut Faulty::<Healthy>::default());
+    }
+
+    #[test]
+    fn release_all_mock_emits_edges() {
+        release_all_emits_edges(&mut Input::new());
+    }
+
+    #[test]
+    fn state_at_reconstructs_the_pressed_set() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (10, Key::B, Btn::Down),
+            (20, Key::A, Btn::Up),
+            (30, Key::B, Btn::Up),
+        ]);
+
+        assert_eq!(input.state_at(0).keys_pressed(), []);
+        assert_eq!(input.state_at(2).keys_pressed(), [Key::A, Key::B]);
+        assert_eq!(input.state_at(3).keys_pressed(), [Key::B]);
+        assert_eq!(input.state_at(99).keys_pressed(), []);
+        assert_eq!(input.state_at(3).state_of(Key::A), Some(Btn::Up));
+        assert_eq!(input.state_at(1).state_of(Key::B), None);
+    }
+
+    #[test]
+    fn state_at_time_includes_events_at_that_instant() {
+        let input = play(&[(0, Key::A, Btn::Down), (10, Key::B, Btn::Down), (20, Key::A, Btn::Up)]);
+
+        assert_eq!(input.state_at_time(ms(15)).keys_pressed(), [Key::A, Key::B]);
+        assert_eq!(input.state_at_time(ms(20)).keys_pressed(), [Key::B]);
+    }
+
+    #[test]
+    fn reconstructed_state_outlives_the_history() {
+        let mut input = play(&[(0, Key::A, Btn::Down)]);
+        let past = input.state_at(1);
+        input.clear_history();
+
+        assert!(past.is_pressed(Key::A));
+    }
+
+    #[test]
+    fn debug_dump_lists_pressed_keys_and_states() {
+        let mut input = play(&[(0, Key::A, Btn::Down), (0, Key::B, Btn::Down), (0, Key::B, Btn::Up)]);
+        input.update_time(ms(1250));
+
+        assert_eq!(
+            input.debug_dump(),
+            "pressed=[A:Down(1.250s)] any_pressed=true last_pressed=Some(B)"
+        );
+    }
+
+    #[test]
+    fn debug_dump_of_an_idle_state() {
+        assert_eq!(Input::new().debug_dump(), "pressed=[] any_pressed=false last_pressed=None");
+    }
+
+    #[test]
+    fn combos_executed_lists_the_combos_in_the_window() {
+        let input = play(&[
+            (0, Key::D, Btn::Down),
+            (5, Key::D, Btn::Up),
+            (5, Key::D, Btn::Down),
+            (1000, Key::B, Btn::Down),
+            (1010, Key::C, Btn::Down),
+            (1010, Key::B, Btn::Up),
+            (1020, Key::A, Btn::Down),
+        ]);
+        let known: &[(&str, &[Key])] = &[
+            ("ca", &[Key::C, Key::A]),
+            ("dd", &[Key::D, Key::D]),
+            ("bc", &[Key::B, Key::C]),
+        ];
+
+        assert_eq!(input.combos_executed(known, ms(100)), ["bc", "ca"]);
+        assert_eq!(input.combos_executed(known, ms(2000)), ["dd", "bc", "ca"]);
+    }
+
+    #[test]
+    fn combos_executed_reports_nested_combos() {
+        let input = play(&[(0, Key::A, Btn::Down), (10, Key::B, Btn::Down), (20, Key::C, Btn::Down)]);
+        let known: &[(&str, &[Key])] = &[
+            ("abc", &[Key::A, Key::B, Key::C]),
+            ("ab", &[Key::A, Key::B]),
+            ("none", &[]),
+        ];
+
+        assert_eq!(input.combos_executed(known, ms(100)), ["ab", "abc"]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Action {
+        Jump,
+        Attack,
+    }
+
+    fn actions(key: Key) -> Option<Action> {
+        match key {
+            Key::A | Key::B => Some(Action::Jump),
+            Key::C => Some(Action::Attack),
+            Key::D => None,
+        }
+    }
+
+    #[test]
+    fn mapped_view_translates_queries() {
+        let mut input = Input::new();
+        input.set_key(Key::B, Btn::Down);
+        input.set_key(Key::D, Btn::Down);
+
+        let view = input.map_keys(actions);
+
+        assert!(view.is_pressed(Action::Jump));
+        assert!(view.is_just_press(Action::Jump));
+        assert!(!view.is_pressed(Action::Attack));
+        assert_eq!(view.keys_pressed(), [Action::Jump]);
+        assert_eq!(view.last_pressed(), None);
+    }
+
+    #[test]
+    fn mapped_view_merges_keys_with_the_same_target() {
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Up);
+        input.set_key(Key::B, Btn::Down);
+        input.set_key(Key::C, Btn::Up);
+
+        let view = input.map_keys(actions);
+        let mut states: Vec<(Action, Btn)> = view.state_iter().collect();
+        states.sort_by_key(|(action, _)| *action as u8);
+
+        assert_eq!(states, [(Action::Jump, Btn::Down), (Action::Attack, Btn::Up)]);
+        assert_eq!(view.last_pressed(), Some(Action::Jump));
+    }
+
+    #[test]
+    fn mapped_view_reflects_the_borrowed_state() {
+        let mut input = Input::new();
+        assert!(!input.map_keys(actions).is_pressed(Action::Attack));
+
+        input.set_key(Key::C, Btn::Down);
+        assert!(input.map_keys(actions).is_pressed(Action::Attack));
+    }
+
+    #[test]
+    fn mapped_view_reports_edges_of_released_sources() {
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Down);
+        input.advance_frame();
+        input.set_key(Key::A, Btn::Up);
+
+        let view = input.map_keys(actions);
+
+        assert!(view.is_just_released(Action::Jump));
+        assert!(view.is_released(Action::Jump));
+        assert_eq!(view.state_of(Action::Jump), Some(Btn::Up));
+        assert_eq!(view.state_of(Action::Attack), None);
+        assert!(!view.any_pressed());
+    }
+
+    /// Estado con cada tecla de `keys` presionada y soltada, una cada 10ms.
+    fn tapped(keys: &[Key]) -> Input {
+        let mut input = Input::new();
+        for &key in keys {
+            input.set_key(key, Btn::Down);
+            input.update_time(input.current_time().unwrap() + ms(5));
+            input.set_key(key, Btn::Up);
+            input.update_time(input.current_time().unwrap() + ms(5));
+        }
+        input
+    }
+
+    #[test]
+    fn longer_combo_wins_at_equal_priority() {
+        let input = tapped(&[Key::A, Key::A, Key::A, Key::B, Key::B]);
+        let combos = [
+            PrioritizedCombo::new(vec![Key::A, Key::A, Key::A], 0),
+            PrioritizedCombo::new(vec![Key::A, Key::A, Key::A, Key::B, Key::B], 0),
+            PrioritizedCombo::new(vec![Key::C], 9),
+        ];
+
+        assert_eq!(input.best_match(&combos), Some(&combos[1]));
+    }
+
+    #[test]
+    fn higher_priority_beats_a_longer_combo() {
+        let input = tapped(&[Key::A, Key::A, Key::A, Key::B, Key::B]);
+        let combos = [
+            PrioritizedCombo::new(vec![Key::A, Key::A, Key::A, Key::B, Key::B], 0),
+            PrioritizedCombo::new(vec![Key::B, Key::B], 5),
+        ];
+
+        assert_eq!(input.best_match(&combos), Some(&combos[1]));
+    }
+
+    #[test]
+    fn full_tie_keeps_the_first_combo() {
+        let input = tapped(&[Key::A, Key::B]);
+        let combos = [PrioritizedCombo::new(vec![Key::A], 1), PrioritizedCombo::new(vec![Key::B], 1)];
+
+        assert_eq!(input.best_match(&combos), Some(&combos[0]));
+        assert_eq!(input.best_match(&combos[..0]), None);
+        assert_eq!(input.best_match(&[PrioritizedCombo::new(vec![Key::B, Key::A], 0)]), None);
+    }
+
+    #[test]
+    fn state_iter_yields_every_tracked_key() {
+        let input = play(&[(0, Key::A, Btn::Down), (0, Key::B, Btn::Held), (0, Key::C, Btn::Up)]);
+
+        let mut all: Vec<(Key, Btn)> = input.state_iter().collect();
+        all.sort();
+        let mut pressed: Vec<(Key, Btn)> = input.pressed_state_iter().collect();
+        pressed.sort();
+
+        assert_eq!(all, [(Key::A, Btn::Down), (Key::B, Btn::Held), (Key::C, Btn::Up)]);
+        assert_eq!(pressed, [(Key::A, Btn::Down), (Key::B, Btn::Held)]);
+        assert_eq!(input.state_of(Key::D), None);
+    }
+
+    #[test]
+    fn memory_footprint_scales_with_the_event_count() {
+        let event = core::mem::size_of::<Event>();
+        let mut input = Input::new();
+        assert_eq!(input.memory_footprint(), HistoryFootprint::default());
+
+        for _ in 0..4 {
+            input.set_key(Key::A, Btn::Down);
+        }
+        assert_eq!(input.memory_footprint(), HistoryFootprint { logical: 4 * event, allocated: 4 * event });
+
+        input.trim_history(1);
+        assert_eq!(input.memory_footprint().logical, event);
+    }
+
+    #[test]
+    fn any_and_all_follow_the_usual_convention() {
+        let input = play(&[(0, Key::A, Btn::Down), (0, Key::B, Btn::Held), (0, Key::C, Btn::Up)]);
+
+        assert!(input.is_any_pressed(&[Key::C, Key::B]));
+        assert!(!input.is_any_pressed(&[Key::C, Key::D]));
+        assert!(input.is_all_pressed(&[Key::A, Key::B]));
+        assert!(!input.is_all_pressed(&[Key::A, Key::C]));
+        assert!(!input.is_any_pressed(&[]));
+        assert!(input.is_all_pressed(&[]));
+        assert_eq!(input.is_all_pressed(&[Key::A, Key::C]), input.active_combo(&[Key::A, Key::C]));
+    }
+
+    #[test]
+    fn bare_key_is_suppressed_while_its_combo_is_held() {
+        // Key::C hace de Ctrl
+        let ctrl_a: &[Key] = &[Key::C, Key::A];
+        let mut input = Input::new();
+        input.set_key(Key::A, Btn::Down);
+        assert!(input.is_pressed_exclusive(Key::A, &[ctrl_a]));
+
+        input.set_key(Key::C, Btn::Down);
+        assert!(!input.is_pressed_exclusive(Key::A, &[ctrl_a]));
+        assert!(!input.is_pressed_exclusive(Key::A, &[&[Key::B], ctrl_a]));
+
+        input.set_key(Key::C, Btn::Up);
+        assert!(input.is_pressed_exclusive(Key::A, &[ctrl_a, &[]]));
+
+        input.set_key(Key::A, Btn::Up);
+        assert!(!input.is_pressed_exclusive(Key::A, &[]));
+    }
+
+    #[test]
+    fn pressed_iter_backs_the_pressed_queries() {
+        let input = play(&[(0, Key::A, Btn::Down), (0, Key::B, Btn::Held), (0, Key::C, Btn::Up)]);
+
+        let mut pressed: Vec<Key> = input.pressed_iter().collect();
+        pressed.sort();
+        let mut listed = input.keys_pressed();
+        listed.sort();
+
+        assert_eq!(pressed, [Key::A, Key::B]);
+        assert_eq!(listed, pressed);
+        assert_eq!(input.pressed_count(), 2);
+        assert!(input.any_pressed());
+        assert!(!play(&[(0, Key::C, Btn::Up)]).any_pressed());
+    }
+
+    /// Estado con los eventos `(microsegundo, tecla, estado)` aplicados en orden.
+    fn play_micros(events: &[(u64, Key, Btn)]) -> Input {
+        let mut input = Input::new();
+        for &(at, key, state) in events {
+            input.update_time(Duration::from_micros(at));
+            input.set_key(key, state);
+        }
+        input
+    }
+
+    #[test]
+    fn identical_timelines_hash_equal() {
+        let timeline = [(0, Key::A, Btn::Down), (16_000, Key::B, Btn::Down), (40_000, Key::A, Btn::Up)];
+
+        assert_eq!(play_micros(&timeline).timeline_hash(), play_micros(&timeline).timeline_hash());
+    }
+
+    #[test]
+    fn hash_ignores_sub_millisecond_jitter_and_the_origin() {
+        let reference = play_micros(&[(0, Key::A, Btn::Down), (16_000, Key::B, Btn::Down)]).timeline_hash();
+
+        let jittered = play_micros(&[(0, Key::A, Btn::Down), (16_300, Key::B, Btn::Down)]);
+        let shifted = play_micros(&[(5_000, Key::A, Btn::Down), (21_000, Key::B, Btn::Down)]);
+
+        assert_eq!(jittered.timeline_hash(), reference);
+        assert_eq!(shifted.timeline_hash(), reference);
+    }
+
+    #[test]
+    fn reordered_or_altered_timelines_hash_differently() {
+        let reference = play_micros(&[(0, Key::A, Btn::Down), (16_000, Key::B, Btn::Down)]).timeline_hash();
+
+        let reordered = play_micros(&[(0, Key::B, Btn::Down), (16_000, Key::A, Btn::Down)]);
+        let restated = play_micros(&[(0, Key::A, Btn::Down), (16_000, Key::B, Btn::Held)]);
+        let delayed = play_micros(&[(0, Key::A, Btn::Down), (17_000, Key::B, Btn::Down)]);
+
+        assert_ne!(reordered.timeline_hash(), reference);
+        assert_ne!(restated.timeline_hash(), reference);
+        assert_ne!(delayed.timeline_hash(), reference);
+    }
+
+    #[test]
+    fn state_of_reads_the_raw_state() {
+        let mut input = Faulty::<Healthy>::default();
+        input.set_key(Key::A, Btn::Held);
+        input.set_key(Key::B, Btn::Up);
+
+        assert_eq!(input.state_of(Key::A), Some(Btn::Held));
+        assert_eq!(input.state_of(Key::B), Some(Btn::Up));
+        assert_eq!(input.state_of(Key::C), None);
+        assert!(input.is_pressed(Key::A));
+        assert!(input.is_released(Key::C));
+    }
+
+    #[test]
+    fn held_for_needs_the_full_threshold() {
+        let mut input = play(&[(0, Key::A, Btn::Down)]);
+        input.update_time(ms(799));
+        assert!(!input.held_for(Key::A, ms(800)));
+
+        input.update_time(ms(800));
+        assert!(input.held_for(Key::A, ms(800)));
+        assert!(!input.held_for(Key::B, Duration::ZERO));
+    }
+
+    #[test]
+    fn held_ratio_is_clamped_to_one() {
+        let mut input = play(&[(0, Key::A, Btn::Down)]);
+        input.update_time(ms(500));
+        assert_eq!(input.held_ratio(Key::A, ms(2000)), 0.25);
+
+        input.update_time(ms(3000));
+        assert_eq!(input.held_ratio(Key::A, ms(2000)), 1.0);
+        assert_eq!(input.held_ratio(Key::A, Duration::ZERO), 1.0);
+        assert_eq!(input.held_ratio(Key::B, ms(2000)), 0.0);
+    }
+
+    #[test]
+    fn shortcut_fires_only_on_the_frame_the_key_goes_down() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.advance_frame();
+        input.press(Key::B);
+        assert!(input.combo_with_modifiers(&[Key::A], Key::B));
+        assert!(input.active_combo(&[Key::A, Key::B]));
+
+        input.advance_frame();
+        assert!(!input.combo_with_modifiers(&[Key::A], Key::B));
+        assert!(input.active_combo(&[Key::A, Key::B]));
+    }
+
+    #[test]
+    fn shortcut_needs_every_modifier_held() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.press(Key::C);
+        assert!(!input.combo_with_modifiers(&[Key::A, Key::B], Key::C));
+        assert!(input.combo_with_modifiers(&[], Key::C));
+    }
+
+    #[test]
+    fn edge_lists_hold_only_this_frames_edges() {
+        let mut input = Input::new();
+        input.press(Key::A);
+        input.press(Key::B);
+        input.advance_frame();
+        input.press(Key::C);
+        input.release(Key::B);
+
+        assert_eq!(input.just_pressed_keys(), [Key::C]);
+        assert_eq!(input.just_released_keys(), [Key::B]);
+
+        input.advance_frame();
+        assert!(input.just_pressed_keys().is_empty());
+        assert!(input.just_released_keys().is_empty());
+    }
+
+    #[test]
+    fn serializable_events_are_relative_to_the_origin() {
+        let event = Event { key: Key::B, state: Btn::Down, at: ms(1250) };
+
+        let record = event.to_serializable::<MockClock>(ms(1000));
+        assert_eq!(record, SerializableEvent { key: Key::B, state: Btn::Down, offset_micros: 250_000 });
+        assert_eq!(event.to_serializable::<MockClock>(ms(2000)).offset_micros, 0);
+    }
+
+    #[test]
+    fn serializable_offsets_saturate() {
+        let event = Event { key: Key::A, state: Btn::Up, at: Duration::MAX };
+
+        assert_eq!(event.to_serializable::<MockClock>(Duration::ZERO).offset_micros, u64::MAX);
+    }
+
+    #[test]
+    fn pattern_slots_accept_alternatives_and_wildcards() {
+        use SeqToken::*;
+        let input = tapped(&[Key::A, Key::C, Key::B, Key::D]);
+
+        assert!(input.match_pattern(&[Exact(Key::A), AnyOf(&[Key::B, Key::C]), Wildcard, Exact(Key::D)]));
+        assert!(!input.match_pattern(&[Exact(Key::A), AnyOf(&[Key::B, Key::D])]));
+        assert!(input.match_pattern(&[Wildcard, Wildcard, Wildcard, Wildcard]));
+        assert!(!input.match_pattern(&[Wildcard; 5]));
+    }
+
+    #[test]
+    fn repeat_expands_to_consecutive_presses() {
+        use SeqToken::*;
+        let input = tapped(&[Key::A, Key::A, Key::A, Key::B]);
+
+        assert!(input.match_pattern(&[Repeat(Key::A, 3), Exact(Key::B)]));
+        assert!(!input.match_pattern(&[Repeat(Key::A, 4)]));
+        assert!(input.match_pattern(&[Exact(Key::A), Repeat(Key::C, 0), Exact(Key::B)]));
+        assert!(!input.match_pattern(&[Repeat(Key::A, 0)]));
+        assert!(!input.match_pattern(&[]));
+    }
+
+    #[test]
+    fn match_sequence_is_an_exact_pattern() {
+        use SeqToken::*;
+        let input = tapped(&[Key::A, Key::B, Key::C]);
+
+        assert_eq!(input.match_sequence(&[Key::B, Key::C]), input.match_pattern(&[Exact(Key::B), Exact(Key::C)]));
+        assert!(input.match_sequence(&[Key::B, Key::C]));
+        assert!(!input.match_sequence(&[Key::A, Key::C]));
+    }
+
+    #[test]
+    fn loose_matching_counts_every_event_as_gap_by_default() {
+        let input = tapped(&[Key::A, Key::C, Key::B]);
+
+        // Entre A↓ y B↓ quedan A↑, C↓ y C↑.
+        assert!(input.match_sequence_loose(&[Key::A, Key::B], 3));
+        assert!(!input.match_sequence_loose(&[Key::A, Key::B], 2));
+        assert!(input.match_sequence_loose(&[Key::A, Key::C, Key::B], 1));
+        assert!(!input.match_sequence_loose(&[], 10));
+    }
+
+    #[test]
+    fn loose_matching_can_count_only_presses() {
+        let input = tapped(&[Key::A, Key::C, Key::B]);
+
+        assert!(input.match_sequence_loose_by(&[Key::A, Key::B], 1, GapCounting::PressesOnly));
+        assert!(!input.match_sequence_loose_by(&[Key::A, Key::B], 0, GapCounting::PressesOnly));
+        assert!(input.match_sequence_loose_by(&[Key::A, Key::C], 0, GapCounting::PressesOnly));
+    }
+
+    #[test]
+    fn releases_never_match_a_step() {
+        let input = play(&[(0, Key::A, Btn::Down), (10, Key::B, Btn::Up), (20, Key::C, Btn::Down)]);
+
+        assert!(!input.match_sequence_loose(&[Key::A, Key::B], 5));
+        assert!(input.match_sequence_loose(&[Key::A, Key::C], 1));
+        assert!(!input.match_sequence_loose(&[Key::A, Key::C], 0));
+    }
+
+    #[test]
+    fn press_events_skip_releases_and_held_reports() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (10, Key::A, Btn::Held),
+            (20, Key::A, Btn::Up),
+            (30, Key::B, Btn::Down),
+        ]);
+
+        let presses: Vec<Key> = input.press_events().map(|event| event.key()).collect();
+        assert_eq!(presses, [Key::A, Key::B]);
+        assert!(input.match_sequence(&[Key::A, Key::B]));
+        assert!(!input.match_sequence(&[Key::A, Key::A]));
+    }
+
+    #[test]
+    fn export_is_relative_to_the_first_event() {
+        let input = play(&[(1000, Key::A, Btn::Down), (1016, Key::B, Btn::Down), (1100, Key::A, Btn::Up)]);
+
+        let records = input.export_history();
+        let offsets: Vec<u64> = records.iter().map(|record| record.offset_micros).collect();
+        assert_eq!(offsets, [0, 16_000, 100_000]);
+        assert_eq!((records[2].key, records[2].state), (Key::A, Btn::Up));
+        assert!(Input::new().export_history().is_empty());
+    }
+
+    #[test]
+    fn import_replaces_the_history_and_keeps_the_intervals() {
+        let recorded = play(&[(1000, Key::A, Btn::Down), (1016, Key::B, Btn::Down), (1100, Key::A, Btn::Up)]);
+        let records = recorded.export_history();
+
+        let mut replay = play(&[(5000, Key::C, Btn::Down)]);
+        replay.import_history(&records);
+
+        assert_eq!(replay.export_history(), records);
+        assert_eq!(replay.history()[0].timestamp(), ms(5000));
+        assert_eq!(replay.press_intervals(Key::B), recorded.press_intervals(Key::B));
+        assert!(replay.match_sequence(&[Key::A, Key::B]));
+        // El estado vivo no cambia.
+        assert!(replay.is_pressed(Key::C));
+        assert!(replay.is_released(Key::A));
+    }
+
+    #[test]
+    fn instant_replay_reaches_the_final_state() {
+        let recorded = play(&[(0, Key::A, Btn::Down), (10_000, Key::B, Btn::Down), (20_000, Key::A, Btn::Up)]);
+
+        let started = std::time::Instant::now();
+        let mut target = Input::new();
+        recorded.replay_into(&mut target, 0.0);
+        let mut other = Faulty::<Healthy>::default();
+        recorded.replay_into(&mut other, f32::NAN);
+
+        assert!(started.elapsed() < ms(10_000));
+        assert_eq!(target.keys_pressed(), [Key::B]);
+        // Sin advance_frame, los flancos quedan acumulados.
+        assert!(target.is_just_released(Key::A));
+        assert!(other.is_pressed(Key::B));
+        assert!(other.is_released(Key::A));
+    }
+
+    #[test]
+    fn realtime_replay_waits_the_scaled_intervals() {
+        let recorded = play(&[(0, Key::A, Btn::Down), (40, Key::A, Btn::Up)]);
+
+        let started = std::time::Instant::now();
+        let mut target = Input::new();
+        recorded.replay_into(&mut target, 2.0);
+
+        assert!(started.elapsed() >= ms(20));
+        assert!(target.is_released(Key::A));
+        assert_eq!(target.history().len(), 2);
+    }
+
+    #[test]
+    fn trim_older_than_keeps_events_at_the_boundary() {
+        let mut input = play(&[(0, Key::A, Btn::Down), (100, Key::B, Btn::Down), (200, Key::C, Btn::Down)]);
+        input.update_time(ms(300));
+
+        input.trim_older_than(ms(200));
+
+        let kept: Vec<Key> = input.history().iter().map(|event| event.key()).collect();
+        assert_eq!(kept, [Key::B, Key::C]);
+        assert_eq!(input.keys_in_last(ms(1000)), [Key::B, Key::C]);
+    }
+
+    #[test]
+    fn trim_older_than_measures_age_against_now() {
+        let mut input = play(&[(0, Key::A, Btn::Down), (100, Key::B, Btn::Down)]);
+        input.update_time(ms(1000));
+        input.trim_older_than(ms(500));
+        assert!(input.history().is_empty());
+
+        let mut empty = Input::new();
+        empty.trim_older_than(Duration::ZERO);
+        assert!(empty.history().is_empty());
+    }
+
+    #[test]
+    fn simultaneous_peak_counts_overlapping_holds() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (5, Key::B, Btn::Down),
+            (8, Key::C, Btn::Down),
+            (20, Key::A, Btn::Up),
+            (30, Key::D, Btn::Down),
+        ]);
+
+        assert_eq!(input.simultaneous_peak(Duration::ZERO), 3);
+        assert_eq!(Input::new().simultaneous_peak(ms(100)), 0);
+    }
+
+    #[test]
+    fn wider_windows_tolerate_rolled_presses() {
+        let rolled = play(&[
+            (0, Key::A, Btn::Down),
+            (10, Key::A, Btn::Up),
+            (12, Key::B, Btn::Down),
+            (20, Key::B, Btn::Up),
+            (22, Key::C, Btn::Down),
+            (25, Key::C, Btn::Held),
+        ]);
+
+        assert_eq!(rolled.simultaneous_peak(Duration::ZERO), 1);
+        assert_eq!(rolled.simultaneous_peak(ms(5)), 2);
+        assert_eq!(rolled.simultaneous_peak(ms(15)), 3);
+    }
+
+    #[test]
+    fn matcher_checks_keys_and_per_step_windows() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (50, Key::C, Btn::Down),
+            (60, Key::C, Btn::Up),
+            (300, Key::D, Btn::Down),
+        ]);
+        let motion = SequenceMatcher::new().exact(Key::A).any_of(&[Key::B, Key::C]).within(ms(100));
+
+        assert_eq!(motion.len(), 2);
+        assert!(input.matches(&motion));
+        assert!(input.matches(&motion.clone().exact(Key::D)));
+        assert!(!input.matches(&motion.exact(Key::D).within(ms(200))));
+        assert!(!input.matches(&SequenceMatcher::new().exact(Key::A).exact(Key::D)));
+    }
+
+    #[test]
+    fn within_on_an_empty_matcher_is_ignored() {
+        let input = play(&[(0, Key::A, Btn::Down)]);
+        let empty = SequenceMatcher::<Key>::new().within(ms(10));
+
+        assert!(empty.is_empty());
+        assert!(!input.matches(&empty));
+        assert!(input.matches(&empty.exact(Key::A).within(ms(0))));
+    }
+
+    #[test]
+    fn release_sequence_follows_key_ups_in_order() {
+        // Cargar atrás (A), soltar y rematar con C.
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (900, Key::C, Btn::Down),
+            (950, Key::A, Btn::Up),
+            (1000, Key::C, Btn::Up),
+        ]);
+
+        assert!(input.match_release_sequence(&[Key::A, Key::C], ms(50)));
+        assert!(!input.match_release_sequence(&[Key::A, Key::C], ms(49)));
+        assert!(!input.match_release_sequence(&[Key::C, Key::A], ms(1000)));
+        assert!(!input.match_release_sequence(&[], ms(1000)));
+        // Las pulsaciones no cuentan como liberaciones.
+        assert!(input.match_sequence_in_time(&[Key::A, Key::C], ms(900)));
+        assert!(!input.match_release_sequence(&[Key::C, Key::A, Key::C], ms(1000)));
+    }
+
+    #[test]
+    fn histogram_is_sorted_by_count_then_recency() {
+        let mut input = tapped(&[Key::A, Key::B, Key::A, Key::C, Key::A]);
+        input.set_key(Key::D, Btn::Up);
+
+        assert_eq!(input.press_histogram(), [(Key::A, 3), (Key::C, 1), (Key::B, 1)]);
+        assert_eq!(input.press_histogram(), input.press_histogram());
+        assert_eq!(input.most_frequent_key(), Some(Key::A));
+        assert!(Input::new().press_histogram().is_empty());
+    }
+
+    #[test]
+    fn apm_counts_presses_in_the_trailing_window() {
+        let mut input = play(&[
+            (0, Key::A, Btn::Down),
+            (500, Key::B, Btn::Down),
+            (600, Key::B, Btn::Up),
+            (1000, Key::C, Btn::Down),
+            (1500, Key::D, Btn::Down),
+        ]);
+        input.update_time(ms(2000));
+
+        assert_eq!(input.actions_per_minute(ms(1000)), 120.0);
+    }
+
+    #[test]
+    fn apm_of_a_short_history_uses_the_elapsed_span() {
+        let mut input = play(&[(0, Key::A, Btn::Down), (500, Key::B, Btn::Down)]);
+        input.update_time(ms(1000));
+
+        assert_eq!(input.actions_per_minute(ms(60_000)), 120.0);
+        assert_eq!(Input::new().actions_per_minute(ms(1000)), 0.0);
+        assert_eq!(play(&[(0, Key::A, Btn::Down)]).actions_per_minute(ms(1000)), 0.0);
+    }
+
+    fn taps_at(times: &[u64]) -> Input {
+        let events: Vec<(u64, Key, Btn)> = times
+            .iter()
+            .flat_map(|&at| [(at, Key::A, Btn::Down), (at + 10, Key::A, Btn::Up)])
+            .collect();
+        play(&events)
+    }
+
+    #[test]
+    fn steady_taps_have_no_variance() {
+        let steady = taps_at(&[0, 100, 200, 300]);
+
+        assert_eq!(steady.press_intervals(Key::A), [ms(100); 3]);
+        assert_eq!(steady.average_press_interval(Key::A), Some(ms(100)));
+        assert_eq!(steady.tap_variance(Key::A), Some(0.0));
+    }
+
+    #[test]
+    fn mashing_has_a_high_variance() {
+        let mashing = taps_at(&[0, 50, 250, 300]);
+
+        assert_eq!(mashing.press_intervals(Key::A), [ms(50), ms(200), ms(50)]);
+        let variance = mashing.tap_variance(Key::A).unwrap();
+        assert!((variance - 0.005).abs() < 1e-6, "{variance}");
+        assert_eq!(taps_at(&[0]).tap_variance(Key::A), None);
+        assert_eq!(mashing.tap_variance(Key::B), None);
+    }
+
+    #[test]
+    fn since_last_event_tells_never_from_just_now() {
+        assert_eq!(Input::new().since_last_event(), None);
+
+        let mut input = play(&[(100, Key::A, Btn::Down)]);
+        assert_eq!(input.since_last_event(), Some(Duration::ZERO));
+
+        input.update_time(ms(350));
+        assert_eq!(input.since_last_event(), Some(ms(250)));
+        assert_eq!(input.since_key_pressed(Key::A), Some(ms(250)));
+        assert_eq!(input.since_key_pressed(Key::B), None);
+    }
+
+    #[test]
+    fn range_bounds_are_inclusive_and_ordered() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (100, Key::B, Btn::Down),
+            (200, Key::C, Btn::Down),
+            (300, Key::D, Btn::Down),
+        ]);
+
+        let keys = |events: Vec<&Event>| events.iter().map(|event| event.key()).collect::<Vec<_>>();
+        assert_eq!(keys(input.events_in_range(ms(100), ms(200))), [Key::B, Key::C]);
+        assert_eq!(keys(input.events_in_range(ms(101), ms(199))), []);
+        assert_eq!(keys(input.events_in_range(ms(0), ms(1000))).len(), 4);
+        assert!(input.events_in_range(ms(200), ms(100)).is_empty());
+    }
+
+    #[test]
+    fn find_events_filters_in_order() {
+        let input = tapped(&[Key::A, Key::B, Key::A]);
+
+        let releases: Vec<Duration> = input
+            .find_events(|event| event.key() == Key::A && event.is_release())
+            .map(|event| event.timestamp())
+            .collect();
+        assert_eq!(releases, [ms(5), ms(25)]);
+    }
+
+    #[test]
+    fn find_events_is_lazy() {
+        let input = tapped(&[Key::A, Key::B, Key::C, Key::D]);
+        let visited = core::cell::Cell::new(0);
+
+        let first = input
+            .find_events(|event| {
+                visited.set(visited.get() + 1);
+                event.key() == Key::B
+            })
+            .next();
+
+        assert_eq!(first.map(|event| event.state()), Some(Btn::Down));
+        assert_eq!(visited.get(), 3);
+    }
+
+    #[test]
+    fn keys_ever_pressed_deduplicates_in_first_appearance_order() {
+        let mut input = tapped(&[Key::C, Key::A, Key::C, Key::B, Key::A]);
+        input.set_key(Key::D, Btn::Up);
+
+        assert_eq!(input.keys_ever_pressed(), [Key::C, Key::A, Key::B]);
+        assert!(Input::new().keys_ever_pressed().is_empty());
+    }
+
+    #[test]
+    fn reaction_is_measured_from_the_latest_cue() {
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (100, Key::B, Btn::Down),
+            (500, Key::A, Btn::Up),
+            (600, Key::A, Btn::Down),
+            (650, Key::B, Btn::Up),
+            (780, Key::B, Btn::Held),
+            (840, Key::B, Btn::Down),
+            (900, Key::B, Btn::Down),
+        ]);
+
+        assert_eq!(input.reaction_time(Key::A, Key::B), Some(ms(240)));
+        // La última pulsación de B es la señal: no hay respuesta después.
+        assert_eq!(input.reaction_time(Key::B, Key::B), None);
+    }
+
+    #[test]
+    fn reaction_without_a_later_response_is_none() {
+        let input = play(&[(0, Key::B, Btn::Down), (100, Key::A, Btn::Down), (200, Key::C, Btn::Down)]);
+
+        assert_eq!(input.reaction_time(Key::A, Key::B), None);
+        assert_eq!(input.reaction_time(Key::D, Key::C), None);
+        assert_eq!(input.reaction_time(Key::A, Key::C), Some(ms(100)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exported_history_roundtrips_through_json() {
+        let input = play(&[(1000, Key::A, Btn::Down), (1016, Key::A, Btn::Up)]);
+        let records = input.export_history();
+
+        let json = serde_json::to_string(&records).unwrap();
+        assert_eq!(json, r#"[{"key":"A","state":"Down","offset_micros":0},{"key":"A","state":"Up","offset_micros":16000}]"#);
+        assert_eq!(serde_json::from_str::<Vec<HistoryRecord<Key, Btn>>>(&json).unwrap(), records);
+    }
+
+    #[test]
+    fn chord_sequence_needs_each_key_within_its_window_while_holding() {
+        let steps = [(Key::A, ms(0)), (Key::B, ms(200)), (Key::C, ms(100))];
+
+        let input = play(&[
+            (0, Key::A, Btn::Down),
+            (150, Key::D, Btn::Down),
+            (180, Key::B, Btn::Down),
+            (260, Key::C, Btn::Down),
+        ]);
+        // D intercalada no rompe el acorde.
+        assert!(input.chord_sequence(&steps));
+
+        let late = play(&[(0, Key::A, Btn::Down), (250, Key::B, Btn::Down), (300, Key::C, Btn::Down)]);
+        assert!(!late.chord_sequence(&steps));
+    }
+
+    #[test]
+    fn chord_sequence_breaks_when_a_previous_key_is_released() {
+        let steps = [(Key::A, ms(0)), (Key::B, ms(200))];
+
+        let released = play(&[(0, Key::A, Btn::Down), (50, Key::A, Btn::Up), (100, Key::B, Btn::Down)]);
+        assert!(!released.chord_sequence(&steps));
+
+        // Un segundo intento posterior en el historial sí cuenta.
+        let retried = play(&[
+            (0, Key::A, Btn::Down),
+            (50, Key::A, Btn::Up),
+            (500, Key::A, Btn::Down),
+            (520, Key::B, Btn::Down),
+        ]);
+        assert!(retried.chord_sequence(&steps));
+        assert!(!retried.chord_sequence(&[]));
+    }
+
+    #[test]
+    fn primitives_alone_answer_every_query_like_the_mock() {
+        type Observed = (Vec<Key>, Vec<Key>, usize, bool, Option<Btn>, bool, usize, Option<Key>);
+
+        fn observe<I: WithHistoryExt<Key, Btn, Event>>(input: &I) -> Observed {
+            let mut pressed = input.keys_pressed();
+            pressed.sort();
+            let mut just = input.just_pressed_keys();
+            just.sort();
+            (
+                pressed,
+                just,
+                input.pressed_count(),
+                input.is_any_pressed(&[Key::C, Key::D]),
+                input.state_of(Key::C),
+                input.active_combo(&[Key::A, Key::B]),
+                input.total_presses(Key::A),
+                input.most_frequent_key(),
+            )
+        }
+
+        let mut minimal = Faulty::<Healthy>::default();
+        let mut mock = Input::new();
+        let frames: [&[(Key, Btn)]; 3] = [
+            &[(Key::A, Btn::Down), (Key::B, Btn::Down)],
+            &[(Key::A, Btn::Up), (Key::C, Btn::Held)],
+            &[(Key::A, Btn::Down), (Key::B, Btn::Up)],
+        ];
+
+        // `Faulty<Healthy>` solo implementa los métodos requeridos; el mock
+        // sobrescribe varios de los que tienen implementación por defecto. No se
+        // compara `last_pressed`, que el mock documenta distinto a propósito.
+        for updates in frames {
+            minimal.set_keys(updates);
+            mock.set_keys(updates);
+            assert_eq!(observe(&minimal), observe(&mock));
+            minimal.advance_frame();
+            mock.advance_frame();
+        }
+    }
+
+    #[test]
+    fn history_access_alone_unlocks_every_sub_trait() {
+        fn recent<I: TemporalQueryExt<Key, Btn, Event>>(input: &I) -> usize {
+            input.count_recent(Key::A, 4)
+        }
+        fn combo<I: ComboDetectionExt<Key, Btn, Event>>(input: &I) -> bool {
+            input.match_sequence(&[Key::A, Key::B])
+        }
+        fn presses<I: HistoryStatsExt<Key, Btn, Event>>(input: &I) -> usize {
+            input.total_presses(Key::A)
+        }
+        fn full<I: WithHistoryExt<Key, Btn, Event>>(input: &I) -> (usize, bool, usize) {
+            (recent(input), combo(input), presses(input))
+        }
+
+        // `Faulty` solo implementa `HistoryAccessExt`; el resto llega por las
+        // implementaciones genéricas.
+        let updates = [(Key::A, Btn::Down), (Key::A, Btn::Up), (Key::A, Btn::Down), (Key::B, Btn::Down)];
+        let mut input = Faulty::<Healthy>::default();
+        input.set_keys(&updates);
+        let mut mock = Input::new();
+        mock.set_keys(&updates);
+
+        assert_eq!(full(&input), (2, true, 2));
+        assert_eq!(full(&input), full(&mock));
+    }
+
+    #[test]
+    fn events_classify_presses_releases_and_held_reports() {
+        let event = |state| Event { key: Key::A, state, at: ms(0) };
+
+        assert!(event(Btn::Down).is_press() && !event(Btn::Down).is_release());
+        assert!(!event(Btn::Up).is_press() && event(Btn::Up).is_release());
+        // Un reporte de tecla mantenida no es ni pulsación ni liberación.
+        assert!(!event(Btn::Held).is_press() && !event(Btn::Held).is_release());
+    }
+
+    #[test]
+    fn two_state_models_get_press_and_release_from_is_down() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Switch(bool);
+
+        impl StateSemantics for Switch {
+            fn is_down(&self) -> bool {
+                self.0
+            }
+
+            fn released() -> Self {
+                Switch(false)
+            }
+        }
+
+        assert!(Switch(true).is_press() && !Switch(true).is_release());
+        assert!(!Switch(false).is_press() && Switch(false).is_release());
+        assert_eq!(Switch::released(), Switch(false));
+    }
+
+    #[test]
+    fn dedup_keeps_the_earliest_event_of_each_run() {
+        let mut input = play(&[
+            (0, Key::A, Btn::Down),
+            (10, Key::A, Btn::Down),
+            (20, Key::A, Btn::Up),
+            (25, Key::A, Btn::Up),
+            (30, Key::B, Btn::Down),
+            (40, Key::A, Btn::Down),
+        ]);
+        assert!(input.has_duplicates());
+        assert_eq!(input.total_presses(Key::A), 3);
+
+        input.dedup_consecutive();
+
+        assert!(!input.has_duplicates());
+        assert_eq!(input.total_presses(Key::A), 2);
+        assert_eq!(input.history().iter().map(|event| event.at).collect::<Vec<_>>(), [ms(0), ms(20), ms(30), ms(40)]);
+    }
+
+    #[test]
+    fn dedup_only_collapses_adjacent_repeats() {
+        let mut input = play(&[(0, Key::A, Btn::Down), (10, Key::A, Btn::Down), (20, Key::B, Btn::Down), (30, Key::A, Btn::Down)]);
+        input.update_time(ms(500));
+
+        input.dedup_consecutive();
+
+        let kept: Vec<_> = input.history().iter().map(|event| (event.key, event.state, event.at)).collect();
+        assert_eq!(kept, [(Key::A, Btn::Down, ms(0)), (Key::B, Btn::Down, ms(20)), (Key::A, Btn::Down, ms(30))]);
+        assert!(!input.has_duplicates());
+    }
+
+    #[test]
+    fn recent_most_frequent_key_ignores_old_spam() {
+        let mut input = play(&[
+            (0, Key::A, Btn::Down),
+            (10, Key::A, Btn::Down),
+            (20, Key::A, Btn::Down),
+            (5000, Key::B, Btn::Down),
+            (5100, Key::B, Btn::Down),
+            (5150, Key::C, Btn::Down),
+        ]);
+        input.update_time(ms(5200));
+
+        assert_eq!(input.most_frequent_key(), Some(Key::A));
+        assert_eq!(input.most_frequent_key_recent(ms(1000)), Some(Key::B));
+        // Con una sola pulsación de cada una en la ventana gana la más reciente.
+        assert_eq!(input.most_frequent_key_recent(ms(150)), Some(Key::C));
+
+        input.update_time(ms(10_000));
+        assert_eq!(input.most_frequent_key_recent(ms(1000)), None);
+    }
+
+    #[test]
+    fn default_remap_reports_no_support_and_changes_nothing() {
+        let mut input = Faulty::<Healthy>::default();
+
+        assert!(!input.remap(Key::A, Key::B));
+        assert!(!input.clear_remaps());
+
+        input.set_key(Key::A, Btn::Down);
+        assert!(input.is_pressed(Key::A) && !input.is_pressed(Key::B));
+    }
+
+    #[test]
+    fn set_keys_applies_a_report_in_slice_order() {
+        let mut input = Faulty::<Healthy>::default();
+        input.set_key(Key::C, Btn::Down);
+        input.advance_frame();
+
+        input.set_keys(&[(Key::A, Btn::Down), (Key::C, Btn::Up), (Key::B, Btn::Down), (Key::A, Btn::Up)]);
+
+        // A se presionó y soltó en el mismo reporte: quedan los dos flancos.
+        assert!(input.is_just_press(Key::A) && input.is_just_released(Key::A));
+        assert!(input.is_just_released(Key::C));
+        assert_eq!(input.keys_pressed(), [Key::B]);
+        let order: Vec<_> = input.history()[1..].iter().map(|event| (event.key, event.state)).collect();
+        assert_eq!(order, [(Key::A, Btn::Down), (Key::C, Btn::Up), (Key::B, Btn::Down), (Key::A, Btn::Up)]);
+    }
+
+    #[test]
+    fn pressed_count_follows_presses_holds_and_releases() {
+        let mut input = Faulty::<Healthy>::default();
+        assert_eq!(input.pressed_count(), 0);
+
+        input.set_keys(&[(Key::A, Btn::Down), (Key::B, Btn::Down), (Key::C, Btn::Down)]);
+        assert_eq!(input.pressed_count(), 3);
+
+        input.advance_frame();
+        input.set_keys(&[(Key::A, Btn::Held), (Key::B, Btn::Up)]);
+        // Una tecla mantenida sigue contando; la recién soltada no.
+        assert_eq!(input.pressed_count(), 2);
+
+        input.release_all();
+        assert_eq!(input.pressed_count(), 0);
+    }
+
+    /// Evento que puede agrupar varias teclas.
+    #[derive(Debug, Clone, PartialEq, Hash)]
+    struct ChordKeyEvent {
+        keys: Vec<Key>,
+        state: Btn,
+        at: Duration,
+    }
+
+    fn chord_event(at: u64, key: Key, state: Btn) -> ChordKeyEvent {
+        ChordKeyEvent { keys: vec![key], state, at: ms(at) }
+    }
+
+    impl InputEvent for ChordKeyEvent {
+        type Key = Key;
+        type State = Btn;
+        type Time = Duration;
+
+        fn key(&self) -> Key {
+            self.keys[0]
+        }
+
+        fn state(&self) -> Btn {
+            self.state
+        }
+
+        fn timestamp(&self) -> Duration {
+            self.at
+        }
+    }
+
+    impl ChordEvent for ChordKeyEvent {
+        fn chord_keys(&self) -> &[Key] {
+            &self.keys
+        }
+
+        fn merge(events: &[Self]) -> Self {
+            ChordKeyEvent {
+                keys: events.iter().flat_map(|event| event.keys.iter().copied()).collect(),
+                state: events[0].state,
+                at: events[0].at,
+            }
+        }
+    }
+
+    #[test]
+    fn near_simultaneous_presses_collapse_into_one_chord() {
+        let events = [
+            chord_event(0, Key::A, Btn::Down),
+            chord_event(1, Key::B, Btn::Down),
+            chord_event(2, Key::C, Btn::Down),
+            chord_event(50, Key::D, Btn::Down),
+        ];
+
+        let collapsed = ChordKeyEvent::collapse::<MockClock>(&events, ms(5));
+
+        assert_eq!(collapsed.len(), 2);
+        assert!(collapsed[0].is_chord());
+        assert_eq!(collapsed[0].chord_keys(), [Key::A, Key::B, Key::C]);
+        assert_eq!(collapsed[0].key(), Key::A);
+        assert_eq!(collapsed[0].timestamp(), ms(0));
+        assert_eq!(collapsed[1], events[3]);
+    }
+
+    #[test]
+    fn different_states_never_share_a_chord() {
+        let events = [
+            chord_event(0, Key::A, Btn::Down),
+            chord_event(1, Key::A, Btn::Up),
+            chord_event(2, Key::B, Btn::Up),
+        ];
+
+        let collapsed = ChordKeyEvent::collapse::<MockClock>(&events, ms(5));
+
+        assert_eq!(collapsed.len(), 2);
+        assert!(!collapsed[0].is_chord());
+        assert_eq!(collapsed[1].chord_keys(), [Key::A, Key::B]);
+    }
+}
@@ -0,0 +1,180 @@
+use core::hash::Hash;
+
+use crate::{InputStateExt, StateSemantics};
+
+/// # Trait `StickyKeysExt`
+///
+/// Opción de accesibilidad de **teclas especiales persistentes** (*sticky keys*):
+/// una tecla marcada como persistente, normalmente un modificador, se queda
+/// presionada tras soltarla hasta que se pulsa otra tecla.
+///
+/// Permite hacer `Shift + A` pulsando primero `Shift` y después `A`, sin mantener las
+/// dos a la vez. Al implementarse en el estado, todas las consultas de
+/// [`InputStateExt`] (y los combos construidos sobre ellas) ven la tecla presionada
+/// sin que el juego tenga que saber nada.
+///
+/// ## Comportamiento
+/// - Al presionar una tecla persistente, queda **enganchada**:
+///   [`is_pressed`](InputStateExt::is_pressed) sigue retornando `true` aunque se
+///   suelte físicamente.
+/// - Al presionar una tecla **no** persistente, las teclas enganchadas se mantienen
+///   durante ese frame, para que combos como
+///   [`active_combo`](InputStateExt::active_combo) las vean junto a ella, y se
+///   liberan en el siguiente [`advance_frame`](InputStateExt::advance_frame). La
+///   liberación genera el flanco de
+///   [`is_just_released`](InputStateExt::is_just_released) en el frame siguiente y
+///   se registra como una liberación normal, igual que un
+///   [`set_key`](InputStateExt::set_key).
+/// - Varias teclas persistentes se pueden enganchar a la vez (`Ctrl`, luego
+///   `Shift`, luego `S`).
+/// - Presionar de nuevo una tecla enganchada la libera.
+///
+/// ## Parámetros genéricos
+/// - `K`, `S`: Tecla y estado del [`InputStateExt`] subyacente.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// if settings.sticky_keys {
+///     for modifier in [KeyCode::ShiftLeft, KeyCode::ControlLeft, KeyCode::AltLeft] {
+///         input.set_sticky(modifier, true);
+///     }
+/// }
+///
+/// // En la UI, cada frame:
+/// hud.set_indicator("Shift", input.is_sticky_latched(KeyCode::ShiftLeft));
+/// ```
+pub trait StickyKeysExt<K, S>: InputStateExt<K, S>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    /// Marca `key` como persistente (`sticky = true`) o normal (`false`).
+    ///
+    /// Desmarcar una tecla enganchada la libera.
+    fn set_sticky(&mut self, key: K, sticky: bool);
+
+    /// Retorna `true` si `key` está **enganchada**: se presionó siendo persistente y
+    /// aún no se liberó automáticamente, esté o no mantenida físicamente.
+    ///
+    /// Pensado para los indicadores de la UI.
+    fn is_sticky_latched(&self, key: K) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::HistoryAccessExt;
+    use crate::testing::fixtures::{Btn, Input, Key, delegate_input_state};
+
+    /// Estado de referencia con teclas persistentes.
+    #[derive(Default)]
+    struct Sticky {
+        input: Input,
+        sticky: Vec<Key>,
+        latched: Vec<Key>,
+        /// Se presionó una tecla normal: las enganchadas se liberan al cerrar el frame.
+        release_pending: bool,
+    }
+
+    impl Sticky {
+        fn unlatch(&mut self, key: Key) {
+            self.latched.retain(|latched| *latched != key);
+            self.input.set_key(key, Btn::Up);
+        }
+
+        fn feed(&mut self, key: Key, state: Btn) {
+            if !self.sticky.contains(&key) {
+                self.input.set_key(key, state);
+                self.release_pending |= state.is_press() && !self.latched.is_empty();
+                return;
+            }
+            if state.is_press() && self.latched.contains(&key) {
+                self.unlatch(key);
+            } else if state.is_press() {
+                self.latched.push(key);
+                self.input.set_key(key, state);
+            }
+            // Las liberaciones físicas de una tecla persistente no la sueltan.
+        }
+
+        fn close_frame(&mut self) {
+            self.input.advance_frame();
+            if core::mem::take(&mut self.release_pending) {
+                for key in core::mem::take(&mut self.latched) {
+                    self.input.set_key(key, Btn::Up);
+                }
+            }
+        }
+    }
+
+    delegate_input_state!(Sticky, input, set_key: Sticky::feed, advance_frame: Sticky::close_frame);
+
+    impl StickyKeysExt<Key, Btn> for Sticky {
+        fn set_sticky(&mut self, key: Key, sticky: bool) {
+            if sticky {
+                self.sticky.push(key);
+                return;
+            }
+            self.sticky.retain(|marked| *marked != key);
+            if self.latched.contains(&key) {
+                self.unlatch(key);
+            }
+        }
+
+        fn is_sticky_latched(&self, key: Key) -> bool {
+            self.latched.contains(&key)
+        }
+    }
+
+    #[test]
+    fn latched_modifier_joins_the_next_key_then_releases() {
+        let mut input = Sticky::default();
+        input.set_sticky(Key::A, true);
+
+        input.set_key(Key::A, Btn::Down);
+        input.set_key(Key::A, Btn::Up);
+        input.advance_frame();
+        assert!(input.is_pressed(Key::A) && input.is_sticky_latched(Key::A));
+
+        input.set_key(Key::B, Btn::Down);
+        // Durante el frame de la tecla normal, el combo ve las dos.
+        assert!(input.active_combo(&[Key::A, Key::B]));
+
+        input.advance_frame();
+        assert!(!input.is_pressed(Key::A) && !input.is_sticky_latched(Key::A));
+        assert!(input.is_just_released(Key::A));
+        assert_eq!(input.input.history().last().map(|event| (event.key, event.state)), Some((Key::A, Btn::Up)));
+    }
+
+    #[test]
+    fn several_keys_latch_and_pressing_one_again_frees_it() {
+        let mut input = Sticky::default();
+        input.set_sticky(Key::A, true);
+        input.set_sticky(Key::C, true);
+
+        input.set_key(Key::A, Btn::Down);
+        input.set_key(Key::C, Btn::Down);
+        assert!(input.is_sticky_latched(Key::A) && input.is_sticky_latched(Key::C));
+
+        input.set_key(Key::A, Btn::Up);
+        input.set_key(Key::A, Btn::Down);
+        assert!(!input.is_pressed(Key::A));
+        assert!(input.is_pressed(Key::C));
+    }
+
+    #[test]
+    fn unmarking_a_latched_key_releases_it() {
+        let mut input = Sticky::default();
+        input.set_sticky(Key::D, true);
+        input.set_key(Key::D, Btn::Down);
+
+        input.set_sticky(Key::D, false);
+
+        assert!(!input.is_pressed(Key::D) && !input.is_sticky_latched(Key::D));
+        input.set_key(Key::D, Btn::Down);
+        input.set_key(Key::D, Btn::Up);
+        assert!(!input.is_pressed(Key::D));
+    }
+}
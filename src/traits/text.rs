@@ -0,0 +1,221 @@
+use alloc::string::String;
+use core::hash::Hash;
+
+use crate::StateSemantics;
+
+/// Distribución de teclado usada para traducir teclas a caracteres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KeyboardLayout {
+    /// QWERTY (EE. UU.).
+    #[default]
+    Qwerty,
+    /// QWERTZ (alemán, centroeuropeo).
+    Qwertz,
+    /// AZERTY (francés, belga).
+    Azerty,
+    /// Dvorak.
+    Dvorak,
+    /// Colemak.
+    Colemak,
+    /// Distribución propia del backend (por ejemplo, un identificador del sistema
+    /// operativo).
+    Custom(u32),
+}
+
+/// Evento de un **IME** (editor de métodos de entrada), entregado a
+/// [`TextInputExt::feed_ime`].
+///
+/// Los IME de chino, japonés o coreano componen un carácter a partir de varias
+/// pulsaciones: mientras se compone, el texto es provisional (*preedit*) y solo
+/// pasa a ser texto definitivo al confirmarlo.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImeEvent {
+    /// Texto provisional de la composición en curso. Reemplaza al anterior; una
+    /// cadena vacía termina la composición sin confirmar nada.
+    Preedit(String),
+    /// Texto confirmado, que pasa a [`TextInputExt::take_text`].
+    Commit(String),
+}
+
+/// # Trait `TextInputExt`
+///
+/// Interpreta el input como **texto**: caracteres compuestos según la distribución
+/// de teclado, los modificadores y el IME, para cajas de chat o campos de nombre.
+///
+/// Es independiente de [`InputStateExt`](crate::InputStateExt) porque la entrada de
+/// texto trabaja con caracteres, no con flancos de teclas: `Shift + a` es una `A`,
+/// una tecla mantenida produce caracteres repetidos y un IME puede necesitar varias
+/// pulsaciones para un solo carácter.
+///
+/// ## Modificadores
+/// La implementación sigue el estado de los modificadores a partir de los eventos
+/// recibidos en [`feed`](Self::feed): `Shift` y `Caps Lock` producen mayúsculas, y
+/// `Shift`/`AltGr` los símbolos que correspondan a la [`KeyboardLayout`] activa.
+/// Las combinaciones con `Ctrl` o `Alt` no producen texto.
+///
+/// ## Parámetros genéricos
+/// - `K`: Tipo de tecla.
+/// - `S`: Tipo de estado de tecla.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// // En el runtime, con el chat abierto:
+/// text.feed(key, state);
+///
+/// // En la UI, cada frame:
+/// chat.input.push_str(&text.take_text());
+/// chat.set_composition(text.preedit());
+/// ```
+pub trait TextInputExt<K, S>
+where
+    K: Copy + PartialEq + Hash,
+    S: StateSemantics,
+{
+    /// Procesa un evento de tecla, actualizando los modificadores y añadiendo al
+    /// texto pendiente los caracteres que produzca.
+    fn feed(&mut self, key: K, state: S);
+
+    /// Procesa un evento del IME del sistema.
+    fn feed_ime(&mut self, event: ImeEvent);
+
+    /// Retorna y **vacía** el texto confirmado desde la última llamada.
+    ///
+    /// No incluye el texto provisional del IME (ver [`preedit`](Self::preedit)).
+    fn take_text(&mut self) -> String;
+
+    /// Retorna el texto provisional de la composición del IME en curso, o `None` si
+    /// no hay ninguna.
+    ///
+    /// La UI debe mostrarlo (normalmente subrayado) en el punto de inserción, sin
+    /// añadirlo todavía al contenido del campo.
+    fn preedit(&self) -> Option<&str>;
+
+    /// Cambia la distribución de teclado usada para traducir teclas a caracteres.
+    fn set_layout(&mut self, layout: KeyboardLayout);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::Btn;
+
+    /// Teclas por posición física.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Pos {
+        Q,
+        W,
+        Shift,
+        Ctrl,
+    }
+
+    /// Caja de texto de referencia con las dos primeras letras de la fila superior.
+    #[derive(Default)]
+    struct TextBox {
+        layout: KeyboardLayout,
+        shift: bool,
+        ctrl: bool,
+        text: String,
+        preedit: Option<String>,
+    }
+
+    impl TextInputExt<Pos, Btn> for TextBox {
+        fn feed(&mut self, key: Pos, state: Btn) {
+            match key {
+                Pos::Shift => self.shift = state.is_down(),
+                Pos::Ctrl => self.ctrl = state.is_down(),
+                Pos::Q | Pos::W if state.is_down() && !self.ctrl => {
+                    let letter = match (self.layout, key) {
+                        (KeyboardLayout::Azerty, Pos::Q) => 'a',
+                        (KeyboardLayout::Azerty, _) => 'z',
+                        (_, Pos::Q) => 'q',
+                        _ => 'w',
+                    };
+                    self.text.push(if self.shift { letter.to_ascii_uppercase() } else { letter });
+                }
+                _ => {}
+            }
+        }
+
+        fn feed_ime(&mut self, event: ImeEvent) {
+            match event {
+                ImeEvent::Preedit(text) => self.preedit = Some(text).filter(|text| !text.is_empty()),
+                ImeEvent::Commit(text) => {
+                    self.preedit = None;
+                    self.text.push_str(&text);
+                }
+            }
+        }
+
+        fn take_text(&mut self) -> String {
+            core::mem::take(&mut self.text)
+        }
+
+        fn preedit(&self) -> Option<&str> {
+            self.preedit.as_deref()
+        }
+
+        fn set_layout(&mut self, layout: KeyboardLayout) {
+            self.layout = layout;
+        }
+    }
+
+    fn type_keys(text: &mut TextBox, keys: &[(Pos, Btn)]) {
+        for &(key, state) in keys {
+            text.feed(key, state);
+        }
+    }
+
+    #[test]
+    fn the_layout_decides_the_character() {
+        let mut text = TextBox::default();
+        type_keys(&mut text, &[(Pos::Q, Btn::Down), (Pos::Q, Btn::Up), (Pos::W, Btn::Down)]);
+        assert_eq!(text.take_text(), "qw");
+
+        text.set_layout(KeyboardLayout::Azerty);
+        type_keys(&mut text, &[(Pos::Q, Btn::Down), (Pos::W, Btn::Down)]);
+        assert_eq!(text.take_text(), "az");
+    }
+
+    #[test]
+    fn modifiers_shape_the_text() {
+        let mut text = TextBox::default();
+        type_keys(
+            &mut text,
+            &[(Pos::Shift, Btn::Down), (Pos::Q, Btn::Down), (Pos::Shift, Btn::Up), (Pos::W, Btn::Down)],
+        );
+        assert_eq!(text.take_text(), "Qw");
+
+        // Las combinaciones con Ctrl son atajos, no texto.
+        type_keys(&mut text, &[(Pos::Ctrl, Btn::Down), (Pos::W, Btn::Down)]);
+        assert_eq!(text.take_text(), "");
+    }
+
+    #[test]
+    fn take_text_drains_the_pending_text() {
+        let mut text = TextBox::default();
+        text.feed(Pos::Q, Btn::Down);
+
+        assert_eq!(text.take_text(), "q");
+        assert_eq!(text.take_text(), "");
+    }
+
+    #[test]
+    fn ime_preedit_is_provisional_until_committed() {
+        let mut text = TextBox::default();
+        text.feed_ime(ImeEvent::Preedit("に".into()));
+        text.feed_ime(ImeEvent::Preedit("にほ".into()));
+
+        assert_eq!(text.preedit(), Some("にほ"));
+        assert_eq!(text.take_text(), "");
+
+        text.feed_ime(ImeEvent::Commit("日本".into()));
+        assert_eq!(text.preedit(), None);
+        assert_eq!(text.take_text(), "日本");
+
+        // Un preedit vacío cancela la composición sin confirmar nada.
+        text.feed_ime(ImeEvent::Preedit("ご".into()));
+        text.feed_ime(ImeEvent::Preedit(String::new()));
+        assert_eq!(text.preedit(), None);
+        assert_eq!(text.take_text(), "");
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+/// Fase de un contacto en el frame actual, reportada en [`TouchPoint::phase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// El dedo tocó la superficie en este frame.
+    Began,
+    /// El contacto sigue activo (se haya movido o no).
+    Moved,
+    /// El dedo se levantó en este frame. Es la última vez que se reporta este `id`.
+    Ended,
+}
+
+/// Contacto individual de una superficie táctil.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchPoint {
+    /// Identificador **estable** del contacto mientras el dedo siga apoyado.
+    pub id: u64,
+    /// Posición `(x, y)` en píxeles, con el mismo sistema de coordenadas que
+    /// [`MouseStateExt::position`](crate::MouseStateExt::position).
+    pub position: (f64, f64),
+    /// Presión normalizada `0.0..=1.0`; `1.0` si la superficie no mide presión.
+    pub pressure: f32,
+    /// Fase del contacto en el frame actual.
+    pub phase: TouchPhase,
+}
+
+/// # Trait `TouchExt`
+///
+/// Define el estado de una superficie **multitáctil** (pantalla o touchpad en modo
+/// absoluto) en el frame actual.
+///
+/// ## Identificadores de contacto
+/// Lo que hace necesario este contrato es la estabilidad de los ids: un mismo dedo
+/// conserva su [`TouchPoint::id`] desde [`Began`](TouchPhase::Began) hasta
+/// [`Ended`](TouchPhase::Ended), aunque otros dedos se apoyen o se levanten. Así
+/// los reconocedores de gestos del motor pueden seguir un dedo concreto entre
+/// frames sin depender del backend. Un id no se reutiliza mientras su contacto siga
+/// activo.
+///
+/// ## Ejemplo
+/// ```rust,ignore
+/// for touch in screen.active_touches() {
+///     if touch.phase == TouchPhase::Began {
+///         pinch.track(touch.id);
+///     }
+/// }
+///
+/// if let Some(finger) = screen.touch_by_id(pinch.first) {
+///     pinch.update(finger.position);
+/// }
+/// ```
+pub trait TouchExt {
+    /// Retorna los contactos del frame actual, incluidos los que terminaron en él
+    /// ([`TouchPhase::Ended`]).
+    fn active_touches(&self) -> Vec<TouchPoint>;
+
+    /// Retorna el contacto con este `id`, si sigue presente en el frame actual.
+    ///
+    /// Por defecto lo busca en [`active_touches`](Self::active_touches).
+    fn touch_by_id(&self, id: u64) -> Option<TouchPoint> {
+        self.active_touches().into_iter().find(|touch| touch.id == id)
+    }
+
+    /// Registra el estado de un contacto. Lo llama el runtime al leer un evento
+    /// táctil del backend.
+    fn set_touch(&mut self, touch: TouchPoint);
+
+    /// Cierra el frame actual: elimina los contactos en fase
+    /// [`Ended`](TouchPhase::Ended) y pasa los [`Began`](TouchPhase::Began) a
+    /// [`Moved`](TouchPhase::Moved).
+    fn advance_touch_frame(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Superficie de referencia: guarda los contactos del frame tal como llegan.
+    #[derive(Default)]
+    struct Screen {
+        touches: Vec<TouchPoint>,
+    }
+
+    impl TouchExt for Screen {
+        fn active_touches(&self) -> Vec<TouchPoint> {
+            self.touches.clone()
+        }
+
+        fn set_touch(&mut self, touch: TouchPoint) {
+            match self.touches.iter_mut().find(|known| known.id == touch.id) {
+                Some(known) => *known = touch,
+                None => self.touches.push(touch),
+            }
+        }
+
+        fn advance_touch_frame(&mut self) {
+            self.touches.retain(|touch| touch.phase != TouchPhase::Ended);
+            for touch in &mut self.touches {
+                touch.phase = TouchPhase::Moved;
+            }
+        }
+    }
+
+    fn finger(id: u64, x: f64, phase: TouchPhase) -> TouchPoint {
+        TouchPoint { id, position: (x, 0.0), pressure: 1.0, phase }
+    }
+
+    #[test]
+    fn touch_by_id_follows_one_finger_across_frames() {
+        let mut screen = Screen::default();
+        screen.set_touch(finger(1, 10.0, TouchPhase::Began));
+        screen.set_touch(finger(2, 50.0, TouchPhase::Began));
+        assert_eq!(screen.active_touches().len(), 2);
+        assert_eq!(screen.touch_by_id(1).map(|t| t.phase), Some(TouchPhase::Began));
+
+        screen.advance_touch_frame();
+        screen.set_touch(finger(1, 15.0, TouchPhase::Moved));
+
+        let first = screen.touch_by_id(1).unwrap();
+        assert_eq!(first.position, (15.0, 0.0));
+        assert_eq!(first.phase, TouchPhase::Moved);
+        // El otro dedo conserva su id aunque no se haya movido.
+        assert_eq!(screen.touch_by_id(2).map(|t| t.position), Some((50.0, 0.0)));
+        assert_eq!(screen.touch_by_id(3), None);
+    }
+
+    #[test]
+    fn ended_touches_are_reported_once_then_dropped() {
+        let mut screen = Screen::default();
+        screen.set_touch(finger(7, 0.0, TouchPhase::Began));
+        screen.advance_touch_frame();
+        screen.set_touch(finger(7, 0.0, TouchPhase::Ended));
+
+        assert_eq!(screen.touch_by_id(7).map(|t| t.phase), Some(TouchPhase::Ended));
+
+        screen.advance_touch_frame();
+        assert_eq!(screen.touch_by_id(7), None);
+        assert!(screen.active_touches().is_empty());
+    }
+}
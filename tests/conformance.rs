@@ -0,0 +1,39 @@
+//! La batería de [`conformance_tests!`] sobre el backend de referencia, usada como
+//! la usaría un backend externo.
+
+use std::time::Duration;
+
+use orbit_input_core::StateSemantics;
+use orbit_input_core::testing::{MockEvent, MockInputState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    A,
+    B,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum State {
+    Pressed,
+    Released,
+}
+
+impl StateSemantics for State {
+    fn is_down(&self) -> bool {
+        *self == State::Pressed
+    }
+
+    fn released() -> Self {
+        State::Released
+    }
+}
+
+type Input = MockInputState<Key, State>;
+
+orbit_input_core::conformance_tests! {
+    mod mock_conformance;
+    input: Input::new,
+    keys: (Key::A, Key::B),
+    pressed: State::Pressed,
+    history: MockEvent<Key, State, Duration>,
+}
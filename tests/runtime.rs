@@ -0,0 +1,76 @@
+//! Arranque de un juego sobre [`NullRuntime`]: el game loop espera a `ready()` antes
+//! de la primera consulta al estado.
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use orbit_input_core::testing::{lock, MockInputState, NullRuntime};
+use orbit_input_core::{InjectableRuntime, InputStateExt, RuntimeExt, StateSemantics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    Space,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum State {
+    Pressed,
+    Released,
+}
+
+impl StateSemantics for State {
+    fn is_down(&self) -> bool {
+        *self == State::Pressed
+    }
+
+    fn released() -> Self {
+        State::Released
+    }
+}
+
+type Input = MockInputState<Key, State>;
+
+/// Sondea un futuro una sola vez.
+fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+    pin!(future).poll(&mut Context::from_waker(Waker::noop()))
+}
+
+/// Ejecuta un futuro que se resuelve sin esperar a nada externo.
+fn block_on<F: Future>(future: F) -> F::Output {
+    match poll_once(future) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("el futuro quedó pendiente"),
+    }
+}
+
+#[test]
+fn game_waits_for_ready_before_the_first_frame() {
+    let (mut runtime, shared) = NullRuntime::<Input, Key, State>::new().unwrap();
+
+    block_on(async {
+        runtime.initialize().await.unwrap();
+        runtime.ready().await;
+    });
+
+    assert!(runtime.is_running());
+    assert_eq!(runtime.list_devices().len(), runtime.diagnostics().device_count);
+
+    runtime.inject(Key::Space, State::Pressed).unwrap();
+    assert!(lock(&shared).is_just_press(Key::Space));
+}
+
+#[test]
+fn ready_waits_for_initialize() {
+    let (mut runtime, _) = NullRuntime::<Input, Key, State>::new().unwrap();
+    assert!(poll_once(runtime.ready()).is_pending());
+    assert_eq!(runtime.diagnostics().device_count, 0);
+
+    block_on(runtime.initialize()).unwrap();
+    assert!(poll_once(runtime.ready()).is_ready());
+    assert_eq!(runtime.diagnostics().device_count, 1);
+
+    runtime.stop().unwrap();
+    assert!(poll_once(runtime.ready()).is_pending());
+    assert_eq!(runtime.diagnostics().device_count, 0);
+}